@@ -0,0 +1,251 @@
+use std::{
+    fs::{read_to_string, remove_dir_all},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Error, Result};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::config::Config;
+use crate::project_info::{build_project_info, ProjectInfo};
+
+#[derive(Debug, Deserialize)]
+struct BatchFile {
+    projects: Vec<BatchProject>,
+}
+
+/// A project entry in a batch file. Any keys other than the project name, description, and module
+/// name override the saved config for this project only.
+#[derive(Debug, Deserialize)]
+pub struct BatchProject {
+    pub project_name: String,
+    #[serde(default)]
+    pub project_description: String,
+    pub module_name: Option<String>,
+    #[serde(flatten)]
+    pub overrides: Map<String, Value>,
+}
+
+impl BatchProject {
+    pub fn project_info(&self, config: &Config) -> Result<ProjectInfo> {
+        let config = config.with_overrides(self.overrides.clone())?;
+
+        build_project_info(
+            config,
+            Some(self.project_name.clone()),
+            Some(self.project_description.clone()),
+            self.module_name.clone(),
+            true,
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct BatchReport {
+    /// The base directories of the projects that were created and kept.
+    pub created: Vec<PathBuf>,
+    pub failures: Vec<(String, Error)>,
+    /// The number of created projects removed because a later project failed in atomic mode.
+    pub rolled_back: usize,
+}
+
+/// Creates each project with `create_project`, continuing on error. The directory of a project
+/// that fails is removed. When `atomic` is set the batch stops at the first failure and every
+/// project already created in the batch is removed as well.
+pub fn create_batch<F>(
+    projects: &[BatchProject],
+    config: &Config,
+    atomic: bool,
+    mut create_project: F,
+) -> BatchReport
+where
+    F: FnMut(&mut ProjectInfo) -> Result<()>,
+{
+    let mut report = BatchReport::default();
+
+    for project in projects {
+        let result =
+            project.project_info(config).and_then(|mut project_info| {
+                match create_project(&mut project_info) {
+                    Ok(_) => Ok(project_info.base_dir()),
+                    Err(e) => {
+                        let base_dir = project_info.base_dir();
+                        if base_dir.exists() {
+                            let _ = remove_dir_all(&base_dir);
+                        }
+                        Err(e)
+                    }
+                }
+            });
+
+        match result {
+            Ok(base_dir) => report.created.push(base_dir),
+            Err(e) => {
+                report.failures.push((project.project_name.clone(), e));
+
+                if atomic {
+                    for base_dir in report.created.drain(..) {
+                        if base_dir.exists() {
+                            let _ = remove_dir_all(&base_dir);
+                        }
+                        report.rolled_back += 1;
+                    }
+
+                    break;
+                }
+            }
+        }
+    }
+
+    report
+}
+
+pub fn load_batch_file(batch_file: &Path) -> Result<Vec<BatchProject>> {
+    let Ok(batch_str) = read_to_string(batch_file) else {
+        bail!(format!("Unable to read {}", batch_file.display()));
+    };
+    let batch: BatchFile = match toml::from_str(&batch_str) {
+        Ok(b) => b,
+        Err(e) => bail!(format!("Invalid batch file: {e}")),
+    };
+
+    if batch.projects.is_empty() {
+        bail!("The batch file doesn't contain any projects");
+    }
+
+    Ok(batch.projects)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project_generator::generate_project;
+    use std::fs::write;
+    use tmp_path::tmp_path;
+
+    #[tmp_path]
+    #[test]
+    fn test_batch_creates_projects() {
+        let batch_file = tmp_path.join("projects.toml");
+        write(
+            &batch_file,
+            r#"[[projects]]
+project_name = "Batch Service One"
+project_description = "The first service"
+creator = "Arthur Dent"
+creator_email = "authur@heartofgold.com"
+
+[[projects]]
+project_name = "Batch Service Two"
+creator = "Arthur Dent"
+creator_email = "authur@heartofgold.com"
+project_manager = "Uv"
+max_line_length = 88
+"#,
+        )
+        .unwrap();
+        let projects = load_batch_file(&batch_file).unwrap();
+
+        assert_eq!(projects.len(), 2);
+
+        for project in &projects {
+            let mut project_info = project.project_info(&Config::default()).unwrap();
+            project_info.project_root_dir = Some(tmp_path.clone());
+            project_info.offline = true;
+            generate_project(&project_info).unwrap();
+        }
+
+        assert!(tmp_path.join("batch-service-one/pyproject.toml").is_file());
+        assert!(tmp_path.join("batch-service-two/pyproject.toml").is_file());
+    }
+
+    fn write_failing_batch(batch_file: &Path) {
+        write(
+            batch_file,
+            r#"[[projects]]
+project_name = "Batch Service One"
+creator = "Arthur Dent"
+creator_email = "authur@heartofgold.com"
+
+[[projects]]
+project_name = "Batch Service Two"
+creator = "Arthur Dent"
+creator_email = "authur@heartofgold.com"
+"#,
+        )
+        .unwrap();
+    }
+
+    fn fail_second_project(project_info: &mut ProjectInfo, root: &Path) -> Result<()> {
+        project_info.project_root_dir = Some(root.to_path_buf());
+        project_info.offline = true;
+        generate_project(project_info)?;
+
+        if project_info.project_slug == "batch-service-two" {
+            bail!("Failed to create the second project");
+        }
+
+        Ok(())
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_create_batch_continues_on_error() {
+        let batch_file = tmp_path.join("projects.toml");
+        write_failing_batch(&batch_file);
+        let projects = load_batch_file(&batch_file).unwrap();
+        let report = create_batch(&projects, &Config::default(), false, |project_info| {
+            fail_second_project(project_info, &tmp_path)
+        });
+
+        assert_eq!(report.created, vec![tmp_path.join("batch-service-one")]);
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.rolled_back, 0);
+        assert!(tmp_path.join("batch-service-one").is_dir());
+        assert!(!tmp_path.join("batch-service-two").exists());
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_create_batch_atomic() {
+        let batch_file = tmp_path.join("projects.toml");
+        write_failing_batch(&batch_file);
+        let projects = load_batch_file(&batch_file).unwrap();
+        let report = create_batch(&projects, &Config::default(), true, |project_info| {
+            fail_second_project(project_info, &tmp_path)
+        });
+
+        assert!(report.created.is_empty());
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.rolled_back, 1);
+        assert!(!tmp_path.join("batch-service-one").exists());
+        assert!(!tmp_path.join("batch-service-two").exists());
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_batch_invalid_override() {
+        let batch_file = tmp_path.join("projects.toml");
+        write(
+            &batch_file,
+            r#"[[projects]]
+project_name = "Batch Service"
+not_a_key = true
+"#,
+        )
+        .unwrap();
+        let projects = load_batch_file(&batch_file).unwrap();
+
+        assert!(projects[0].project_info(&Config::default()).is_err());
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_batch_no_projects() {
+        let batch_file = tmp_path.join("projects.toml");
+        write(&batch_file, "projects = []\n").unwrap();
+
+        assert!(load_batch_file(&batch_file).is_err());
+    }
+}
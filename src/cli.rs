@@ -1,7 +1,10 @@
+use std::path::PathBuf;
+
 use clap::{Parser, Subcommand, ValueEnum};
 
 use crate::project_info::{
-    Day, DependabotSchedule, LicenseType, ProjectManager, Pyo3PythonManager,
+    parse_author, CloudProvider, Day, DependabotSchedule, DependencyBoundStyle, ExtraPreCommitHook,
+    LicenseType, ProjectManager, PublishAuth, Pyo3PythonManager, TypeChecker,
 };
 
 #[derive(Clone, Debug, ValueEnum)]
@@ -21,6 +24,13 @@ pub enum BooleanChoice {
 pub struct Args {
     #[clap(subcommand)]
     pub command: Command,
+
+    #[clap(
+        long,
+        global = true,
+        help = "Path to a config file to use instead of the default location"
+    )]
+    pub config: Option<PathBuf>,
 }
 
 #[derive(Debug, Subcommand)]
@@ -33,18 +43,146 @@ pub enum Command {
             help = "If set the default package versions will be used instead of the latest"
         )]
         skip_download_latest_packages: bool,
+        #[clap(
+            long,
+            help = "Don't make any network requests, the default package versions will be used"
+        )]
+        offline: bool,
         #[clap(
             short,
             long,
             help = "Use saved configuration and default values instead of prompting where possible"
         )]
         default: bool,
+        #[clap(
+            short,
+            long,
+            help = "Run the project manager's lock command after the project is created"
+        )]
+        lock: bool,
+        #[clap(
+            long,
+            help = "Install the project's dependencies with the project manager after it is created"
+        )]
+        install: bool,
+        #[clap(
+            long,
+            help = "Format the generated project with ruff after it is created"
+        )]
+        format: bool,
+        #[clap(
+            long,
+            help = "Generate a settings module that loads environment variables with pydantic-settings"
+        )]
+        include_settings: bool,
+        #[clap(
+            long,
+            help = "Generate a logging setup module for application projects"
+        )]
+        include_logging: bool,
+        #[clap(
+            long,
+            help = "The import name of the package, used instead of prompting for the source directory"
+        )]
+        module_name: Option<String>,
+        #[clap(
+            long = "author",
+            value_parser = parse_author,
+            help = "An additional author in the format \"Name <email>\", can be repeated"
+        )]
+        authors: Vec<(String, String)>,
+        #[clap(
+            long,
+            help = "Create every project listed in a TOML batch file instead of prompting"
+        )]
+        batch: Option<PathBuf>,
+        #[clap(
+            long,
+            requires = "batch",
+            help = "Remove every project created by the batch if any project fails"
+        )]
+        atomic: bool,
+        #[clap(
+            long,
+            help = "Print a single progress line instead of showing an animated spinner"
+        )]
+        no_spinner: bool,
+        #[clap(long, help = "Don't initialize a git repository in the new project")]
+        no_git: bool,
+        #[clap(
+            long,
+            help = "Print how long generating the project took with a breakdown by phase"
+        )]
+        timings: bool,
+        #[clap(
+            long,
+            help = "Use the defaults from a saved config profile instead of the saved config"
+        )]
+        profile: Option<String>,
+    },
+
+    /// Regenerate the GitHub workflow and pre-commit files of an existing project
+    Update {
+        #[clap(help = "Path to the project directory, defaults to the current directory")]
+        path: Option<PathBuf>,
+        #[clap(
+            short,
+            long,
+            help = "If set the default package versions will be used instead of the latest"
+        )]
+        skip_download_latest_packages: bool,
+    },
+
+    /// Regenerate only the pyproject.toml file of an existing project using the saved config
+    UpdatePyproject {
+        #[clap(help = "Path to the project directory, defaults to the current directory")]
+        path: Option<PathBuf>,
+        #[clap(
+            short,
+            long,
+            help = "If set the default package versions will be used instead of the latest"
+        )]
+        skip_download_latest_packages: bool,
+    },
+
+    /// Print the supported project managers, licenses, and CI providers as JSON
+    Features,
+
+    /// Describe each project manager and the build backend it uses
+    ListManagers,
+
+    /// Generate a man page for python-project
+    Manpage {
+        #[clap(
+            short,
+            long,
+            help = "The file to write the man page to, defaults to printing it to stdout"
+        )]
+        output: Option<PathBuf>,
     },
 
+    /// View or pin the package versions used when the latest versions aren't downloaded
+    Versions(Versions),
+
     /// Save default config values
     Config(Config),
 }
 
+#[derive(Debug, Parser)]
+pub struct Versions {
+    #[clap(subcommand)]
+    pub command: VersionsCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum VersionsCommand {
+    /// Show the version used for each package when working offline
+    Show,
+
+    /// Pin a package to a version instead of its built in default
+    Set { name: String, version: String },
+}
+
 #[derive(Debug, Parser)]
 pub struct Config {
     #[clap(subcommand)]
@@ -65,6 +203,12 @@ pub enum Param {
     /// Remove the saved config for creator email
     ResetCreatorEmail,
 
+    /// Save a default copyright holder for licenses, used instead of the creator
+    CopyrightHolder { value: String },
+
+    /// Remove the saved copyright holder, the creator will be used
+    ResetCopyrightHolder,
+
     /// Save a default license
     License { value: LicenseType },
 
@@ -119,6 +263,138 @@ pub enum Param {
     /// Remove the saved max line length
     ResetMaxLineLength,
 
+    /// Save the ruff line length, defaults to the max line length
+    RuffLineLength { value: u8 },
+
+    /// Remove the saved ruff line length
+    ResetRuffLineLength,
+
+    /// Save a default minimum test coverage percentage
+    MinCoverage { value: u8 },
+
+    /// Remove the saved minimum test coverage
+    ResetMinCoverage,
+
+    /// Save additional ruff lint rules to select, comma separated
+    ExtraRuffRules { value: String },
+
+    /// Remove the saved additional ruff lint rules
+    ResetExtraRuffRules,
+
+    /// Save a default value for Include Bandit
+    IncludeBandit { value: BooleanChoice },
+
+    /// Remove the saved include bandit value
+    ResetIncludeBandit,
+
+    /// Save a default value for including a conda environment.yml file in Pixi projects
+    IncludeCondaEnv { value: BooleanChoice },
+
+    /// Remove the saved include conda env value
+    ResetIncludeCondaEnv,
+
+    /// Save a default value for including a CODEOWNERS file
+    IncludeCodeowners { value: BooleanChoice },
+
+    /// Remove the saved include CODEOWNERS value
+    ResetIncludeCodeowners,
+
+    /// Save a GitHub username to use as the owner in the CODEOWNERS file
+    GithubUsername { value: String },
+
+    /// Remove the saved GitHub username
+    ResetGithubUsername,
+
+    /// Save a default value for including issue and pull request templates
+    IncludeGithubTemplates { value: BooleanChoice },
+
+    /// Remove the saved include GitHub templates value
+    ResetIncludeGithubTemplates,
+
+    /// Save a default value for including a CONTRIBUTING.md file
+    IncludeContributing { value: BooleanChoice },
+
+    /// Remove the saved include contributing value
+    ResetIncludeContributing,
+
+    /// Save a default value for including a CodeQL analysis workflow
+    IncludeCodeql { value: BooleanChoice },
+
+    /// Remove the saved include CodeQL value
+    ResetIncludeCodeql,
+
+    /// Save whether a SECURITY.md file should be included
+    IncludeSecurityPolicy { value: BooleanChoice },
+
+    /// Reset whether a SECURITY.md file should be included
+    ResetIncludeSecurityPolicy,
+
+    /// Save whether a CHANGELOG.md file should be included
+    IncludeChangelog { value: BooleanChoice },
+
+    /// Reset whether a CHANGELOG.md file should be included
+    ResetIncludeChangelog,
+
+    /// Save whether a CITATION.cff file should be included
+    IncludeCitation { value: BooleanChoice },
+
+    /// Reset whether a CITATION.cff file should be included
+    ResetIncludeCitation,
+
+    /// Save whether a pytest-benchmark harness should be included
+    IncludeBenchmarks { value: BooleanChoice },
+
+    /// Reset whether a pytest-benchmark harness should be included
+    ResetIncludeBenchmarks,
+
+    /// Save whether a pre-commit.ci config block should be added to .pre-commit-config.yaml
+    UsePrecommitCi { value: BooleanChoice },
+
+    /// Reset whether a pre-commit.ci config block should be added
+    ResetUsePrecommitCi,
+
+    /// Save additional pre-commit hooks to include, comma separated
+    ExtraPreCommitHooks {
+        #[clap(value_delimiter = ',')]
+        value: Vec<ExtraPreCommitHook>,
+    },
+
+    /// Remove the saved additional pre-commit hooks
+    ResetExtraPreCommitHooks,
+
+    /// Save the files and directories mypy checks, comma separated
+    MypyFiles { value: String },
+
+    /// Remove the saved mypy files, the source directory and tests will be used
+    ResetMypyFiles,
+
+    /// Save a Python version for ruff to target, defaults to the min Python version
+    RuffTargetVersion { value: String },
+
+    /// Remove the saved ruff target version, the min Python version will be used
+    ResetRuffTargetVersion,
+
+    /// Pin a development dependency to a version instead of using the latest
+    SetPackageVersion { name: String, version: String },
+
+    /// Remove the pinned version for a single package
+    ResetPackageVersion { name: String },
+
+    /// Remove all pinned package versions
+    ResetPackageVersions,
+
+    /// Save how uv and pixi dev dependency versions are constrained
+    DependencyBoundStyle { value: DependencyBoundStyle },
+
+    /// Remove the saved dependency bound style, exact versions will be used
+    ResetDependencyBoundStyle,
+
+    /// Save the type checker to use
+    TypeChecker { value: TypeChecker },
+
+    /// Reset the type checker, mypy will be used
+    ResetTypeChecker,
+
     /// Save a default value for Use Dependabot
     UseDependabot { value: BooleanChoice },
 
@@ -149,6 +425,102 @@ pub enum Param {
     /// Remove the saved use release drafter value
     ResetUseReleaseDrafter,
 
+    /// Save a cloud provider to generate an OIDC deploy workflow for
+    CloudProvider { value: CloudProvider },
+
+    /// Remove the saved cloud provider, no deploy workflow will be generated
+    ResetCloudProvider,
+
+    /// Save the AWS role ARN or GCP workload identity provider used by the deploy workflow
+    CloudDeployRole { value: String },
+
+    /// Remove the saved cloud deploy role, the value will be read from a repository variable
+    ResetCloudDeployRole,
+
+    /// Save a default value for Publish Docker, applications with a Dockerfile get a GHCR publish workflow
+    PublishDocker { value: BooleanChoice },
+
+    /// Remove the saved publish docker value
+    ResetPublishDocker,
+
+    /// Save a default value for adding SBOM generation and build attestation to the PyPI publish workflow
+    SupplyChainAttestation { value: BooleanChoice },
+
+    /// Remove the saved supply chain attestation value
+    ResetSupplyChainAttestation,
+
+    /// Save a package index URL to publish to instead of PyPI
+    PypiIndexUrl { value: String },
+
+    /// Reset the package index URL, PyPI will be used
+    ResetPypiIndexUrl,
+
+    /// Save how the publish workflow authenticates with the package index
+    PublishAuth { value: PublishAuth },
+
+    /// Reset the publish authentication, trusted publishing will be used
+    ResetPublishAuth,
+
+    /// Save whether a TestPyPI publish workflow should be included
+    IncludeTestpypiPublish { value: BooleanChoice },
+
+    /// Reset whether a TestPyPI publish workflow should be included
+    ResetIncludeTestpypiPublish,
+
+    /// Save whether a rust-toolchain.toml file should be included in Maturin projects
+    IncludeRustToolchain { value: BooleanChoice },
+
+    /// Reset whether a rust-toolchain.toml file should be included in Maturin projects
+    ResetIncludeRustToolchain,
+
+    /// Save the Rust edition used in the Cargo.toml of Maturin projects
+    RustEdition { value: String },
+
+    /// Reset the Rust edition, 2021 will be used
+    ResetRustEdition,
+
+    /// Save the PyO3 version used in the Cargo.toml of Maturin projects instead of the latest
+    Pyo3Version { value: String },
+
+    /// Reset the PyO3 version, the latest version will be used
+    ResetPyo3Version,
+
+    /// Save whether Maturin projects should build abi3 stable ABI wheels
+    UseAbi3 { value: BooleanChoice },
+
+    /// Reset whether Maturin projects should build abi3 stable ABI wheels
+    ResetUseAbi3,
+
+    /// Save whether Maturin projects should also publish musllinux wheels
+    BuildMusllinux { value: BooleanChoice },
+
+    /// Reset whether Maturin projects should also publish musllinux wheels
+    ResetBuildMusllinux,
+
+    /// Save the targets Maturin wheels are built for when publishing, comma separated
+    MaturinTargets { value: String },
+
+    /// Reset the Maturin targets, the default targets will be used
+    ResetMaturinTargets,
+
+    /// Save the number of minutes publish jobs can run before they are cancelled
+    CiJobTimeout { value: u16 },
+
+    /// Remove the saved CI job timeout, 30 minutes will be used
+    ResetCiJobTimeout,
+
+    /// Save whether the testing workflow should comment coverage on pull requests
+    CiCoverageComment { value: BooleanChoice },
+
+    /// Reset whether the testing workflow should comment coverage on pull requests
+    ResetCiCoverageComment,
+
+    /// Save whether uv CI installs with --frozen, turn off until a uv.lock is committed
+    UvFrozen { value: BooleanChoice },
+
+    /// Reset whether uv CI installs with --frozen
+    ResetUvFrozen,
+
     /// Save a default value for Use Multi OS CI
     UseMultiOsCi { value: BooleanChoice },
 
@@ -167,9 +539,54 @@ pub enum Param {
     /// Remove the save download latest packages value
     ResetDownloadLatestPackages,
 
+    /// Save whether a spinner should be shown while generating
+    UseSpinner { value: BooleanChoice },
+
+    /// Reset whether a spinner should be shown while generating
+    ResetUseSpinner,
+
+    /// Save whether a git repository should be initialized in new projects
+    InitGit { value: BooleanChoice },
+
+    /// Reset whether a git repository should be initialized in new projects
+    ResetInitGit,
+
+    /// Save the number of hours fetched package versions are cached for
+    VersionCacheTtl { value: u64 },
+
+    /// Remove the saved version cache TTL, versions will be cached for 24 hours
+    ResetVersionCacheTtl,
+
+    /// Remove all cached package versions
+    CacheClear,
+
     /// Rerset the config to the default values
     Reset,
 
     /// View the current config values
     Show,
+
+    /// Print the saved value for a single config key, for example `license`
+    Get { key: String },
+
+    /// Save, list, or delete named config profiles
+    Profile(Profile),
+}
+
+#[derive(Debug, Parser)]
+pub struct Profile {
+    #[clap(subcommand)]
+    pub command: ProfileCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ProfileCommand {
+    /// Save the current config values as a named profile
+    Save { name: String },
+
+    /// List the saved profiles
+    List,
+
+    /// Delete a saved profile
+    Delete { name: String },
 }
@@ -1,23 +1,101 @@
 use std::{
+    collections::HashMap,
+    env,
     fmt::Display,
-    fs::{create_dir_all, read_to_string, File},
-    path::PathBuf,
+    fs::{create_dir_all, read_dir, read_to_string, remove_file, write},
+    path::{Path, PathBuf},
     rc::Rc,
+    sync::RwLock,
 };
 
 use anyhow::{bail, Result};
 use colored::*;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
+use crate::package_version::validate_package_name;
 use crate::project_info::{
-    is_valid_python_version, Day, DependabotSchedule, LicenseType, ProjectManager,
-    Pyo3PythonManager,
+    is_valid_crate_version, is_valid_email, is_valid_maturin_target, is_valid_python_test_version,
+    is_valid_python_version, is_valid_ruff_rule, is_valid_rust_edition, CloudProvider, Day,
+    DependabotSchedule, DependencyBoundStyle, ExtraPreCommitHook, LicenseType, ProjectManager,
+    PublishAuth, Pyo3PythonManager, TypeChecker, DEFAULT_RUFF_RULES,
 };
 
+/// The keys accepted by `config get`.
+const CONFIG_KEYS: &[&str] = &[
+    "creator",
+    "creator_email",
+    "copyright_holder",
+    "license",
+    "python_version",
+    "min_python_version",
+    "project_manager",
+    "pyo3_python_manager",
+    "is_async_project",
+    "is_application",
+    "github_actions_python_test_versions",
+    "max_line_length",
+    "ruff_line_length",
+    "min_coverage",
+    "extra_ruff_rules",
+    "include_bandit",
+    "include_conda_env",
+    "include_codeowners",
+    "github_username",
+    "include_github_templates",
+    "include_contributing",
+    "include_codeql",
+    "include_security_policy",
+    "include_changelog",
+    "include_citation",
+    "include_benchmarks",
+    "use_precommit_ci",
+    "extra_pre_commit_hooks",
+    "mypy_files",
+    "ruff_target_version",
+    "package_version_overrides",
+    "dependency_bound_style",
+    "type_checker",
+    "use_dependabot",
+    "dependabot_schedule",
+    "dependabot_day",
+    "use_continuous_deployment",
+    "use_release_drafter",
+    "cloud_provider",
+    "cloud_deploy_role",
+    "publish_docker",
+    "supply_chain_attestation",
+    "pypi_index_url",
+    "publish_auth",
+    "include_testpypi_publish",
+    "include_rust_toolchain",
+    "rust_edition",
+    "pyo3_version",
+    "use_abi3",
+    "build_musllinux",
+    "maturin_targets",
+    "ci_job_timeout",
+    "ci_coverage_comment",
+    "uv_frozen",
+    "use_multi_os_ci",
+    "include_docs",
+    "download_latest_packages",
+    "use_spinner",
+    "init_git",
+    "version_cache_ttl",
+];
+
+/// The config file passed with the `--config` flag.
+static CONFIG_FILE_FLAG: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+// Every field is optional so a config saved by an older version, which won't have newer fields,
+// still loads.
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(default)]
 pub struct Config {
     pub creator: Option<String>,
     pub creator_email: Option<String>,
+    pub copyright_holder: Option<String>,
     pub license: Option<LicenseType>,
     pub python_version: Option<String>,
     pub min_python_version: Option<String>,
@@ -27,14 +105,54 @@ pub struct Config {
     pub is_application: Option<bool>,
     pub github_actions_python_test_versions: Option<Vec<String>>,
     pub max_line_length: Option<u8>,
+    pub ruff_line_length: Option<u8>,
+    pub min_coverage: Option<u8>,
+    pub extra_ruff_rules: Option<Vec<String>>,
+    pub include_bandit: Option<bool>,
+    pub include_conda_env: Option<bool>,
+    pub include_codeowners: Option<bool>,
+    pub github_username: Option<String>,
+    pub include_github_templates: Option<bool>,
+    pub include_contributing: Option<bool>,
+    pub include_codeql: Option<bool>,
+    pub include_security_policy: Option<bool>,
+    pub include_changelog: Option<bool>,
+    pub include_citation: Option<bool>,
+    pub include_benchmarks: Option<bool>,
+    pub use_precommit_ci: Option<bool>,
+    pub extra_pre_commit_hooks: Option<Vec<ExtraPreCommitHook>>,
+    pub mypy_files: Option<Vec<String>>,
+    pub ruff_target_version: Option<String>,
+    pub package_version_overrides: Option<HashMap<String, String>>,
+    pub dependency_bound_style: Option<DependencyBoundStyle>,
+    pub type_checker: Option<TypeChecker>,
     pub use_dependabot: Option<bool>,
     pub dependabot_schedule: Option<DependabotSchedule>,
     pub dependabot_day: Option<Day>,
     pub use_continuous_deployment: Option<bool>,
     pub use_release_drafter: Option<bool>,
+    pub cloud_provider: Option<CloudProvider>,
+    pub cloud_deploy_role: Option<String>,
+    pub publish_docker: Option<bool>,
+    pub supply_chain_attestation: Option<bool>,
+    pub pypi_index_url: Option<String>,
+    pub publish_auth: Option<PublishAuth>,
+    pub include_testpypi_publish: Option<bool>,
+    pub include_rust_toolchain: Option<bool>,
+    pub rust_edition: Option<String>,
+    pub pyo3_version: Option<String>,
+    pub use_abi3: Option<bool>,
+    pub build_musllinux: Option<bool>,
+    pub maturin_targets: Option<Vec<String>>,
+    pub ci_job_timeout: Option<u16>,
+    pub ci_coverage_comment: Option<bool>,
+    pub uv_frozen: Option<bool>,
     pub use_multi_os_ci: Option<bool>,
     pub include_docs: Option<bool>,
     pub download_latest_packages: Option<bool>,
+    pub use_spinner: Option<bool>,
+    pub init_git: Option<bool>,
+    pub version_cache_ttl: Option<u64>,
 
     #[serde(skip)]
     config_dir: Rc<Option<PathBuf>>,
@@ -47,6 +165,7 @@ impl Default for Config {
         Self {
             creator: None,
             creator_email: None,
+            copyright_holder: None,
             license: None,
             python_version: None,
             min_python_version: None,
@@ -56,14 +175,54 @@ impl Default for Config {
             is_application: None,
             github_actions_python_test_versions: None,
             max_line_length: None,
+            ruff_line_length: None,
+            min_coverage: None,
+            extra_ruff_rules: None,
+            include_bandit: None,
+            include_conda_env: None,
+            include_codeowners: None,
+            github_username: None,
+            include_github_templates: None,
+            include_contributing: None,
+            include_codeql: None,
+            include_security_policy: None,
+            include_changelog: None,
+            include_citation: None,
+            include_benchmarks: None,
+            use_precommit_ci: None,
+            extra_pre_commit_hooks: None,
+            mypy_files: None,
+            ruff_target_version: None,
+            package_version_overrides: None,
+            dependency_bound_style: None,
+            type_checker: None,
             use_dependabot: None,
             dependabot_schedule: None,
             dependabot_day: None,
             use_continuous_deployment: None,
             use_release_drafter: None,
+            cloud_provider: None,
+            cloud_deploy_role: None,
+            publish_docker: None,
+            supply_chain_attestation: None,
+            pypi_index_url: None,
+            publish_auth: None,
+            include_testpypi_publish: None,
+            include_rust_toolchain: None,
+            rust_edition: None,
+            pyo3_version: None,
+            use_abi3: None,
+            build_musllinux: None,
+            maturin_targets: None,
+            ci_job_timeout: None,
+            ci_coverage_comment: None,
+            uv_frozen: None,
             use_multi_os_ci: None,
             include_docs: None,
             download_latest_packages: None,
+            use_spinner: None,
+            init_git: None,
+            version_cache_ttl: None,
             config_dir: config_dir(),
             config_file_path: config_file_path(),
         }
@@ -73,39 +232,106 @@ impl Default for Config {
 impl Config {
     pub fn load_config(&self) -> Self {
         if let Some(config_file) = &*self.config_file_path {
-            if config_file.exists() {
-                if let Ok(config_str) = read_to_string(config_file) {
-                    if let Ok(config) = serde_json::from_str::<Self>(&config_str) {
-                        return Self {
-                            creator: config.creator,
-                            creator_email: config.creator_email,
-                            license: config.license,
-                            python_version: config.python_version,
-                            min_python_version: config.min_python_version,
-                            project_manager: config.project_manager,
-                            pyo3_python_manager: config.pyo3_python_manager,
-                            is_async_project: config.is_async_project,
-                            is_application: config.is_application,
-                            github_actions_python_test_versions: config
-                                .github_actions_python_test_versions,
-                            max_line_length: config.max_line_length,
-                            use_dependabot: config.use_dependabot,
-                            dependabot_schedule: config.dependabot_schedule,
-                            dependabot_day: config.dependabot_day,
-                            use_continuous_deployment: config.use_continuous_deployment,
-                            use_release_drafter: config.use_release_drafter,
-                            use_multi_os_ci: config.use_multi_os_ci,
-                            include_docs: config.include_docs,
-                            download_latest_packages: config.download_latest_packages,
-                            config_dir: self.config_dir.clone(),
-                            config_file_path: self.config_file_path.clone(),
-                        };
-                    }
-                }
+            if let Some(config) = read_config_file(config_file) {
+                return Self {
+                    creator: config.creator,
+                    creator_email: config.creator_email,
+                    copyright_holder: config.copyright_holder,
+                    license: config.license,
+                    python_version: config.python_version,
+                    min_python_version: config.min_python_version,
+                    project_manager: config.project_manager,
+                    pyo3_python_manager: config.pyo3_python_manager,
+                    is_async_project: config.is_async_project,
+                    is_application: config.is_application,
+                    github_actions_python_test_versions: config.github_actions_python_test_versions,
+                    max_line_length: config.max_line_length,
+                    ruff_line_length: config.ruff_line_length,
+                    min_coverage: config.min_coverage,
+                    extra_ruff_rules: config.extra_ruff_rules,
+                    include_bandit: config.include_bandit,
+                    include_conda_env: config.include_conda_env,
+                    include_codeowners: config.include_codeowners,
+                    github_username: config.github_username,
+                    include_github_templates: config.include_github_templates,
+                    include_contributing: config.include_contributing,
+                    include_codeql: config.include_codeql,
+                    include_security_policy: config.include_security_policy,
+                    include_changelog: config.include_changelog,
+                    include_citation: config.include_citation,
+                    include_benchmarks: config.include_benchmarks,
+                    use_precommit_ci: config.use_precommit_ci,
+                    extra_pre_commit_hooks: config.extra_pre_commit_hooks,
+                    mypy_files: config.mypy_files,
+                    ruff_target_version: config.ruff_target_version,
+                    package_version_overrides: config.package_version_overrides,
+                    dependency_bound_style: config.dependency_bound_style,
+                    type_checker: config.type_checker,
+                    use_dependabot: config.use_dependabot,
+                    dependabot_schedule: config.dependabot_schedule,
+                    dependabot_day: config.dependabot_day,
+                    use_continuous_deployment: config.use_continuous_deployment,
+                    use_release_drafter: config.use_release_drafter,
+                    cloud_provider: config.cloud_provider,
+                    cloud_deploy_role: config.cloud_deploy_role,
+                    publish_docker: config.publish_docker,
+                    supply_chain_attestation: config.supply_chain_attestation,
+                    pypi_index_url: config.pypi_index_url,
+                    publish_auth: config.publish_auth,
+                    include_testpypi_publish: config.include_testpypi_publish,
+                    include_rust_toolchain: config.include_rust_toolchain,
+                    rust_edition: config.rust_edition,
+                    pyo3_version: config.pyo3_version,
+                    use_abi3: config.use_abi3,
+                    build_musllinux: config.build_musllinux,
+                    maturin_targets: config.maturin_targets,
+                    ci_job_timeout: config.ci_job_timeout,
+                    ci_coverage_comment: config.ci_coverage_comment,
+                    uv_frozen: config.uv_frozen,
+                    use_multi_os_ci: config.use_multi_os_ci,
+                    include_docs: config.include_docs,
+                    download_latest_packages: config.download_latest_packages,
+                    use_spinner: config.use_spinner,
+                    init_git: config.init_git,
+                    version_cache_ttl: config.version_cache_ttl,
+                    config_dir: self.config_dir.clone(),
+                    config_file_path: self.config_file_path.clone(),
+                };
+            }
+        };
+
+        Self {
+            config_dir: self.config_dir.clone(),
+            config_file_path: self.config_file_path.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns a copy of the config with the values in `overrides` replacing the saved values.
+    /// The overrides go through the same deserialization as a saved config file.
+    pub fn with_overrides(&self, overrides: Map<String, Value>) -> Result<Self> {
+        let Value::Object(mut values) = serde_json::to_value(self)? else {
+            bail!("Unable to read the config values");
+        };
+
+        for (key, value) in overrides {
+            if !CONFIG_KEYS.contains(&key.as_str()) {
+                bail!(format!("{key} is not a valid config key"));
             }
+
+            values.insert(key, value);
+        }
+
+        let config = match serde_json::from_value::<Self>(Value::Object(values)) {
+            Ok(c) => c,
+            Err(e) => bail!(format!("Invalid config override value: {e}")),
         };
 
-        Self::default()
+        Ok(Self {
+            config_dir: self.config_dir.clone(),
+            config_file_path: self.config_file_path.clone(),
+            ..config
+        })
     }
 
     pub fn reset() -> Result<()> {
@@ -124,8 +350,12 @@ impl Config {
 
                 match &*self.config_file_path {
                     Some(c) => {
-                        let config_file = File::create(c)?;
-                        serde_json::to_writer_pretty(config_file, self)?;
+                        let config_str = if is_toml_file(c) {
+                            toml::to_string_pretty(self)?
+                        } else {
+                            serde_json::to_string_pretty(self)?
+                        };
+                        write(c, config_str)?;
                     }
                     None => {
                         bail!("Error saving config file");
@@ -133,7 +363,7 @@ impl Config {
                 }
             }
             None => {
-                bail!("Error saving config file");
+                bail!("Error saving config file, no config directory could be located. Set the XDG_CONFIG_HOME environment variable, or set PPG_CONFIG_DIR to the directory the config should be saved in");
             }
         }
 
@@ -151,6 +381,10 @@ impl Config {
     }
 
     pub fn save_creator_email(&self, value: String) -> Result<()> {
+        if !is_valid_email(&value) {
+            bail!(format!("{value} is not a valid email address"));
+        }
+
         self.handle_save_config(|config| &mut config.creator_email, Some(value))?;
         Ok(())
     }
@@ -160,6 +394,16 @@ impl Config {
         Ok(())
     }
 
+    pub fn save_copyright_holder(&self, value: String) -> Result<()> {
+        self.handle_save_config(|config| &mut config.copyright_holder, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_copyright_holder(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.copyright_holder, None)?;
+        Ok(())
+    }
+
     pub fn save_license(&self, value: LicenseType) -> Result<()> {
         self.handle_save_config(|config| &mut config.license, Some(value))?;
         Ok(())
@@ -251,7 +495,7 @@ impl Config {
                 .collect::<Vec<String>>();
 
             for version in &versions {
-                if !is_valid_python_version(&version.replace('"', "")) {
+                if !is_valid_python_test_version(&version.replace('"', "")) {
                     bail!(format!("{} is not a valid Python Version", version));
                 }
             }
@@ -276,6 +520,316 @@ impl Config {
         Ok(())
     }
 
+    pub fn save_ruff_line_length(&self, value: u8) -> Result<()> {
+        if !(40..=200).contains(&value) {
+            bail!("The ruff line length must be between 40 and 200");
+        }
+
+        self.handle_save_config(|config| &mut config.ruff_line_length, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_ruff_line_length(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.ruff_line_length, None)?;
+        Ok(())
+    }
+
+    pub fn save_min_coverage(&self, value: u8) -> Result<()> {
+        if value > 100 {
+            bail!(format!(
+                "{value} is not a valid minimum coverage. The value must be between 0 and 100"
+            ));
+        }
+
+        self.handle_save_config(|config| &mut config.min_coverage, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_min_coverage(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.min_coverage, None)?;
+        Ok(())
+    }
+
+    pub fn save_extra_ruff_rules(&self, value: String) -> Result<()> {
+        self.handle_save_extra_ruff_rules(Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_extra_ruff_rules(&self) -> Result<()> {
+        self.handle_save_extra_ruff_rules(None)?;
+        Ok(())
+    }
+
+    fn handle_save_extra_ruff_rules(&self, value: Option<String>) -> Result<()> {
+        let mut config = self.load_config();
+
+        if let Some(v) = value {
+            let mut rules: Vec<String> = Vec::new();
+
+            for rule in v.replace(' ', "").split(',') {
+                if !is_valid_ruff_rule(rule) {
+                    bail!(format!("{} is not a valid ruff rule", rule));
+                }
+
+                if !DEFAULT_RUFF_RULES.contains(&rule) && !rules.iter().any(|r| r == rule) {
+                    rules.push(rule.to_string());
+                }
+            }
+
+            config.extra_ruff_rules = Some(rules);
+        } else {
+            config.extra_ruff_rules = None;
+        }
+
+        config.save()?;
+
+        Ok(())
+    }
+
+    pub fn save_include_bandit(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_bandit, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_include_bandit(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_bandit, None)?;
+        Ok(())
+    }
+
+    pub fn save_include_conda_env(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_conda_env, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_include_conda_env(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_conda_env, None)?;
+        Ok(())
+    }
+
+    pub fn save_include_codeowners(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_codeowners, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_include_codeowners(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_codeowners, None)?;
+        Ok(())
+    }
+
+    pub fn save_github_username(&self, value: String) -> Result<()> {
+        let value = value.trim().trim_start_matches('@').to_string();
+
+        if value.is_empty() || !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            bail!(format!("{value} is not a valid GitHub username"));
+        }
+
+        self.handle_save_config(|config| &mut config.github_username, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_github_username(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.github_username, None)?;
+        Ok(())
+    }
+
+    pub fn save_include_github_templates(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_github_templates, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_include_github_templates(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_github_templates, None)?;
+        Ok(())
+    }
+
+    pub fn save_include_contributing(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_contributing, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_include_contributing(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_contributing, None)?;
+        Ok(())
+    }
+
+    pub fn save_include_codeql(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_codeql, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_include_codeql(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_codeql, None)?;
+        Ok(())
+    }
+
+    pub fn save_include_security_policy(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_security_policy, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_include_security_policy(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_security_policy, None)?;
+        Ok(())
+    }
+
+    pub fn save_include_changelog(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_changelog, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_include_changelog(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_changelog, None)?;
+        Ok(())
+    }
+
+    pub fn save_include_citation(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_citation, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_include_citation(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_citation, None)?;
+        Ok(())
+    }
+
+    pub fn save_include_benchmarks(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_benchmarks, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_include_benchmarks(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_benchmarks, None)?;
+        Ok(())
+    }
+
+    pub fn save_use_precommit_ci(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.use_precommit_ci, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_use_precommit_ci(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.use_precommit_ci, None)?;
+        Ok(())
+    }
+
+    pub fn save_extra_pre_commit_hooks(&self, value: Vec<ExtraPreCommitHook>) -> Result<()> {
+        let mut hooks: Vec<ExtraPreCommitHook> = Vec::new();
+        for hook in value {
+            if !hooks.contains(&hook) {
+                hooks.push(hook);
+            }
+        }
+
+        self.handle_save_config(|config| &mut config.extra_pre_commit_hooks, Some(hooks))?;
+        Ok(())
+    }
+
+    pub fn reset_extra_pre_commit_hooks(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.extra_pre_commit_hooks, None)?;
+        Ok(())
+    }
+
+    pub fn save_mypy_files(&self, value: String) -> Result<()> {
+        let files: Vec<String> = value
+            .split(',')
+            .map(|f| f.trim().to_string())
+            .filter(|f| !f.is_empty())
+            .collect();
+
+        if files.is_empty() {
+            bail!("At least one file or directory is required for mypy files");
+        }
+
+        self.handle_save_config(|config| &mut config.mypy_files, Some(files))?;
+        Ok(())
+    }
+
+    pub fn reset_mypy_files(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.mypy_files, None)?;
+        Ok(())
+    }
+
+    pub fn save_ruff_target_version(&self, value: String) -> Result<()> {
+        if !is_valid_python_version(&value) {
+            bail!(format!("{value} is not a valid Python Version"));
+        }
+
+        self.handle_save_config(|config| &mut config.ruff_target_version, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_ruff_target_version(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.ruff_target_version, None)?;
+        Ok(())
+    }
+
+    /// Pins a package to a version that is used instead of the latest or default version. This
+    /// backs both `config set-package-version` and `versions set`.
+    pub fn save_package_version(&self, name: String, version: String) -> Result<()> {
+        let name = name.trim().to_lowercase();
+        let version = version.trim().to_string();
+
+        if name.is_empty() || version.is_empty() {
+            bail!("A package name and version are required");
+        }
+
+        validate_package_name(&name)?;
+
+        let mut overrides = self
+            .load_config()
+            .package_version_overrides
+            .unwrap_or_default();
+        overrides.insert(name, version);
+        self.handle_save_config(
+            |config| &mut config.package_version_overrides,
+            Some(overrides),
+        )?;
+        Ok(())
+    }
+
+    /// Removes a single pinned version. The map is removed entirely once it is empty so the
+    /// config matches one where no versions were ever pinned.
+    pub fn reset_package_version(&self, name: String) -> Result<()> {
+        let name = name.trim().to_lowercase();
+        let mut overrides = self
+            .load_config()
+            .package_version_overrides
+            .unwrap_or_default();
+
+        if overrides.remove(&name).is_none() {
+            bail!("No pinned version found for {name}");
+        }
+
+        let overrides = (!overrides.is_empty()).then_some(overrides);
+        self.handle_save_config(|config| &mut config.package_version_overrides, overrides)?;
+        Ok(())
+    }
+
+    pub fn reset_package_versions(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.package_version_overrides, None)?;
+        Ok(())
+    }
+
+    pub fn save_dependency_bound_style(&self, value: DependencyBoundStyle) -> Result<()> {
+        self.handle_save_config(|config| &mut config.dependency_bound_style, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_dependency_bound_style(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.dependency_bound_style, None)?;
+        Ok(())
+    }
+
+    pub fn save_type_checker(&self, value: TypeChecker) -> Result<()> {
+        self.handle_save_config(|config| &mut config.type_checker, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_type_checker(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.type_checker, None)?;
+        Ok(())
+    }
+
     pub fn save_use_dependabot(&self, value: bool) -> Result<()> {
         self.handle_save_config(|config| &mut config.use_dependabot, Some(value))?;
         Ok(())
@@ -326,514 +880,2444 @@ impl Config {
         Ok(())
     }
 
-    pub fn save_use_multi_os_ci(&self, value: bool) -> Result<()> {
-        self.handle_save_config(|config| &mut config.use_multi_os_ci, Some(value))?;
+    pub fn save_cloud_provider(&self, value: CloudProvider) -> Result<()> {
+        self.handle_save_config(|config| &mut config.cloud_provider, Some(value))?;
         Ok(())
     }
 
-    pub fn reset_use_multi_os_ci(&self) -> Result<()> {
-        self.handle_save_config(|config| &mut config.use_multi_os_ci, None)?;
+    pub fn reset_cloud_provider(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.cloud_provider, None)?;
         Ok(())
     }
 
-    pub fn save_include_docs(&self, value: bool) -> Result<()> {
-        self.handle_save_config(|config| &mut config.include_docs, Some(value))?;
+    pub fn save_cloud_deploy_role(&self, value: String) -> Result<()> {
+        if value.trim().is_empty() {
+            bail!("The cloud deploy role can't be empty");
+        }
+
+        self.handle_save_config(|config| &mut config.cloud_deploy_role, Some(value))?;
         Ok(())
     }
 
-    pub fn reset_include_docs(&self) -> Result<()> {
-        self.handle_save_config(|config| &mut config.include_docs, None)?;
+    pub fn reset_cloud_deploy_role(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.cloud_deploy_role, None)?;
         Ok(())
     }
 
-    pub fn save_download_latest_packages(&self, value: bool) -> Result<()> {
-        self.handle_save_config(|config| &mut config.download_latest_packages, Some(value))?;
+    pub fn save_publish_docker(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.publish_docker, Some(value))?;
         Ok(())
     }
 
-    pub fn reset_download_latest_packages(&self) -> Result<()> {
-        self.handle_save_config(|config| &mut config.download_latest_packages, None)?;
+    pub fn reset_publish_docker(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.publish_docker, None)?;
         Ok(())
     }
 
-    fn handle_save_config<F, T>(&self, func: F, value: Option<T>) -> Result<()>
-    where
-        F: FnOnce(&mut Self) -> &mut Option<T>,
-    {
-        let mut config = self.load_config();
-        let field = func(&mut config);
-        *field = value;
-        config.save()?;
-
+    pub fn save_supply_chain_attestation(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.supply_chain_attestation, Some(value))?;
         Ok(())
     }
 
-    pub fn show(&self) {
-        let config = self.load_config();
-        print_config_value("Creator", &config.creator);
-        print_config_value("Creator Email", &config.creator_email);
-        print_config_value("License", &config.license);
-        print_config_value("Python Version", &config.python_version);
-        print_config_value("Min Python Version", &config.min_python_version);
+    pub fn reset_supply_chain_attestation(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.supply_chain_attestation, None)?;
+        Ok(())
+    }
 
-        let is_application_label = "Application or Library";
-        if let Some(is_application) = config.is_application {
-            if is_application {
-                println!("{}: application", is_application_label.blue());
-            } else {
-                println!("{}: lib", is_application_label.blue());
-            }
-        } else {
-            println!("{}: null", is_application_label.blue());
-        }
+    pub fn save_pypi_index_url(&self, value: String) -> Result<()> {
+        let value = value.trim().to_string();
 
-        let gha_python_label = "Python Versions for Github Actions Testing";
-        if let Some(gha_python) = config.github_actions_python_test_versions {
-            let gha_python_str = gha_python.join(", ");
-            println!("{}: {gha_python_str}", gha_python_label.blue());
-        } else {
-            println!("{}: null", gha_python_label.blue());
+        if !value.starts_with("https://") && !value.starts_with("http://") {
+            bail!(format!("{value} is not a valid package index URL"));
         }
 
-        print_config_value("Project Manager", &config.project_manager);
-        print_config_value("PyO3 Python Manager", &config.pyo3_python_manager);
-        print_config_value("Async Project", &config.is_async_project);
-        print_config_value("Max Line Length", &config.max_line_length);
-        print_config_value("Use Dependabot", &config.use_dependabot);
-        print_config_value("Dependabot Schedule", &config.dependabot_schedule);
-        print_config_value("Dependabot Day", &config.dependabot_day);
-        print_config_value(
-            "Use Continuous Deployment",
-            &config.use_continuous_deployment,
-        );
-        print_config_value("Use Release Drafter", &config.use_release_drafter);
-        print_config_value("Use Multi OS CI", &config.use_multi_os_ci);
-        print_config_value("Include Docs", &config.include_docs);
-        print_config_value("Download Latest Packages", &config.download_latest_packages);
+        self.handle_save_config(|config| &mut config.pypi_index_url, Some(value))?;
+        Ok(())
     }
-}
 
+    pub fn reset_pypi_index_url(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.pypi_index_url, None)?;
+        Ok(())
+    }
+
+    pub fn save_publish_auth(&self, value: PublishAuth) -> Result<()> {
+        self.handle_save_config(|config| &mut config.publish_auth, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_publish_auth(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.publish_auth, None)?;
+        Ok(())
+    }
+
+    pub fn save_include_testpypi_publish(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_testpypi_publish, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_include_testpypi_publish(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_testpypi_publish, None)?;
+        Ok(())
+    }
+
+    pub fn save_include_rust_toolchain(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_rust_toolchain, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_include_rust_toolchain(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_rust_toolchain, None)?;
+        Ok(())
+    }
+
+    pub fn save_rust_edition(&self, value: String) -> Result<()> {
+        let value = value.trim().to_string();
+
+        if !is_valid_rust_edition(&value) {
+            bail!(format!("{value} is not a valid Rust edition"));
+        }
+
+        self.handle_save_config(|config| &mut config.rust_edition, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_rust_edition(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.rust_edition, None)?;
+        Ok(())
+    }
+
+    pub fn save_pyo3_version(&self, value: String) -> Result<()> {
+        let value = value.trim().to_string();
+
+        if !is_valid_crate_version(&value) {
+            bail!(format!("{value} is not a valid PyO3 version"));
+        }
+
+        self.handle_save_config(|config| &mut config.pyo3_version, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_pyo3_version(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.pyo3_version, None)?;
+        Ok(())
+    }
+
+    pub fn save_use_abi3(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.use_abi3, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_use_abi3(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.use_abi3, None)?;
+        Ok(())
+    }
+
+    pub fn save_build_musllinux(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.build_musllinux, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_build_musllinux(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.build_musllinux, None)?;
+        Ok(())
+    }
+
+    pub fn save_maturin_targets(&self, value: String) -> Result<()> {
+        let targets: Vec<String> = value
+            .split(',')
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+
+        if targets.is_empty() {
+            bail!("At least one target is required for Maturin targets");
+        }
+
+        if let Some(invalid) = targets.iter().find(|t| !is_valid_maturin_target(t)) {
+            bail!(format!("{invalid} is not a valid Maturin target"));
+        }
+
+        self.handle_save_config(|config| &mut config.maturin_targets, Some(targets))?;
+        Ok(())
+    }
+
+    pub fn reset_maturin_targets(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.maturin_targets, None)?;
+        Ok(())
+    }
+
+    pub fn save_ci_job_timeout(&self, value: u16) -> Result<()> {
+        if value == 0 {
+            bail!("The CI job timeout must be at least 1 minute");
+        }
+
+        self.handle_save_config(|config| &mut config.ci_job_timeout, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_ci_job_timeout(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.ci_job_timeout, None)?;
+        Ok(())
+    }
+
+    pub fn save_ci_coverage_comment(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.ci_coverage_comment, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_ci_coverage_comment(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.ci_coverage_comment, None)?;
+        Ok(())
+    }
+
+    pub fn save_uv_frozen(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.uv_frozen, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_uv_frozen(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.uv_frozen, None)?;
+        Ok(())
+    }
+
+    pub fn save_use_multi_os_ci(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.use_multi_os_ci, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_use_multi_os_ci(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.use_multi_os_ci, None)?;
+        Ok(())
+    }
+
+    pub fn save_include_docs(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_docs, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_include_docs(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.include_docs, None)?;
+        Ok(())
+    }
+
+    pub fn save_download_latest_packages(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.download_latest_packages, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_download_latest_packages(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.download_latest_packages, None)?;
+        Ok(())
+    }
+
+    pub fn save_use_spinner(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.use_spinner, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_use_spinner(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.use_spinner, None)?;
+        Ok(())
+    }
+
+    pub fn save_init_git(&self, value: bool) -> Result<()> {
+        self.handle_save_config(|config| &mut config.init_git, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_init_git(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.init_git, None)?;
+        Ok(())
+    }
+
+    pub fn save_version_cache_ttl(&self, value: u64) -> Result<()> {
+        self.handle_save_config(|config| &mut config.version_cache_ttl, Some(value))?;
+        Ok(())
+    }
+
+    pub fn reset_version_cache_ttl(&self) -> Result<()> {
+        self.handle_save_config(|config| &mut config.version_cache_ttl, None)?;
+        Ok(())
+    }
+
+    /// Named profiles are stored as separate config files in a directory next to the config file.
+    fn profiles_dir(&self) -> Result<PathBuf> {
+        match &*self.config_dir {
+            Some(c) => Ok(c.join("profiles")),
+            None => bail!("No config directory could be located for profiles"),
+        }
+    }
+
+    fn profile_path(&self, name: &str) -> Result<PathBuf> {
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            bail!(format!(
+                "{name} is not a valid profile name, only letters, numbers, - and _ are allowed"
+            ));
+        }
+
+        Ok(self.profiles_dir()?.join(format!("{name}.toml")))
+    }
+
+    /// Snapshots the current config values into a named profile, replacing the profile if it
+    /// already exists.
+    pub fn save_profile(&self, name: &str) -> Result<()> {
+        let path = self.profile_path(name)?;
+        let config = self.load_config();
+
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        write(path, toml::to_string_pretty(&config)?)?;
+
+        Ok(())
+    }
+
+    pub fn list_profiles(&self) -> Result<Vec<String>> {
+        let profiles_dir = self.profiles_dir()?;
+
+        if !profiles_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut profiles: Vec<String> = read_dir(profiles_dir)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| is_toml_file(path))
+            .filter_map(|path| path.file_stem().map(|s| s.to_string_lossy().to_string()))
+            .collect();
+        profiles.sort();
+
+        Ok(profiles)
+    }
+
+    pub fn delete_profile(&self, name: &str) -> Result<()> {
+        let path = self.profile_path(name)?;
+
+        if !path.exists() {
+            bail!(format!("No profile named {name} exists"));
+        }
+
+        remove_file(path)?;
+
+        Ok(())
+    }
+
+    /// Loads a profile's values in place of the saved config. Saving the returned config still
+    /// writes to the main config file.
+    pub fn load_profile(&self, name: &str) -> Result<Self> {
+        let path = self.profile_path(name)?;
+
+        if !path.exists() {
+            bail!(format!("No profile named {name} exists"));
+        }
+
+        let Some(config) = read_config_file(&path) else {
+            bail!(format!("Unable to read the {name} profile"));
+        };
+
+        Ok(Self {
+            config_dir: self.config_dir.clone(),
+            config_file_path: self.config_file_path.clone(),
+            ..config
+        })
+    }
+
+    /// The cache of latest package versions is kept next to the config file.
+    pub fn version_cache_path(&self) -> Option<PathBuf> {
+        self.config_dir
+            .as_ref()
+            .as_ref()
+            .map(|c| c.join("version_cache.json"))
+    }
+
+    fn handle_save_config<F, T>(&self, func: F, value: Option<T>) -> Result<()>
+    where
+        F: FnOnce(&mut Self) -> &mut Option<T>,
+    {
+        let mut config = self.load_config();
+        let field = func(&mut config);
+        *field = value;
+        config.save()?;
+
+        Ok(())
+    }
+
+    pub fn get(&self, key: &str) -> Result<String> {
+        let config = self.load_config();
+        let value = match key {
+            "creator" => config_value_str(&config.creator),
+            "creator_email" => config_value_str(&config.creator_email),
+            "copyright_holder" => config_value_str(&config.copyright_holder),
+            "license" => config_value_str(&config.license),
+            "python_version" => config_value_str(&config.python_version),
+            "min_python_version" => config_value_str(&config.min_python_version),
+            "project_manager" => config_value_str(&config.project_manager),
+            "pyo3_python_manager" => config_value_str(&config.pyo3_python_manager),
+            "is_async_project" => config_value_str(&config.is_async_project),
+            "is_application" => config_value_str(&config.is_application),
+            "github_actions_python_test_versions" => {
+                config_list_str(&config.github_actions_python_test_versions)
+            }
+            "max_line_length" => config_value_str(&config.max_line_length),
+            "ruff_line_length" => config_value_str(&config.ruff_line_length),
+            "min_coverage" => config_value_str(&config.min_coverage),
+            "extra_ruff_rules" => config_list_str(&config.extra_ruff_rules),
+            "include_bandit" => config_value_str(&config.include_bandit),
+            "include_conda_env" => config_value_str(&config.include_conda_env),
+            "include_codeowners" => config_value_str(&config.include_codeowners),
+            "github_username" => config_value_str(&config.github_username),
+            "include_github_templates" => config_value_str(&config.include_github_templates),
+            "include_contributing" => config_value_str(&config.include_contributing),
+            "include_codeql" => config_value_str(&config.include_codeql),
+            "include_security_policy" => config_value_str(&config.include_security_policy),
+            "include_changelog" => config_value_str(&config.include_changelog),
+            "include_citation" => config_value_str(&config.include_citation),
+            "include_benchmarks" => config_value_str(&config.include_benchmarks),
+            "use_precommit_ci" => config_value_str(&config.use_precommit_ci),
+            "extra_pre_commit_hooks" => config_list_str(&config.extra_pre_commit_hooks),
+            "mypy_files" => config_list_str(&config.mypy_files),
+            "ruff_target_version" => config_value_str(&config.ruff_target_version),
+            "package_version_overrides" => {
+                config_list_str(&config.package_version_overrides.map(|overrides| {
+                    let mut overrides: Vec<String> = overrides
+                        .iter()
+                        .map(|(name, version)| format!("{name}=={version}"))
+                        .collect();
+                    overrides.sort();
+                    overrides
+                }))
+            }
+            "dependency_bound_style" => config_value_str(&config.dependency_bound_style),
+            "type_checker" => config_value_str(&config.type_checker),
+            "use_dependabot" => config_value_str(&config.use_dependabot),
+            "dependabot_schedule" => config_value_str(&config.dependabot_schedule),
+            "dependabot_day" => config_value_str(&config.dependabot_day),
+            "use_continuous_deployment" => config_value_str(&config.use_continuous_deployment),
+            "use_release_drafter" => config_value_str(&config.use_release_drafter),
+            "cloud_provider" => config_value_str(&config.cloud_provider),
+            "cloud_deploy_role" => config_value_str(&config.cloud_deploy_role),
+            "publish_docker" => config_value_str(&config.publish_docker),
+            "supply_chain_attestation" => config_value_str(&config.supply_chain_attestation),
+            "pypi_index_url" => config_value_str(&config.pypi_index_url),
+            "publish_auth" => config_value_str(&config.publish_auth),
+            "include_testpypi_publish" => config_value_str(&config.include_testpypi_publish),
+            "include_rust_toolchain" => config_value_str(&config.include_rust_toolchain),
+            "rust_edition" => config_value_str(&config.rust_edition),
+            "pyo3_version" => config_value_str(&config.pyo3_version),
+            "use_abi3" => config_value_str(&config.use_abi3),
+            "build_musllinux" => config_value_str(&config.build_musllinux),
+            "maturin_targets" => config_list_str(&config.maturin_targets),
+            "ci_job_timeout" => config_value_str(&config.ci_job_timeout),
+            "ci_coverage_comment" => config_value_str(&config.ci_coverage_comment),
+            "uv_frozen" => config_value_str(&config.uv_frozen),
+            "use_multi_os_ci" => config_value_str(&config.use_multi_os_ci),
+            "include_docs" => config_value_str(&config.include_docs),
+            "download_latest_packages" => config_value_str(&config.download_latest_packages),
+            "use_spinner" => config_value_str(&config.use_spinner),
+            "init_git" => config_value_str(&config.init_git),
+            "version_cache_ttl" => config_value_str(&config.version_cache_ttl),
+            _ => bail!(
+                "{key} is not a valid config key, valid keys are: {}",
+                CONFIG_KEYS.join(", ")
+            ),
+        };
+
+        Ok(value)
+    }
+
+    pub fn show(&self) {
+        let config = self.load_config();
+        print_config_value("Creator", &config.creator);
+        print_config_value("Creator Email", &config.creator_email);
+        print_config_value("Copyright Holder", &config.copyright_holder);
+        print_config_value("License", &config.license);
+        print_config_value("Python Version", &config.python_version);
+        print_config_value("Min Python Version", &config.min_python_version);
+
+        let is_application_label = "Application or Library";
+        if let Some(is_application) = config.is_application {
+            if is_application {
+                println!("{}: application", is_application_label.blue());
+            } else {
+                println!("{}: lib", is_application_label.blue());
+            }
+        } else {
+            println!("{}: null", is_application_label.blue());
+        }
+
+        let gha_python_label = "Python Versions for Github Actions Testing";
+        if let Some(gha_python) = config.github_actions_python_test_versions {
+            let gha_python_str = gha_python.join(", ");
+            println!("{}: {gha_python_str}", gha_python_label.blue());
+        } else {
+            println!("{}: null", gha_python_label.blue());
+        }
+
+        print_config_value("Project Manager", &config.project_manager);
+        print_config_value("PyO3 Python Manager", &config.pyo3_python_manager);
+        print_config_value("Async Project", &config.is_async_project);
+        print_config_value("Max Line Length", &config.max_line_length);
+        print_config_value("Ruff Line Length", &config.ruff_line_length);
+        print_config_value("Min Coverage", &config.min_coverage);
+
+        let extra_ruff_rules_label = "Extra Ruff Rules";
+        if let Some(extra_ruff_rules) = config.extra_ruff_rules {
+            let extra_ruff_rules_str = extra_ruff_rules.join(", ");
+            println!("{}: {extra_ruff_rules_str}", extra_ruff_rules_label.blue());
+        } else {
+            println!("{}: null", extra_ruff_rules_label.blue());
+        }
+
+        print_config_value("Include Bandit", &config.include_bandit);
+        print_config_value("Include Conda Env", &config.include_conda_env);
+        print_config_value("Include CODEOWNERS", &config.include_codeowners);
+        print_config_value("GitHub Username", &config.github_username);
+        print_config_value("Include GitHub Templates", &config.include_github_templates);
+        print_config_value("Include Contributing", &config.include_contributing);
+        print_config_value("Include CodeQL", &config.include_codeql);
+        print_config_value("Include Security Policy", &config.include_security_policy);
+        print_config_value("Include Changelog", &config.include_changelog);
+        print_config_value("Include Citation", &config.include_citation);
+        print_config_value("Include Benchmarks", &config.include_benchmarks);
+        print_config_value("Use pre-commit.ci", &config.use_precommit_ci);
+
+        let extra_pre_commit_hooks_label = "Extra Pre-commit Hooks";
+        if let Some(extra_pre_commit_hooks) = config.extra_pre_commit_hooks {
+            let extra_pre_commit_hooks_str = extra_pre_commit_hooks
+                .iter()
+                .map(|hook| hook.to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+            println!(
+                "{}: {extra_pre_commit_hooks_str}",
+                extra_pre_commit_hooks_label.blue()
+            );
+        } else {
+            println!("{}: null", extra_pre_commit_hooks_label.blue());
+        }
+
+        let mypy_files_label = "Mypy Files";
+        if let Some(mypy_files) = config.mypy_files {
+            let mypy_files_str = mypy_files.join(", ");
+            println!("{}: {mypy_files_str}", mypy_files_label.blue());
+        } else {
+            println!("{}: null", mypy_files_label.blue());
+        }
+
+        print_config_value("Ruff Target Version", &config.ruff_target_version);
+
+        let package_version_overrides_label = "Package Version Overrides";
+        if let Some(overrides) = config.package_version_overrides {
+            let mut overrides: Vec<String> = overrides
+                .iter()
+                .map(|(name, version)| format!("{name}=={version}"))
+                .collect();
+            overrides.sort();
+            println!(
+                "{}: {}",
+                package_version_overrides_label.blue(),
+                overrides.join(", ")
+            );
+        } else {
+            println!("{}: null", package_version_overrides_label.blue());
+        }
+
+        print_config_value("Dependency Bound Style", &config.dependency_bound_style);
+        print_config_value("Type Checker", &config.type_checker);
+
+        print_config_value("Use Dependabot", &config.use_dependabot);
+        print_config_value("Dependabot Schedule", &config.dependabot_schedule);
+        print_config_value("Dependabot Day", &config.dependabot_day);
+        print_config_value(
+            "Use Continuous Deployment",
+            &config.use_continuous_deployment,
+        );
+        print_config_value("Use Release Drafter", &config.use_release_drafter);
+        print_config_value("Cloud Provider", &config.cloud_provider);
+        print_config_value("Cloud Deploy Role", &config.cloud_deploy_role);
+        print_config_value("Publish Docker", &config.publish_docker);
+        print_config_value("Supply Chain Attestation", &config.supply_chain_attestation);
+        print_config_value("PyPI Index URL", &config.pypi_index_url);
+        print_config_value("Publish Auth", &config.publish_auth);
+        print_config_value("Include TestPyPI Publish", &config.include_testpypi_publish);
+        print_config_value("Include Rust Toolchain", &config.include_rust_toolchain);
+        print_config_value("Rust Edition", &config.rust_edition);
+        print_config_value("PyO3 Version", &config.pyo3_version);
+        print_config_value("Use abi3", &config.use_abi3);
+        print_config_value("Build Musllinux", &config.build_musllinux);
+        println!(
+            "{}: {}",
+            "Maturin Targets".blue(),
+            config_list_str(&config.maturin_targets)
+        );
+        print_config_value("CI Job Timeout", &config.ci_job_timeout);
+        print_config_value("CI Coverage Comment", &config.ci_coverage_comment);
+        print_config_value("uv Frozen", &config.uv_frozen);
+        print_config_value("Use Multi OS CI", &config.use_multi_os_ci);
+        print_config_value("Include Docs", &config.include_docs);
+        print_config_value("Download Latest Packages", &config.download_latest_packages);
+        print_config_value("Use Spinner", &config.use_spinner);
+        print_config_value("Initialize Git", &config.init_git);
+        print_config_value("Version Cache TTL", &config.version_cache_ttl);
+    }
+}
+
+/// Sets the config file used by every `Config` created afterwards. This takes precedence over
+/// the `PPG_CONFIG_FILE` and `PPG_CONFIG_DIR` environment variables.
+pub fn set_config_file(path: Option<PathBuf>) {
+    *CONFIG_FILE_FLAG.write().unwrap_or_else(|e| e.into_inner()) = path;
+}
+
+fn config_file_override() -> Option<PathBuf> {
+    if let Some(config_file) = CONFIG_FILE_FLAG
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+    {
+        return Some(config_file);
+    }
+
+    env::var_os("PPG_CONFIG_FILE")
+        .filter(|c| !c.is_empty())
+        .map(PathBuf::from)
+}
+
+/// `PPG_CONFIG_DIR` is used as is when set, otherwise a python-project-generator directory is
+/// created in the platform's config directory. When `PPG_CONFIG_FILE` is set its parent
+/// directory is used instead.
 fn config_dir() -> Rc<Option<PathBuf>> {
-    let config_dir: Option<PathBuf> = dirs::config_dir();
+    if let Some(config_file) = config_file_override() {
+        let parent = config_file
+            .parent()
+            .map(|p| p.to_path_buf())
+            .unwrap_or_default();
+        return Rc::new(Some(parent));
+    }
+
+    if let Some(c) = env::var_os("PPG_CONFIG_DIR").filter(|c| !c.is_empty()) {
+        return Rc::new(Some(PathBuf::from(c)));
+    }
+
+    let config_dir: Option<PathBuf> = dirs::config_dir();
+
+    if let Some(mut c) = config_dir {
+        c.push("python-project-generator");
+        return Rc::new(Some(c));
+    }
+
+    Rc::new(None)
+}
+
+fn config_file_path() -> Rc<Option<PathBuf>> {
+    if let Some(config_file) = config_file_override() {
+        return Rc::new(Some(config_file));
+    }
+
+    if let Some(c) = &config_dir().as_ref() {
+        let mut c = c.clone();
+        c.push("config.toml");
+        return Rc::new(Some(c));
+    };
+
+    Rc::new(None)
+}
+
+fn is_toml_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "toml")
+}
+
+/// Drops values that no longer match their field's type so one bad value doesn't cause the whole
+/// config to be discarded.
+fn remove_invalid_config_values(values: &mut Map<String, Value>) {
+    values.retain(|key, value| {
+        let mut single_value = Map::new();
+        single_value.insert(key.clone(), value.clone());
+        let is_valid = serde_json::from_value::<Config>(Value::Object(single_value)).is_ok();
+
+        if !is_valid {
+            let message = format!("Ignoring invalid saved config value for {key}");
+            eprintln!("{}", message.yellow());
+        }
+
+        is_valid
+    });
+}
+
+/// Reads the config as TOML or JSON depending on the file extension. When a TOML config doesn't
+/// exist yet a legacy `config.json` in the same directory is read instead.
+fn read_config_file(config_file: &Path) -> Option<Config> {
+    let path = if config_file.exists() {
+        config_file.to_path_buf()
+    } else {
+        let legacy_config_file = config_file.with_extension("json");
+        if !legacy_config_file.exists() {
+            return None;
+        }
+        legacy_config_file
+    };
+
+    let config_str = read_to_string(&path).ok()?;
+    let mut values: Map<String, Value> = if is_toml_file(&path) {
+        toml::from_str(&config_str).ok()?
+    } else {
+        serde_json::from_str(&config_str).ok()?
+    };
+
+    remove_invalid_config_values(&mut values);
+
+    serde_json::from_value(Value::Object(values)).ok()
+}
+
+fn config_value_str<T: Display>(value: &Option<T>) -> String {
+    match value {
+        Some(v) => v.to_string(),
+        None => "null".to_string(),
+    }
+}
+
+fn config_list_str<T: Display>(values: &Option<Vec<T>>) -> String {
+    match values {
+        Some(v) => v
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>()
+            .join(", "),
+        None => "null".to_string(),
+    }
+}
+
+fn print_config_value<T: Display>(label: &str, value: &Option<T>) {
+    println!("{}: {}", label.blue(), config_value_str(value));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project_info::build_project_info;
+    use std::sync::{Mutex, MutexGuard};
+    use tmp_path::tmp_path;
+
+    // Tests that read or change the config environment variables can't run in parallel.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn env_lock() -> MutexGuard<'static, ()> {
+        ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    #[tmp_path]
+    fn mock_config() -> Config {
+        tmp_path.push("python-project-generator");
+        let config_dir = tmp_path.clone();
+        create_dir_all(&config_dir).unwrap();
+        tmp_path.push("config.json");
+        let config_file_path = tmp_path;
+
+        let config = Config {
+            config_dir: Some(config_dir).into(),
+            config_file_path: Some(config_file_path).into(),
+            ..Default::default()
+        };
+
+        config.save().unwrap();
+
+        config
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_config_dir_env_override() {
+        let _lock = env_lock();
+        env::set_var("PPG_CONFIG_DIR", &tmp_path);
+        let dir = config_dir();
+        let file_path = config_file_path();
+        env::remove_var("PPG_CONFIG_DIR");
+
+        assert_eq!(*dir, Some(tmp_path.clone()));
+        assert_eq!(*file_path, Some(tmp_path.join("config.toml")));
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_config_file_flag() {
+        use crate::cli::Args;
+        use clap::Parser;
+
+        let _lock = env_lock();
+        let config_file = tmp_path.join("repo").join("ppg.toml");
+        let args = Args::try_parse_from([
+            "python-project",
+            "config",
+            "creator",
+            "Wade Watts",
+            "--config",
+            config_file.to_str().unwrap(),
+        ])
+        .unwrap();
+        env::set_var("PPG_CONFIG_FILE", tmp_path.join("env.toml"));
+        set_config_file(args.config);
+        let config = Config::default();
+        config.save_creator("Wade Watts".to_string()).unwrap();
+        let result = Config::default().load_config();
+        set_config_file(None);
+        env::remove_var("PPG_CONFIG_FILE");
+
+        assert!(config_file.is_file());
+        assert!(!tmp_path.join("env.toml").exists());
+        assert_eq!(result.creator, Some("Wade Watts".to_string()));
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_config_file_env_override() {
+        let _lock = env_lock();
+        let config_file = tmp_path.join("generator").join("ppg.json");
+        env::set_var("PPG_CONFIG_FILE", &config_file);
+        let config = Config::default();
+        config.save_creator("Wade Watts".to_string()).unwrap();
+        let result = config.load_config();
+        env::remove_var("PPG_CONFIG_FILE");
+
+        assert!(config_file.is_file());
+        assert_eq!(result.creator, Some("Wade Watts".to_string()));
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_toml_config_round_trip() {
+        let config_file = tmp_path.join("config.toml");
+        let config = Config {
+            config_dir: Some(tmp_path.clone()).into(),
+            config_file_path: Some(config_file.clone()).into(),
+            ..Default::default()
+        };
+        config.save_creator("Wade Watts".to_string()).unwrap();
+        config.save_license(LicenseType::Apache2).unwrap();
+        config
+            .save_github_actions_python_test_versions("3.11, 3.12".to_string())
+            .unwrap();
+        let result = config.load_config();
+
+        assert!(read_to_string(&config_file)
+            .unwrap()
+            .contains("creator = \"Wade Watts\""));
+        assert_eq!(result.creator, Some("Wade Watts".to_string()));
+        assert_eq!(result.license, Some(LicenseType::Apache2));
+        assert_eq!(
+            result.github_actions_python_test_versions,
+            Some(vec!["3.11".to_string(), "3.12".to_string()])
+        );
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_legacy_json_config_is_read() {
+        write(
+            tmp_path.join("config.json"),
+            r#"{"creator": "Wade Watts", "max_line_length": 88}"#,
+        )
+        .unwrap();
+        let config = Config {
+            config_dir: Some(tmp_path.clone()).into(),
+            config_file_path: Some(tmp_path.join("config.toml")).into(),
+            ..Default::default()
+        };
+        let result = config.load_config();
+
+        assert_eq!(result.creator, Some("Wade Watts".to_string()));
+        assert_eq!(result.max_line_length, Some(88));
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_toml_config_preferred_over_json() {
+        write(tmp_path.join("config.json"), r#"{"creator": "Wade Watts"}"#).unwrap();
+        write(tmp_path.join("config.toml"), "creator = \"Arthur Dent\"\n").unwrap();
+        let config = Config {
+            config_dir: Some(tmp_path.clone()).into(),
+            config_file_path: Some(tmp_path.join("config.toml")).into(),
+            ..Default::default()
+        };
+        let result = config.load_config();
+
+        assert_eq!(result.creator, Some("Arthur Dent".to_string()));
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_load_config_missing_newer_fields() {
+        write(
+            tmp_path.join("config.json"),
+            r#"{"creator": "Wade Watts", "license": "Apache2", "use_dependabot": false}"#,
+        )
+        .unwrap();
+        let config = Config {
+            config_dir: Some(tmp_path.clone()).into(),
+            config_file_path: Some(tmp_path.join("config.json")).into(),
+            ..Default::default()
+        };
+        let result = config.load_config();
+
+        assert_eq!(result.creator, Some("Wade Watts".to_string()));
+        assert_eq!(result.license, Some(LicenseType::Apache2));
+        assert_eq!(result.use_dependabot, Some(false));
+        assert_eq!(result.min_coverage, None);
+        assert_eq!(result.extra_pre_commit_hooks, None);
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_load_config_invalid_value_keeps_other_values() {
+        write(
+            tmp_path.join("config.json"),
+            r#"{"creator": "Wade Watts", "max_line_length": "long"}"#,
+        )
+        .unwrap();
+        let config = Config {
+            config_dir: Some(tmp_path.clone()).into(),
+            config_file_path: Some(tmp_path.join("config.json")).into(),
+            ..Default::default()
+        };
+        let result = config.load_config();
+
+        assert_eq!(result.creator, Some("Wade Watts".to_string()));
+        assert_eq!(result.max_line_length, None);
+    }
+
+    #[test]
+    fn test_save_no_config_dir() {
+        let config = Config {
+            config_dir: Rc::new(None),
+            config_file_path: Rc::new(None),
+            ..Default::default()
+        };
+        let result = config.save();
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("no config directory could be located"));
+    }
+
+    #[test]
+    fn test_config_dir() {
+        let _lock = env_lock();
+        let config_dir = config_dir();
+        assert_ne!(config_dir, Rc::new(None));
+        let config = config_dir.as_ref().as_ref().unwrap();
+
+        let last = config.file_name();
+        assert_ne!(last, None);
+        assert_eq!(last.unwrap(), "python-project-generator");
+    }
+
+    #[test]
+    fn test_config_file_path() {
+        let _lock = env_lock();
+        let config_file_path = config_file_path();
+        assert_ne!(config_file_path, Rc::new(None));
+        let mut config = config_file_path.as_ref().as_ref().unwrap().clone();
+
+        let last = config.file_name();
+        assert_ne!(last, None);
+        assert_eq!(last.unwrap(), "config.toml");
+
+        config.pop();
+        let dir = config.file_name();
+        assert_ne!(dir, None);
+        assert_eq!(dir.unwrap(), "python-project-generator");
+    }
+
+    #[test]
+    fn test_save_and_load_config() {
+        let mut config = mock_config();
+        config.creator = Some("Some Person".to_string());
+        config.creator_email = Some("someone@email.com".to_string());
+        config.save().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result, config);
+    }
+
+    #[test]
+    fn test_save_creator() {
+        let config = mock_config();
+        let expected = "Some Person".to_string();
+        config.save_creator(expected.clone()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.creator, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_creator() {
+        let config = mock_config();
+        config.save_creator("Some Person".to_string()).unwrap();
+        config.reset_creator().unwrap();
+        let result = config.load_config();
+
+        assert!(result.creator.is_none());
+    }
+
+    #[test]
+    fn test_get_set_key() {
+        let config = mock_config();
+        config.save_license(LicenseType::Apache2).unwrap();
+
+        assert_eq!(config.get("license").unwrap(), "Apache 2.0");
+    }
+
+    #[test]
+    fn test_get_set_list_key() {
+        let config = mock_config();
+        config.save_mypy_files("src,tests".to_string()).unwrap();
+
+        assert_eq!(config.get("mypy_files").unwrap(), "src, tests");
+    }
+
+    #[test]
+    fn test_get_unset_key() {
+        let config = mock_config();
+
+        assert_eq!(config.get("creator_email").unwrap(), "null");
+    }
+
+    #[test]
+    fn test_config_keys_match_fields() {
+        let config = mock_config();
+        let values = serde_json::to_value(config).unwrap();
+        let mut fields: Vec<&str> = values
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(|k| k.as_str())
+            .collect();
+        fields.sort();
+        let mut keys = CONFIG_KEYS.to_vec();
+        keys.sort();
+
+        assert_eq!(fields, keys);
+
+        for key in CONFIG_KEYS {
+            assert!(mock_config().get(key).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_get_invalid_key() {
+        let config = mock_config();
+        let result = config.get("not_a_key");
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("valid keys are: creator, creator_email"));
+    }
+
+    #[test]
+    fn test_with_overrides() {
+        let config = mock_config();
+        config.save_creator("Arthur Dent".to_string()).unwrap();
+        config.save_max_line_length(100).unwrap();
+        let mut overrides = Map::new();
+        overrides.insert("max_line_length".to_string(), Value::from(88));
+        let result = config.load_config().with_overrides(overrides).unwrap();
+
+        assert_eq!(result.creator, Some("Arthur Dent".to_string()));
+        assert_eq!(result.max_line_length, Some(88));
+    }
+
+    #[test]
+    fn test_save_profile() {
+        let config = mock_config();
+        config.save_creator("Arthur Dent".to_string()).unwrap();
+        config.save_profile("work").unwrap();
+        config.save_creator("Ford Prefect".to_string()).unwrap();
+        let profile = config.load_profile("work").unwrap();
+
+        assert_eq!(profile.creator, Some("Arthur Dent".to_string()));
+        assert_eq!(
+            config.load_config().creator,
+            Some("Ford Prefect".to_string())
+        );
+    }
+
+    #[test]
+    fn test_list_profiles() {
+        let config = mock_config();
+
+        assert!(config.list_profiles().unwrap().is_empty());
+
+        config.save_profile("work").unwrap();
+        config.save_profile("personal").unwrap();
+
+        assert_eq!(
+            config.list_profiles().unwrap(),
+            vec!["personal".to_string(), "work".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_delete_profile() {
+        let config = mock_config();
+        config.save_profile("work").unwrap();
+        config.delete_profile("work").unwrap();
+
+        assert!(config.list_profiles().unwrap().is_empty());
+        assert!(config.delete_profile("work").is_err());
+    }
+
+    #[test]
+    fn test_load_missing_profile() {
+        let config = mock_config();
+
+        assert!(config.load_profile("work").is_err());
+    }
+
+    #[test]
+    fn test_invalid_profile_name() {
+        let config = mock_config();
+
+        assert!(config.save_profile("../work").is_err());
+        assert!(config.save_profile("").is_err());
+    }
+
+    #[test]
+    fn test_load_profile_into_project_info() {
+        let config = mock_config();
+        config.save_creator("Arthur Dent".to_string()).unwrap();
+        config
+            .save_creator_email("authur@heartofgold.com".to_string())
+            .unwrap();
+        config.save_license(LicenseType::Apache2).unwrap();
+        config.save_max_line_length(88).unwrap();
+        config.save_profile("work").unwrap();
+        config.reset_license().unwrap();
+        let profile = config.load_profile("work").unwrap();
+        let project_info = build_project_info(
+            profile,
+            Some("Profile Project".to_string()),
+            Some("A profile project".to_string()),
+            None,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(project_info.creator, "Arthur Dent");
+        assert_eq!(project_info.creator_email, "authur@heartofgold.com");
+        assert_eq!(project_info.license, LicenseType::Apache2);
+        assert_eq!(project_info.max_line_length, 88);
+    }
+
+    #[test]
+    fn test_with_overrides_invalid_key() {
+        let config = mock_config();
+        let mut overrides = Map::new();
+        overrides.insert("not_a_key".to_string(), Value::from(true));
+        let result = config.with_overrides(overrides);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_save_creator_email() {
+        let config = mock_config();
+        let expected = "someone@email.com".to_string();
+        config.save_creator_email(expected.clone()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.creator_email, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_creator_email() {
+        let config = mock_config();
+        config
+            .save_creator_email("someone@email.com".to_string())
+            .unwrap();
+        config.reset_creator_email().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.creator_email, None);
+    }
+
+    #[test]
+    fn test_save_creator_email_invalid() {
+        let config = mock_config();
+        let result = config.save_creator_email("not-an-email".to_string());
+
+        assert!(result.is_err());
+        assert_eq!(config.load_config().creator_email, None);
+    }
+
+    #[test]
+    fn test_save_copyright_holder() {
+        let config = mock_config();
+        let expected = "Heart of Gold Inc.".to_string();
+        config.save_copyright_holder(expected.clone()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.copyright_holder, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_copyright_holder() {
+        let config = mock_config();
+        config
+            .save_copyright_holder("Heart of Gold Inc.".to_string())
+            .unwrap();
+        config.reset_copyright_holder().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.copyright_holder, None);
+    }
+
+    #[test]
+    fn test_save_license() {
+        let config = mock_config();
+        let expected = LicenseType::Apache2;
+        config.save_license(expected.clone()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.license, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_license() {
+        let config = mock_config();
+        config.save_license(LicenseType::Apache2).unwrap();
+        config.reset_license().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.license, None);
+    }
+
+    #[test]
+    fn test_save_python_version() {
+        let config = mock_config();
+        let expected = "3.12".to_string();
+        config.save_python_version(expected.clone()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.python_version, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_python_version() {
+        let config = mock_config();
+        config.save_python_version("3.12".to_string()).unwrap();
+        config.reset_python_version().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.python_version, None);
+    }
+
+    #[test]
+    fn test_save_min_python_version() {
+        let config = mock_config();
+        let expected = "3.12".to_string();
+        config.save_min_python_version(expected.clone()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.min_python_version, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_min_python_version() {
+        let config = mock_config();
+        config.save_min_python_version("3.12".to_string()).unwrap();
+        config.reset_min_python_version().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.min_python_version, None);
+    }
+
+    #[test]
+    fn test_save_project_manager() {
+        let config = mock_config();
+        let expected = ProjectManager::Maturin;
+        config.save_project_manager(expected.clone()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.project_manager, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_project_manager() {
+        let config = mock_config();
+        config
+            .save_project_manager(ProjectManager::Maturin)
+            .unwrap();
+        config.reset_project_manager().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.project_manager, None);
+    }
+
+    #[test]
+    fn test_save_pyo3_python_manger() {
+        let config = mock_config();
+        let expected = Pyo3PythonManager::Uv;
+        config.save_pyo3_python_manager(expected.clone()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.pyo3_python_manager, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_pyo3_project_manager() {
+        let config = mock_config();
+        config
+            .save_pyo3_python_manager(Pyo3PythonManager::Uv)
+            .unwrap();
+        config.reset_pyo3_python_manager().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.pyo3_python_manager, None);
+    }
+
+    #[test]
+    fn test_save_is_async_project() {
+        let config = mock_config();
+        let expected = true;
+        config.save_is_async_project(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.is_async_project, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_is_async_project() {
+        let config = mock_config();
+        config.save_is_async_project(true).unwrap();
+        config.reset_is_async_project().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.is_async_project, None);
+    }
+
+    #[test]
+    fn test_save_is_application() {
+        let config = mock_config();
+        let expected = false;
+        config.save_is_application(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.is_application, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_is_application() {
+        let config = mock_config();
+        config.save_is_application(false).unwrap();
+        config.reset_is_application().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.is_application, None);
+    }
+
+    #[test]
+    fn test_save_github_actions_pythong_test_versions() {
+        let config = mock_config();
+        let expected = vec!["3.11".to_string(), "3.12".to_string()];
+        config
+            .save_github_actions_python_test_versions("3.11, 3.12".to_string())
+            .unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.github_actions_python_test_versions, Some(expected));
+    }
+
+    #[test]
+    fn test_save_github_actions_python_test_versions_free_threaded() {
+        let config = mock_config();
+        let expected = vec!["3.14".to_string(), "3.14t".to_string()];
+        config
+            .save_github_actions_python_test_versions("3.14, 3.14t".to_string())
+            .unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.github_actions_python_test_versions, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_github_actions_pythong_test_versions() {
+        let config = mock_config();
+        config
+            .save_github_actions_python_test_versions("3.11, 3.12".to_string())
+            .unwrap();
+        config.reset_github_actions_python_test_versions().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.github_actions_python_test_versions, None);
+    }
+
+    #[test]
+    fn test_save_max_line_length() {
+        let config = mock_config();
+        let expected = 42;
+        config.save_max_line_length(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.max_line_length, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_max_line_length() {
+        let config = mock_config();
+        config.save_max_line_length(42).unwrap();
+        config.reset_max_line_length().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.max_line_length, None);
+    }
+
+    #[test]
+    fn test_save_ruff_line_length() {
+        let config = mock_config();
+        let expected = 88;
+        config.save_ruff_line_length(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.ruff_line_length, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_ruff_line_length() {
+        let config = mock_config();
+        config.save_ruff_line_length(88).unwrap();
+        config.reset_ruff_line_length().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.ruff_line_length, None);
+    }
+
+    #[test]
+    fn test_save_ruff_line_length_out_of_range() {
+        let config = mock_config();
+
+        assert!(config.save_ruff_line_length(20).is_err());
+        assert!(config.save_ruff_line_length(250).is_err());
+        assert_eq!(config.load_config().ruff_line_length, None);
+    }
+
+    #[test]
+    fn test_save_min_coverage() {
+        let config = mock_config();
+        let expected = 90;
+        config.save_min_coverage(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.min_coverage, Some(expected));
+    }
+
+    #[test]
+    fn test_save_min_coverage_invalid() {
+        let config = mock_config();
+        let result = config.save_min_coverage(101);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reset_min_coverage() {
+        let config = mock_config();
+        config.save_min_coverage(90).unwrap();
+        config.reset_min_coverage().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.min_coverage, None);
+    }
+
+    #[test]
+    fn test_save_extra_ruff_rules() {
+        let config = mock_config();
+        let expected = vec!["SIM".to_string(), "PTH".to_string()];
+        config
+            .save_extra_ruff_rules("SIM, PTH, E, SIM".to_string())
+            .unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.extra_ruff_rules, Some(expected));
+    }
+
+    #[test]
+    fn test_save_extra_ruff_rules_invalid() {
+        let config = mock_config();
+        let result = config.save_extra_ruff_rules("SIM, 123".to_string());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reset_extra_ruff_rules() {
+        let config = mock_config();
+        config.save_extra_ruff_rules("SIM".to_string()).unwrap();
+        config.reset_extra_ruff_rules().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.extra_ruff_rules, None);
+    }
+
+    #[test]
+    fn test_save_include_bandit() {
+        let config = mock_config();
+        let expected = true;
+        config.save_include_bandit(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_bandit, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_include_bandit() {
+        let config = mock_config();
+        config.save_include_bandit(true).unwrap();
+        config.reset_include_bandit().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_bandit, None);
+    }
+
+    #[test]
+    fn test_save_include_conda_env() {
+        let config = mock_config();
+        let expected = true;
+        config.save_include_conda_env(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_conda_env, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_include_conda_env() {
+        let config = mock_config();
+        config.save_include_conda_env(true).unwrap();
+        config.reset_include_conda_env().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_conda_env, None);
+    }
+
+    #[test]
+    fn test_save_include_codeowners() {
+        let config = mock_config();
+        let expected = true;
+        config.save_include_codeowners(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_codeowners, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_include_codeowners() {
+        let config = mock_config();
+        config.save_include_codeowners(true).unwrap();
+        config.reset_include_codeowners().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_codeowners, None);
+    }
+
+    #[test]
+    fn test_save_github_username() {
+        let config = mock_config();
+        let expected = "arthurdent".to_string();
+        config.save_github_username(expected.clone()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.github_username, Some(expected));
+    }
+
+    #[test]
+    fn test_save_github_username_strips_at() {
+        let config = mock_config();
+        config
+            .save_github_username("@arthurdent".to_string())
+            .unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.github_username, Some("arthurdent".to_string()));
+    }
+
+    #[test]
+    fn test_save_github_username_invalid() {
+        let config = mock_config();
+
+        assert!(config
+            .save_github_username("arthur dent".to_string())
+            .is_err());
+    }
+
+    #[test]
+    fn test_reset_github_username() {
+        let config = mock_config();
+        config
+            .save_github_username("arthurdent".to_string())
+            .unwrap();
+        config.reset_github_username().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.github_username, None);
+    }
+
+    #[test]
+    fn test_save_include_github_templates() {
+        let config = mock_config();
+        let expected = true;
+        config.save_include_github_templates(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_github_templates, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_include_github_templates() {
+        let config = mock_config();
+        config.save_include_github_templates(true).unwrap();
+        config.reset_include_github_templates().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_github_templates, None);
+    }
+
+    #[test]
+    fn test_save_include_contributing() {
+        let config = mock_config();
+        let expected = true;
+        config.save_include_contributing(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_contributing, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_include_contributing() {
+        let config = mock_config();
+        config.save_include_contributing(true).unwrap();
+        config.reset_include_contributing().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_contributing, None);
+    }
+
+    #[test]
+    fn test_save_include_codeql() {
+        let config = mock_config();
+        let expected = true;
+        config.save_include_codeql(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_codeql, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_include_codeql() {
+        let config = mock_config();
+        config.save_include_codeql(true).unwrap();
+        config.reset_include_codeql().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_codeql, None);
+    }
+
+    #[test]
+    fn test_save_include_security_policy() {
+        let config = mock_config();
+        let expected = true;
+        config.save_include_security_policy(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_security_policy, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_include_security_policy() {
+        let config = mock_config();
+        config.save_include_security_policy(true).unwrap();
+        config.reset_include_security_policy().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_security_policy, None);
+    }
+
+    #[test]
+    fn test_save_include_changelog() {
+        let config = mock_config();
+        let expected = true;
+        config.save_include_changelog(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.include_changelog, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_include_changelog() {
+        let config = mock_config();
+        config.save_include_changelog(true).unwrap();
+        config.reset_include_changelog().unwrap();
+        let result = config.load_config();
 
-    if let Some(mut c) = config_dir {
-        c.push("python-project-generator");
-        return Rc::new(Some(c));
+        assert_eq!(result.include_changelog, None);
     }
 
-    Rc::new(None)
-}
+    #[test]
+    fn test_save_include_citation() {
+        let config = mock_config();
+        let expected = true;
+        config.save_include_citation(expected).unwrap();
+        let result = config.load_config();
 
-fn config_file_path() -> Rc<Option<PathBuf>> {
-    if let Some(c) = &config_dir().as_ref() {
-        let mut c = c.clone();
-        c.push("config.json");
-        return Rc::new(Some(c));
-    };
+        assert_eq!(result.include_citation, Some(expected));
+    }
 
-    Rc::new(None)
-}
+    #[test]
+    fn test_reset_include_citation() {
+        let config = mock_config();
+        config.save_include_citation(true).unwrap();
+        config.reset_include_citation().unwrap();
+        let result = config.load_config();
 
-fn print_config_value<T: Display>(label: &str, value: &Option<T>) {
-    if let Some(v) = value {
-        println!("{}: {}", label.blue(), v);
-    } else {
-        println!("{}: null", label.blue());
+        assert_eq!(result.include_citation, None);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use tmp_path::tmp_path;
+    #[test]
+    fn test_save_include_benchmarks() {
+        let config = mock_config();
+        let expected = true;
+        config.save_include_benchmarks(expected).unwrap();
+        let result = config.load_config();
 
-    #[tmp_path]
-    fn mock_config() -> Config {
-        tmp_path.push("python-project-generator");
-        let config_dir = tmp_path.clone();
-        create_dir_all(&config_dir).unwrap();
-        tmp_path.push("config.json");
-        let config_file_path = tmp_path;
+        assert_eq!(result.include_benchmarks, Some(expected));
+    }
 
-        let config = Config {
-            config_dir: Some(config_dir).into(),
-            config_file_path: Some(config_file_path).into(),
-            ..Default::default()
-        };
+    #[test]
+    fn test_reset_include_benchmarks() {
+        let config = mock_config();
+        config.save_include_benchmarks(true).unwrap();
+        config.reset_include_benchmarks().unwrap();
+        let result = config.load_config();
 
-        config.save().unwrap();
+        assert_eq!(result.include_benchmarks, None);
+    }
+
+    #[test]
+    fn test_save_use_precommit_ci() {
+        let config = mock_config();
+        let expected = true;
+        config.save_use_precommit_ci(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.use_precommit_ci, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_use_precommit_ci() {
+        let config = mock_config();
+        config.save_use_precommit_ci(true).unwrap();
+        config.reset_use_precommit_ci().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.use_precommit_ci, None);
+    }
+
+    #[test]
+    fn test_save_extra_pre_commit_hooks() {
+        let config = mock_config();
+        let expected = vec![
+            ExtraPreCommitHook::Codespell,
+            ExtraPreCommitHook::CheckMergeConflict,
+        ];
+        config
+            .save_extra_pre_commit_hooks(vec![
+                ExtraPreCommitHook::Codespell,
+                ExtraPreCommitHook::CheckMergeConflict,
+                ExtraPreCommitHook::Codespell,
+            ])
+            .unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.extra_pre_commit_hooks, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_extra_pre_commit_hooks() {
+        let config = mock_config();
+        config
+            .save_extra_pre_commit_hooks(vec![ExtraPreCommitHook::Codespell])
+            .unwrap();
+        config.reset_extra_pre_commit_hooks().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.extra_pre_commit_hooks, None);
+    }
+
+    #[test]
+    fn test_save_mypy_files() {
+        let config = mock_config();
+        let expected = vec!["src".to_string(), "tests".to_string()];
+        config.save_mypy_files("src, tests,".to_string()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.mypy_files, Some(expected));
+    }
+
+    #[test]
+    fn test_save_mypy_files_empty() {
+        let config = mock_config();
+        let result = config.save_mypy_files(" , ".to_string());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reset_mypy_files() {
+        let config = mock_config();
+        config.save_mypy_files("src".to_string()).unwrap();
+        config.reset_mypy_files().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.mypy_files, None);
+    }
+
+    #[test]
+    fn test_save_ruff_target_version() {
+        let config = mock_config();
+        let expected = "3.12".to_string();
+        config.save_ruff_target_version(expected.clone()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.ruff_target_version, Some(expected));
+    }
+
+    #[test]
+    fn test_save_ruff_target_version_invalid() {
+        let config = mock_config();
+        let result = config.save_ruff_target_version("py312".to_string());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reset_ruff_target_version() {
+        let config = mock_config();
+        config.save_ruff_target_version("3.12".to_string()).unwrap();
+        config.reset_ruff_target_version().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.ruff_target_version, None);
+    }
+
+    #[test]
+    fn test_save_package_version() {
+        let config = mock_config();
+        config
+            .save_package_version("mypy".to_string(), "1.10.0".to_string())
+            .unwrap();
+        config
+            .save_package_version("Ruff".to_string(), "0.9.0".to_string())
+            .unwrap();
+        let result = config.load_config();
+        let expected = HashMap::from([
+            ("mypy".to_string(), "1.10.0".to_string()),
+            ("ruff".to_string(), "0.9.0".to_string()),
+        ]);
+
+        assert_eq!(result.package_version_overrides, Some(expected));
+    }
+
+    #[test]
+    fn test_save_package_version_empty() {
+        let config = mock_config();
+        let result = config.save_package_version("mypy".to_string(), " ".to_string());
+
+        assert!(result.is_err());
+    }
 
+    #[test]
+    fn test_save_package_version_unknown_package() {
+        let config = mock_config();
+        let result = config.save_package_version("not-a-package".to_string(), "1.0.0".to_string());
+
+        assert!(result.is_err());
+        assert_eq!(config.load_config().package_version_overrides, None);
+    }
+
+    #[test]
+    fn test_reset_package_versions() {
+        let config = mock_config();
+        config
+            .save_package_version("mypy".to_string(), "1.10.0".to_string())
+            .unwrap();
+        config.reset_package_versions().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.package_version_overrides, None);
+    }
+
+    #[test]
+    fn test_reset_package_version() {
+        let config = mock_config();
+        config
+            .save_package_version("mypy".to_string(), "1.10.0".to_string())
+            .unwrap();
+        config
+            .save_package_version("ruff".to_string(), "0.6.0".to_string())
+            .unwrap();
+        config.reset_package_version("Ruff".to_string()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(
+            result.package_version_overrides,
+            Some(HashMap::from([("mypy".to_string(), "1.10.0".to_string())]))
+        );
+
+        config.reset_package_version("mypy".to_string()).unwrap();
+
+        assert_eq!(config.load_config().package_version_overrides, None);
+    }
+
+    #[test]
+    fn test_reset_package_version_not_pinned() {
+        let config = mock_config();
+
+        assert!(config.reset_package_version("ruff".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_save_dependency_bound_style() {
+        let config = mock_config();
+        let expected = DependencyBoundStyle::Minimum;
+        config
+            .save_dependency_bound_style(expected.clone())
+            .unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.dependency_bound_style, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_dependency_bound_style() {
+        let config = mock_config();
+        config
+            .save_dependency_bound_style(DependencyBoundStyle::Compatible)
+            .unwrap();
+        config.reset_dependency_bound_style().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.dependency_bound_style, None);
+    }
+
+    #[test]
+    fn test_save_type_checker() {
+        let config = mock_config();
+        let expected = TypeChecker::Ty;
+        config.save_type_checker(expected.clone()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.type_checker, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_type_checker() {
+        let config = mock_config();
+        config.save_type_checker(TypeChecker::Ty).unwrap();
+        config.reset_type_checker().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.type_checker, None);
+    }
+
+    #[test]
+    fn test_save_use_dependabot() {
+        let config = mock_config();
+        let expected = false;
+        config.save_use_dependabot(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.use_dependabot, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_use_dependabot() {
+        let config = mock_config();
+        config.save_use_dependabot(false).unwrap();
+        config.reset_use_dependabot().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.use_dependabot, None);
+    }
+
+    #[test]
+    fn test_save_dependabot_schedule() {
+        let config = mock_config();
+        let expected = DependabotSchedule::Weekly;
+        config.save_dependabot_schedule(expected.clone()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.dependabot_schedule, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_dependabot_schedule() {
+        let config = mock_config();
         config
+            .save_dependabot_schedule(DependabotSchedule::Weekly)
+            .unwrap();
+        config.reset_dependabot_schedule().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.dependabot_schedule, None);
+    }
+
+    #[test]
+    fn test_save_dependabot_day() {
+        let config = mock_config();
+        let expected = Day::Monday;
+        config.save_dependabot_day(expected.clone()).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.dependabot_day, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_dependabot_day() {
+        let config = mock_config();
+        config.save_dependabot_day(Day::Tuesday).unwrap();
+        config.reset_dependabot_day().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.dependabot_day, None);
+    }
+
+    #[test]
+    fn test_save_use_continuous_deployment() {
+        let config = mock_config();
+        let expected = false;
+        config.save_use_continuous_deployment(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.use_continuous_deployment, Some(expected));
     }
 
     #[test]
-    fn test_config_dir() {
-        let config_dir = config_dir();
-        assert_ne!(config_dir, Rc::new(None));
-        let config = config_dir.as_ref().as_ref().unwrap();
+    fn test_reset_use_continuous_deployment() {
+        let config = mock_config();
+        config.save_use_continuous_deployment(false).unwrap();
+        config.reset_use_continuous_deployment().unwrap();
+        let result = config.load_config();
 
-        let last = config.file_name();
-        assert_ne!(last, None);
-        assert_eq!(last.unwrap(), "python-project-generator");
+        assert_eq!(result.use_continuous_deployment, None);
     }
 
     #[test]
-    fn test_config_file_path() {
-        let config_file_path = config_file_path();
-        assert_ne!(config_file_path, Rc::new(None));
-        let mut config = config_file_path.as_ref().as_ref().unwrap().clone();
-
-        let last = config.file_name();
-        assert_ne!(last, None);
-        assert_eq!(last.unwrap(), "config.json");
+    fn test_save_use_release_drafter() {
+        let config = mock_config();
+        let expected = false;
+        config.save_use_release_drafter(expected).unwrap();
+        let result = config.load_config();
 
-        config.pop();
-        let dir = config.file_name();
-        assert_ne!(dir, None);
-        assert_eq!(dir.unwrap(), "python-project-generator");
+        assert_eq!(result.use_release_drafter, Some(expected));
     }
 
     #[test]
-    fn test_save_and_load_config() {
-        let mut config = mock_config();
-        config.creator = Some("Some Person".to_string());
-        config.creator_email = Some("someone@email.com".to_string());
-        config.save().unwrap();
+    fn test_reset_use_release_drafter() {
+        let config = mock_config();
+        config.save_use_release_drafter(false).unwrap();
+        config.reset_use_release_drafter().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result, config);
+        assert_eq!(result.use_release_drafter, None);
     }
 
     #[test]
-    fn test_save_creator() {
+    fn test_save_cloud_provider() {
         let config = mock_config();
-        let expected = "Some Person".to_string();
-        config.save_creator(expected.clone()).unwrap();
+        let expected = CloudProvider::Aws;
+        config.save_cloud_provider(expected.clone()).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.creator, Some(expected));
+        assert_eq!(result.cloud_provider, Some(expected));
     }
 
     #[test]
-    fn test_reset_creator() {
+    fn test_reset_cloud_provider() {
         let config = mock_config();
-        config.save_creator("Some Person".to_string()).unwrap();
-        config.reset_creator().unwrap();
+        config.save_cloud_provider(CloudProvider::Gcp).unwrap();
+        config.reset_cloud_provider().unwrap();
         let result = config.load_config();
 
-        assert!(result.creator.is_none());
+        assert_eq!(result.cloud_provider, None);
     }
 
     #[test]
-    fn test_save_creator_email() {
+    fn test_save_cloud_deploy_role() {
         let config = mock_config();
-        let expected = "someone@email.com".to_string();
-        config.save_creator_email(expected.clone()).unwrap();
+        let expected = "arn:aws:iam::123456789012:role/deploy".to_string();
+        config.save_cloud_deploy_role(expected.clone()).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.creator_email, Some(expected));
+        assert_eq!(result.cloud_deploy_role, Some(expected));
     }
 
     #[test]
-    fn test_reset_creator_email() {
+    fn test_save_cloud_deploy_role_empty() {
+        let config = mock_config();
+        let result = config.save_cloud_deploy_role(" ".to_string());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reset_cloud_deploy_role() {
         let config = mock_config();
         config
-            .save_creator_email("someone@email.com".to_string())
+            .save_cloud_deploy_role("arn:aws:iam::123456789012:role/deploy".to_string())
             .unwrap();
-        config.reset_creator_email().unwrap();
+        config.reset_cloud_deploy_role().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.creator_email, None);
+        assert_eq!(result.cloud_deploy_role, None);
     }
 
     #[test]
-    fn test_save_license() {
+    fn test_save_publish_docker() {
         let config = mock_config();
-        let expected = LicenseType::Apache2;
-        config.save_license(expected.clone()).unwrap();
+        let expected = true;
+        config.save_publish_docker(expected).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.license, Some(expected));
+        assert_eq!(result.publish_docker, Some(expected));
     }
 
     #[test]
-    fn test_reset_license() {
+    fn test_reset_publish_docker() {
         let config = mock_config();
-        config.save_license(LicenseType::Apache2).unwrap();
-        config.reset_license().unwrap();
+        config.save_publish_docker(true).unwrap();
+        config.reset_publish_docker().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.license, None);
+        assert_eq!(result.publish_docker, None);
     }
 
     #[test]
-    fn test_save_python_version() {
+    fn test_save_supply_chain_attestation() {
         let config = mock_config();
-        let expected = "3.12".to_string();
-        config.save_python_version(expected.clone()).unwrap();
+        let expected = true;
+        config.save_supply_chain_attestation(expected).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.python_version, Some(expected));
+        assert_eq!(result.supply_chain_attestation, Some(expected));
     }
 
     #[test]
-    fn test_reset_python_version() {
+    fn test_reset_supply_chain_attestation() {
         let config = mock_config();
-        config.save_python_version("3.12".to_string()).unwrap();
-        config.reset_python_version().unwrap();
+        config.save_supply_chain_attestation(true).unwrap();
+        config.reset_supply_chain_attestation().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.python_version, None);
+        assert_eq!(result.supply_chain_attestation, None);
     }
 
     #[test]
-    fn test_save_min_python_version() {
+    fn test_save_pypi_index_url() {
         let config = mock_config();
-        let expected = "3.12".to_string();
-        config.save_min_python_version(expected.clone()).unwrap();
+        let expected = "https://pypi.example.com/legacy/".to_string();
+        config.save_pypi_index_url(expected.clone()).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.min_python_version, Some(expected));
+        assert_eq!(result.pypi_index_url, Some(expected));
     }
 
     #[test]
-    fn test_reset_min_python_version() {
+    fn test_save_pypi_index_url_invalid() {
         let config = mock_config();
-        config.save_min_python_version("3.12".to_string()).unwrap();
-        config.reset_min_python_version().unwrap();
+        let result = config.save_pypi_index_url("pypi.example.com".to_string());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reset_pypi_index_url() {
+        let config = mock_config();
+        config
+            .save_pypi_index_url("https://pypi.example.com/legacy/".to_string())
+            .unwrap();
+        config.reset_pypi_index_url().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.min_python_version, None);
+        assert_eq!(result.pypi_index_url, None);
     }
 
     #[test]
-    fn test_save_project_manager() {
+    fn test_save_publish_auth() {
         let config = mock_config();
-        let expected = ProjectManager::Maturin;
-        config.save_project_manager(expected.clone()).unwrap();
+        let expected = PublishAuth::ApiToken;
+        config.save_publish_auth(expected.clone()).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.project_manager, Some(expected));
+        assert_eq!(result.publish_auth, Some(expected));
     }
 
     #[test]
-    fn test_reset_project_manager() {
+    fn test_reset_publish_auth() {
         let config = mock_config();
-        config
-            .save_project_manager(ProjectManager::Maturin)
-            .unwrap();
-        config.reset_project_manager().unwrap();
+        config.save_publish_auth(PublishAuth::ApiToken).unwrap();
+        config.reset_publish_auth().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.project_manager, None);
+        assert_eq!(result.publish_auth, None);
     }
 
     #[test]
-    fn test_save_pyo3_python_manger() {
+    fn test_save_include_testpypi_publish() {
         let config = mock_config();
-        let expected = Pyo3PythonManager::Uv;
-        config.save_pyo3_python_manager(expected.clone()).unwrap();
+        let expected = true;
+        config.save_include_testpypi_publish(expected).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.pyo3_python_manager, Some(expected));
+        assert_eq!(result.include_testpypi_publish, Some(expected));
     }
 
     #[test]
-    fn test_reset_pyo3_project_manager() {
+    fn test_reset_include_testpypi_publish() {
         let config = mock_config();
-        config
-            .save_pyo3_python_manager(Pyo3PythonManager::Uv)
-            .unwrap();
-        config.reset_pyo3_python_manager().unwrap();
+        config.save_include_testpypi_publish(true).unwrap();
+        config.reset_include_testpypi_publish().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.pyo3_python_manager, None);
+        assert_eq!(result.include_testpypi_publish, None);
     }
 
     #[test]
-    fn test_save_is_async_project() {
+    fn test_save_include_rust_toolchain() {
         let config = mock_config();
         let expected = true;
-        config.save_is_async_project(expected).unwrap();
+        config.save_include_rust_toolchain(expected).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.is_async_project, Some(expected));
+        assert_eq!(result.include_rust_toolchain, Some(expected));
     }
 
     #[test]
-    fn test_reset_is_async_project() {
+    fn test_reset_include_rust_toolchain() {
         let config = mock_config();
-        config.save_is_async_project(true).unwrap();
-        config.reset_is_async_project().unwrap();
+        config.save_include_rust_toolchain(true).unwrap();
+        config.reset_include_rust_toolchain().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.is_async_project, None);
+        assert_eq!(result.include_rust_toolchain, None);
     }
 
     #[test]
-    fn test_save_is_application() {
+    fn test_save_rust_edition() {
         let config = mock_config();
-        let expected = false;
-        config.save_is_application(expected).unwrap();
+        let expected = "2024".to_string();
+        config.save_rust_edition(expected.clone()).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.is_application, Some(expected));
+        assert_eq!(result.rust_edition, Some(expected));
     }
 
     #[test]
-    fn test_reset_is_application() {
+    fn test_save_rust_edition_invalid() {
         let config = mock_config();
-        config.save_is_application(false).unwrap();
-        config.reset_is_application().unwrap();
+
+        assert!(config.save_rust_edition("2020".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_reset_rust_edition() {
+        let config = mock_config();
+        config.save_rust_edition("2024".to_string()).unwrap();
+        config.reset_rust_edition().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.is_application, None);
+        assert_eq!(result.rust_edition, None);
     }
 
     #[test]
-    fn test_save_github_actions_pythong_test_versions() {
+    fn test_save_pyo3_version() {
         let config = mock_config();
-        let expected = vec!["3.11".to_string(), "3.12".to_string()];
-        config
-            .save_github_actions_python_test_versions("3.11, 3.12".to_string())
-            .unwrap();
+        let expected = "0.23.4".to_string();
+        config.save_pyo3_version(expected.clone()).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.github_actions_python_test_versions, Some(expected));
+        assert_eq!(result.pyo3_version, Some(expected));
     }
 
     #[test]
-    fn test_reset_github_actions_pythong_test_versions() {
+    fn test_save_pyo3_version_invalid() {
         let config = mock_config();
-        config
-            .save_github_actions_python_test_versions("3.11, 3.12".to_string())
-            .unwrap();
-        config.reset_github_actions_python_test_versions().unwrap();
+
+        assert!(config.save_pyo3_version("latest".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_reset_pyo3_version() {
+        let config = mock_config();
+        config.save_pyo3_version("0.23.4".to_string()).unwrap();
+        config.reset_pyo3_version().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.github_actions_python_test_versions, None);
+        assert_eq!(result.pyo3_version, None);
     }
 
     #[test]
-    fn test_save_max_line_length() {
+    fn test_save_use_abi3() {
         let config = mock_config();
-        let expected = 42;
-        config.save_max_line_length(expected).unwrap();
+        let expected = true;
+        config.save_use_abi3(expected).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.max_line_length, Some(expected));
+        assert_eq!(result.use_abi3, Some(expected));
     }
 
     #[test]
-    fn test_reset_max_line_length() {
+    fn test_reset_use_abi3() {
         let config = mock_config();
-        config.save_max_line_length(42).unwrap();
-        config.reset_max_line_length().unwrap();
+        config.save_use_abi3(true).unwrap();
+        config.reset_use_abi3().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.max_line_length, None);
+        assert_eq!(result.use_abi3, None);
     }
 
     #[test]
-    fn test_save_use_dependabot() {
+    fn test_save_build_musllinux() {
         let config = mock_config();
-        let expected = false;
-        config.save_use_dependabot(expected).unwrap();
+        let expected = true;
+        config.save_build_musllinux(expected).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.use_dependabot, Some(expected));
+        assert_eq!(result.build_musllinux, Some(expected));
     }
 
     #[test]
-    fn test_reset_use_dependabot() {
+    fn test_reset_build_musllinux() {
         let config = mock_config();
-        config.save_use_dependabot(false).unwrap();
-        config.reset_use_dependabot().unwrap();
+        config.save_build_musllinux(true).unwrap();
+        config.reset_build_musllinux().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.use_dependabot, None);
+        assert_eq!(result.build_musllinux, None);
     }
 
     #[test]
-    fn test_save_dependabot_schedule() {
+    fn test_save_maturin_targets() {
         let config = mock_config();
-        let expected = DependabotSchedule::Weekly;
-        config.save_dependabot_schedule(expected.clone()).unwrap();
+        config
+            .save_maturin_targets("x86_64, aarch64-unknown-linux-musl,".to_string())
+            .unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.dependabot_schedule, Some(expected));
+        assert_eq!(
+            result.maturin_targets,
+            Some(vec![
+                "x86_64".to_string(),
+                "aarch64-unknown-linux-musl".to_string()
+            ])
+        );
     }
 
     #[test]
-    fn test_reset_dependabot_schedule() {
+    fn test_save_maturin_targets_invalid() {
+        let config = mock_config();
+
+        assert!(config
+            .save_maturin_targets("x86_64, sparc".to_string())
+            .is_err());
+        assert!(config.save_maturin_targets(" , ".to_string()).is_err());
+        assert!(config
+            .save_maturin_targets("aarch64-pc-windows-msvc".to_string())
+            .is_err());
+    }
+
+    #[test]
+    fn test_reset_maturin_targets() {
         let config = mock_config();
         config
-            .save_dependabot_schedule(DependabotSchedule::Weekly)
+            .save_maturin_targets("x86_64, aarch64".to_string())
             .unwrap();
-        config.reset_dependabot_schedule().unwrap();
+        config.reset_maturin_targets().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.dependabot_schedule, None);
+        assert_eq!(result.maturin_targets, None);
     }
 
     #[test]
-    fn test_save_dependabot_day() {
+    fn test_save_ci_job_timeout() {
         let config = mock_config();
-        let expected = Day::Monday;
-        config.save_dependabot_day(expected.clone()).unwrap();
+        let expected = 45;
+        config.save_ci_job_timeout(expected).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.dependabot_day, Some(expected));
+        assert_eq!(result.ci_job_timeout, Some(expected));
     }
 
     #[test]
-    fn test_reset_dependabot_day() {
+    fn test_save_ci_job_timeout_zero() {
         let config = mock_config();
-        config.save_dependabot_day(Day::Tuesday).unwrap();
-        config.reset_dependabot_day().unwrap();
+        let result = config.save_ci_job_timeout(0);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reset_ci_job_timeout() {
+        let config = mock_config();
+        config.save_ci_job_timeout(45).unwrap();
+        config.reset_ci_job_timeout().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.dependabot_day, None);
+        assert_eq!(result.ci_job_timeout, None);
     }
 
     #[test]
-    fn test_save_use_continuous_deployment() {
+    fn test_save_ci_coverage_comment() {
         let config = mock_config();
-        let expected = false;
-        config.save_use_continuous_deployment(expected).unwrap();
+        let expected = true;
+        config.save_ci_coverage_comment(expected).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.use_continuous_deployment, Some(expected));
+        assert_eq!(result.ci_coverage_comment, Some(expected));
     }
 
     #[test]
-    fn test_reset_use_continuous_deployment() {
+    fn test_reset_ci_coverage_comment() {
         let config = mock_config();
-        config.save_use_continuous_deployment(false).unwrap();
-        config.reset_use_continuous_deployment().unwrap();
+        config.save_ci_coverage_comment(true).unwrap();
+        config.reset_ci_coverage_comment().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.use_continuous_deployment, None);
+        assert_eq!(result.ci_coverage_comment, None);
     }
 
     #[test]
-    fn test_save_use_release_drafter() {
+    fn test_save_uv_frozen() {
         let config = mock_config();
-        let expected = false;
-        config.save_use_release_drafter(expected).unwrap();
+        let expected = true;
+        config.save_uv_frozen(expected).unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.use_release_drafter, Some(expected));
+        assert_eq!(result.uv_frozen, Some(expected));
     }
 
     #[test]
-    fn test_reset_use_release_drafter() {
+    fn test_reset_uv_frozen() {
         let config = mock_config();
-        config.save_use_release_drafter(false).unwrap();
-        config.reset_use_release_drafter().unwrap();
+        config.save_uv_frozen(true).unwrap();
+        config.reset_uv_frozen().unwrap();
         let result = config.load_config();
 
-        assert_eq!(result.use_release_drafter, None);
+        assert_eq!(result.uv_frozen, None);
     }
 
     #[test]
@@ -895,4 +3379,64 @@ mod tests {
 
         assert_eq!(result.download_latest_packages, None);
     }
+
+    #[test]
+    fn test_save_use_spinner() {
+        let config = mock_config();
+        let expected = true;
+        config.save_use_spinner(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.use_spinner, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_use_spinner() {
+        let config = mock_config();
+        config.save_use_spinner(true).unwrap();
+        config.reset_use_spinner().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.use_spinner, None);
+    }
+
+    #[test]
+    fn test_save_init_git() {
+        let config = mock_config();
+        let expected = true;
+        config.save_init_git(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.init_git, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_init_git() {
+        let config = mock_config();
+        config.save_init_git(true).unwrap();
+        config.reset_init_git().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.init_git, None);
+    }
+
+    #[test]
+    fn test_save_version_cache_ttl() {
+        let config = mock_config();
+        let expected = 12;
+        config.save_version_cache_ttl(expected).unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.version_cache_ttl, Some(expected));
+    }
+
+    #[test]
+    fn test_reset_version_cache_ttl() {
+        let config = mock_config();
+        config.save_version_cache_ttl(12).unwrap();
+        config.reset_version_cache_ttl().unwrap();
+        let result = config.load_config();
+
+        assert_eq!(result.version_cache_ttl, None);
+    }
 }
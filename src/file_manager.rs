@@ -1,12 +1,29 @@
 use std::fs::File;
 use std::io::prelude::*;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 use crate::project_info::ProjectInfo;
 
+/// Generated YAML files are parsed before they are written so a template mistake fails the
+/// generation instead of producing a broken workflow.
+fn validate_yaml(file_path: &Path, file_content: &str) -> Result<()> {
+    let is_yaml = file_path
+        .extension()
+        .is_some_and(|ext| ext == "yml" || ext == "yaml");
+
+    if is_yaml {
+        if let Err(e) = serde_yaml::from_str::<serde_yaml::Value>(file_content) {
+            bail!("Invalid YAML generated for {}: {e}", file_path.display());
+        }
+    }
+
+    Ok(())
+}
+
 pub fn save_file_with_content(file_path: &PathBuf, file_content: &str) -> Result<()> {
+    validate_yaml(file_path, file_content)?;
     let mut file = File::create(file_path)?;
     file.write_all(file_content.as_bytes())?;
 
@@ -22,3 +39,39 @@ pub fn save_empty_src_file(project_info: &ProjectInfo, file_name: &str) -> Resul
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tmp_path::tmp_path;
+
+    #[tmp_path]
+    #[test]
+    fn test_save_file_with_content_invalid_yaml() {
+        let file_path = tmp_path.join("testing.yml");
+        let content = "name: Testing\njobs:\n  test:\n    steps: [\n";
+        let result = save_file_with_content(&file_path, content);
+
+        assert!(result.is_err());
+        assert!(!file_path.exists());
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_save_file_with_content_valid_yaml() {
+        let file_path = tmp_path.join("testing.yml");
+        let content = "name: Testing\njobs:\n  test:\n    runs-on: ${{ matrix.os }}\n";
+        save_file_with_content(&file_path, content).unwrap();
+
+        assert!(file_path.is_file());
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_save_file_with_content_not_yaml() {
+        let file_path = tmp_path.join("justfile");
+        save_file_with_content(&file_path, "lint: [\n").unwrap();
+
+        assert!(file_path.is_file());
+    }
+}
@@ -1,9 +1,14 @@
+use std::fs::create_dir_all;
+
 use anyhow::{bail, Result};
 
 use crate::file_manager::save_file_with_content;
 use crate::project_info::{
-    Day, DependabotSchedule, ProjectInfo, ProjectManager, Pyo3PythonManager,
+    CloudProvider, Day, DependabotSchedule, ProjectInfo, ProjectManager, PublishAuth,
+    Pyo3PythonManager, TypeChecker, MATURIN_LINUX_TARGETS, MATURIN_MACOS_TARGETS,
+    MATURIN_MUSLLINUX_TARGETS, MATURIN_WINDOWS_TARGETS, MATURIN_WINDOWS_TRIPLES,
 };
+use crate::utils::uv_sync_command;
 
 fn build_actions_python_test_versions(github_action_python_test_versions: &[String]) -> String {
     github_action_python_test_versions
@@ -13,12 +18,83 @@ fn build_actions_python_test_versions(github_action_python_test_versions: &[Stri
         .join(", ")
 }
 
+fn create_bandit_step(include_bandit: bool, command: &str) -> String {
+    if include_bandit {
+        format!("\n    - name: bandit check\n      run: {command}")
+    } else {
+        String::new()
+    }
+}
+
+/// ty is run on the same paths the mypy config checks since it doesn't read the mypy `files`.
+fn create_type_check_step(
+    type_checker: &TypeChecker,
+    mypy_command: &str,
+    ty_command: &str,
+) -> String {
+    match type_checker {
+        TypeChecker::Mypy => format!("    - name: mypy check\n      run: {mypy_command}"),
+        TypeChecker::Ty => format!("    - name: ty check\n      run: {ty_command}"),
+    }
+}
+
+/// The testing job is always the last job in the testing workflow so the comment step is appended
+/// to the end of the file. Only the job for the first Python version in the matrix comments to
+/// avoid duplicate comments. The minimum Python version isn't used since it may not be tested.
+fn add_coverage_comment(
+    content: &str,
+    python_test_versions: &[String],
+    use_multi_os_ci: bool,
+) -> String {
+    let mut condition = match python_test_versions.first() {
+        Some(version) => format!("matrix.python-version == '{version}'"),
+        None => "matrix.python-version == env.PYTHON_VERSION".to_string(),
+    };
+    if use_multi_os_ci {
+        condition.push_str(" && matrix.os == 'ubuntu-latest'");
+    }
+
+    let mut with_comment = String::new();
+
+    for line in content.lines() {
+        with_comment.push_str(line);
+        with_comment.push('\n');
+
+        if line == "  testing:" {
+            with_comment
+                .push_str("    permissions:\n      contents: write\n      pull-requests: write\n");
+        }
+    }
+
+    with_comment.push_str(&format!(
+        r#"    - name: Coverage comment
+      if: ${{{{ {condition} }}}}
+      uses: py-cov-action/python-coverage-comment-action@v3
+      with:
+        GITHUB_TOKEN: ${{{{ github.token }}}}
+"#
+    ));
+
+    with_comment
+}
+
 fn create_poetry_ci_testing_linux_only_file(
     source_dir: &str,
     min_python_version: &str,
     github_action_python_test_versions: &[String],
+    include_bandit: bool,
+    type_checker: &TypeChecker,
 ) -> String {
     let python_versions = build_actions_python_test_versions(github_action_python_test_versions);
+    let bandit_step = create_bandit_step(
+        include_bandit,
+        &format!("poetry run bandit -r {source_dir}"),
+    );
+    let type_check_step = create_type_check_step(
+        type_checker,
+        "poetry run mypy",
+        &format!("poetry run ty check {source_dir} tests"),
+    );
 
     format!(
         r#"name: Testing
@@ -52,8 +128,7 @@ jobs:
       run: poetry run ruff format {source_dir} tests --check
     - name: Lint with ruff
       run: poetry run ruff check .
-    - name: mypy check
-      run: poetry run mypy .
+{type_check_step}{bandit_step}
   testing:
     strategy:
       fail-fast: false
@@ -85,8 +160,16 @@ fn create_setuptools_ci_testing_linux_only_file(
     source_dir: &str,
     min_python_version: &str,
     github_action_python_test_versions: &[String],
+    include_bandit: bool,
+    type_checker: &TypeChecker,
 ) -> String {
     let python_versions = build_actions_python_test_versions(github_action_python_test_versions);
+    let bandit_step = create_bandit_step(include_bandit, &format!("bandit -r {source_dir}"));
+    let type_check_step = create_type_check_step(
+        type_checker,
+        "mypy",
+        &format!("ty check {source_dir} tests"),
+    );
 
     format!(
         r#"name: Testing
@@ -116,8 +199,7 @@ jobs:
       run: ruff format {source_dir} tests --check
     - name: Lint with ruff
       run: ruff check .
-    - name: mypy check
-      run: mypy .
+{type_check_step}{bandit_step}
   testing:
     strategy:
       fail-fast: false
@@ -145,8 +227,18 @@ fn create_uv_ci_testing_linux_only_file(
     source_dir: &str,
     min_python_version: &str,
     github_action_python_test_versions: &[String],
+    include_bandit: bool,
+    type_checker: &TypeChecker,
+    uv_frozen: bool,
 ) -> String {
     let python_versions = build_actions_python_test_versions(github_action_python_test_versions);
+    let uv_sync = uv_sync_command(uv_frozen);
+    let bandit_step = create_bandit_step(include_bandit, &format!("uv run bandit -r {source_dir}"));
+    let type_check_step = create_type_check_step(
+        type_checker,
+        "uv run mypy",
+        &format!("uv run ty check {source_dir} tests"),
+    );
 
     format!(
         r#"name: Testing
@@ -172,13 +264,12 @@ jobs:
       with:
         python-version: ${{{{ env.PYTHON_VERSION }}}}
     - name: Install Dependencies
-      run: uv sync --frozen
+      run: {uv_sync}
     - name: Ruff format check
       run: uv run ruff format {source_dir} tests --check
     - name: Lint with ruff
       run: uv run ruff check .
-    - name: mypy check
-      run: uv run mypy .
+{type_check_step}{bandit_step}
   testing:
     strategy:
       fail-fast: false
@@ -196,7 +287,7 @@ jobs:
       with:
         python-version: ${{{{ matrix.python-version }}}}
     - name: Install Dependencies
-      run: uv sync --frozen
+      run: {uv_sync}
     - name: Test with pytest
       run: uv run pytest
 "#
@@ -206,8 +297,13 @@ jobs:
 fn create_pixi_ci_testing_linux_only_file(
     min_python_version: &str,
     github_action_python_test_versions: &[String],
+    include_bandit: bool,
+    type_checker: &TypeChecker,
 ) -> String {
     let python_versions = build_actions_python_test_versions(github_action_python_test_versions);
+    let bandit_step = create_bandit_step(include_bandit, "pixi run run-bandit");
+    let type_check_step =
+        create_type_check_step(type_checker, "pixi run run-mypy", "pixi run run-ty");
 
     format!(
         r#"name: Testing
@@ -234,8 +330,7 @@ jobs:
       run: pixi run run-ruff-format
     - name: Lint with ruff
       run: pixi run run-ruff-check
-    - name: mypy check
-      run: pixi run run-mypy
+{type_check_step}{bandit_step}
   testing:
     strategy:
       fail-fast: false
@@ -261,8 +356,29 @@ fn create_ci_testing_linux_only_file_pyo3(
     min_python_version: &str,
     github_action_python_test_versions: &[String],
     pyo3_python_manager: &Pyo3PythonManager,
+    include_bandit: bool,
+    type_checker: &TypeChecker,
+    uv_frozen: bool,
 ) -> String {
     let python_versions = build_actions_python_test_versions(github_action_python_test_versions);
+    let uv_sync = uv_sync_command(uv_frozen);
+    let bandit_command = match pyo3_python_manager {
+        Pyo3PythonManager::Uv => format!("uv run bandit -r {source_dir}"),
+        Pyo3PythonManager::Setuptools => format!("bandit -r {source_dir}"),
+    };
+    let bandit_step = create_bandit_step(include_bandit, &bandit_command);
+    let type_check_step = match pyo3_python_manager {
+        Pyo3PythonManager::Uv => create_type_check_step(
+            type_checker,
+            "uv run mypy",
+            &format!("uv run ty check {source_dir} tests"),
+        ),
+        Pyo3PythonManager::Setuptools => create_type_check_step(
+            type_checker,
+            "mypy",
+            &format!("ty check {source_dir} tests"),
+        ),
+    };
     match pyo3_python_manager {
         Pyo3PythonManager::Uv => format!(
             r#"name: Testing
@@ -316,14 +432,13 @@ jobs:
         python-version: ${{{{ env.PYTHON_VERSION }}}}
     - name: Install Dependencies
       run: |
-        uv sync --frozen
+        {uv_sync}
         uv run maturin build
     - name: Ruff format check
       run: uv run ruff format {source_dir} tests --check
     - name: Lint with ruff
       run: uv run ruff check .
-    - name: mypy check
-      run: uv run mypy {source_dir} tests
+{type_check_step}{bandit_step}
   testing:
     strategy:
       fail-fast: false
@@ -342,7 +457,7 @@ jobs:
         python-version: ${{{{ matrix.python-version }}}}
     - name: Install Dependencies
       run: |
-        uv sync --frozen
+        {uv_sync}
         uv run maturin build
     - name: Test with pytest
       run: uv run pytest
@@ -405,8 +520,7 @@ jobs:
       run: ruff format {source_dir} tests --check
     - name: Lint with ruff
       run: ruff check .
-    - name: mypy check
-      run: mypy .
+{type_check_step}{bandit_step}
   testing:
     strategy:
       fail-fast: false
@@ -445,6 +559,9 @@ pub fn save_ci_testing_linux_only_file(project_info: &ProjectInfo) -> Result<()>
                     &project_info.min_python_version,
                     &project_info.github_actions_python_test_versions,
                     pyo3_python_manager,
+                    project_info.include_bandit,
+                    &project_info.type_checker,
+                    project_info.uv_sync_frozen(),
                 )
             } else {
                 bail!("A PyO3 Python manager is required for maturin");
@@ -454,23 +571,42 @@ pub fn save_ci_testing_linux_only_file(project_info: &ProjectInfo) -> Result<()>
             &project_info.source_dir,
             &project_info.min_python_version,
             &project_info.github_actions_python_test_versions,
+            project_info.include_bandit,
+            &project_info.type_checker,
         ),
         ProjectManager::Setuptools => create_setuptools_ci_testing_linux_only_file(
             &project_info.source_dir,
             &project_info.min_python_version,
             &project_info.github_actions_python_test_versions,
+            project_info.include_bandit,
+            &project_info.type_checker,
         ),
         ProjectManager::Uv => create_uv_ci_testing_linux_only_file(
             &project_info.source_dir,
             &project_info.min_python_version,
             &project_info.github_actions_python_test_versions,
+            project_info.include_bandit,
+            &project_info.type_checker,
+            project_info.uv_sync_frozen(),
         ),
         ProjectManager::Pixi => create_pixi_ci_testing_linux_only_file(
             &project_info.min_python_version,
             &project_info.github_actions_python_test_versions,
+            project_info.include_bandit,
+            &project_info.type_checker,
         ),
     };
 
+    let content = if project_info.ci_coverage_comment {
+        add_coverage_comment(
+            &content,
+            &project_info.github_actions_python_test_versions,
+            false,
+        )
+    } else {
+        content
+    };
+
     save_file_with_content(&file_path, &content)?;
 
     Ok(())
@@ -480,8 +616,19 @@ fn create_poetry_ci_testing_multi_os_file(
     source_dir: &str,
     min_python_version: &str,
     github_action_python_test_versions: &[String],
+    include_bandit: bool,
+    type_checker: &TypeChecker,
 ) -> String {
     let python_versions = build_actions_python_test_versions(github_action_python_test_versions);
+    let bandit_step = create_bandit_step(
+        include_bandit,
+        &format!("poetry run bandit -r {source_dir}"),
+    );
+    let type_check_step = create_type_check_step(
+        type_checker,
+        "poetry run mypy",
+        &format!("poetry run ty check {source_dir} tests"),
+    );
 
     format!(
         r#"name: Testing
@@ -515,8 +662,7 @@ jobs:
       run: poetry run ruff format {source_dir} tests --check
     - name: Lint with ruff
       run: poetry run ruff check .
-    - name: mypy check
-      run: poetry run mypy .
+{type_check_step}{bandit_step}
   testing:
     strategy:
       fail-fast: false
@@ -549,8 +695,16 @@ fn create_setuptools_ci_testing_multi_os_file(
     source_dir: &str,
     min_python_version: &str,
     github_action_python_test_versions: &[String],
+    include_bandit: bool,
+    type_checker: &TypeChecker,
 ) -> String {
     let python_versions = build_actions_python_test_versions(github_action_python_test_versions);
+    let bandit_step = create_bandit_step(include_bandit, &format!("bandit -r {source_dir}"));
+    let type_check_step = create_type_check_step(
+        type_checker,
+        "mypy",
+        &format!("ty check {source_dir} tests"),
+    );
 
     format!(
         r#"name: Testing
@@ -580,8 +734,7 @@ jobs:
       run: ruff format {source_dir} tests --check
     - name: Lint with ruff
       run: ruff check .
-    - name: mypy check
-      run: mypy .
+{type_check_step}{bandit_step}
   testing:
     strategy:
       fail-fast: false
@@ -611,8 +764,29 @@ fn create_ci_testing_multi_os_file_pyo3(
     min_python_version: &str,
     github_action_python_test_versions: &[String],
     pyo3_python_manager: &Pyo3PythonManager,
+    include_bandit: bool,
+    type_checker: &TypeChecker,
+    uv_frozen: bool,
 ) -> String {
     let python_versions = build_actions_python_test_versions(github_action_python_test_versions);
+    let uv_sync = uv_sync_command(uv_frozen);
+    let bandit_command = match pyo3_python_manager {
+        Pyo3PythonManager::Uv => format!("uv run bandit -r {source_dir}"),
+        Pyo3PythonManager::Setuptools => format!("bandit -r {source_dir}"),
+    };
+    let bandit_step = create_bandit_step(include_bandit, &bandit_command);
+    let type_check_step = match pyo3_python_manager {
+        Pyo3PythonManager::Uv => create_type_check_step(
+            type_checker,
+            "uv run mypy",
+            &format!("uv run ty check {source_dir} tests"),
+        ),
+        Pyo3PythonManager::Setuptools => create_type_check_step(
+            type_checker,
+            "mypy",
+            &format!("ty check {source_dir} tests"),
+        ),
+    };
     match pyo3_python_manager {
         Pyo3PythonManager::Uv => format!(
             r#"name: Testing
@@ -666,14 +840,13 @@ jobs:
         python-version: ${{{{ env.PYTHON_VERSION }}}}
     - name: Install Dependencies
       run: |
-        uv sync --frozen
+        {uv_sync}
         uv run maturin build
     - name: Ruff format check
       run: uv run ruff format {source_dir} tests --check
     - name: Lint with ruff
       run: uv run ruff check .
-    - name: mypy check
-      run: uv run mypy {source_dir} tests
+{type_check_step}{bandit_step}
   testing:
     strategy:
       fail-fast: false
@@ -693,7 +866,7 @@ jobs:
         python-version: ${{{{ matrix.python-version }}}}
     - name: Install Dependencies
       run: |
-        uv sync --frozen
+        {uv_sync}
         uv run maturin build
     - name: Test with pytest
       run: uv run pytest
@@ -756,8 +929,7 @@ jobs:
       run: ruff format {source_dir} tests --check
     - name: Lint with ruff
       run: ruff check .
-    - name: mypy check
-      run: mypy .
+{type_check_step}{bandit_step}
   testing:
     strategy:
       fail-fast: false
@@ -789,8 +961,18 @@ fn create_uv_ci_testing_multi_os_file(
     source_dir: &str,
     min_python_version: &str,
     github_action_python_test_versions: &[String],
+    include_bandit: bool,
+    type_checker: &TypeChecker,
+    uv_frozen: bool,
 ) -> String {
     let python_versions = build_actions_python_test_versions(github_action_python_test_versions);
+    let uv_sync = uv_sync_command(uv_frozen);
+    let bandit_step = create_bandit_step(include_bandit, &format!("uv run bandit -r {source_dir}"));
+    let type_check_step = create_type_check_step(
+        type_checker,
+        "uv run mypy",
+        &format!("uv run ty check {source_dir} tests"),
+    );
 
     format!(
         r#"name: Testing
@@ -817,13 +999,12 @@ jobs:
       with:
         python-version: ${{{{ env.PYTHON_VERSION }}}}
     - name: Install Dependencies
-      run: uv sync --frozen
+      run: {uv_sync}
     - name: Ruff format check
       run: uv run ruff format {source_dir} tests --check
     - name: Lint with ruff
       run: uv run ruff check .
-    - name: mypy check
-      run: uv run mypy .
+{type_check_step}{bandit_step}
   testing:
     strategy:
       fail-fast: false
@@ -842,7 +1023,7 @@ jobs:
       with:
         python-version: ${{{{ matrix.python-version }}}}
     - name: Install Dependencies
-      run: uv sync --frozen
+      run: {uv_sync}
     - name: Test with pytest
       run: uv run pytest
 "#
@@ -852,8 +1033,13 @@ jobs:
 fn create_pixi_ci_testing_multi_os_file(
     min_python_version: &str,
     github_action_python_test_versions: &[String],
+    include_bandit: bool,
+    type_checker: &TypeChecker,
 ) -> String {
     let python_versions = build_actions_python_test_versions(github_action_python_test_versions);
+    let bandit_step = create_bandit_step(include_bandit, "pixi run run-bandit");
+    let type_check_step =
+        create_type_check_step(type_checker, "pixi run run-mypy", "pixi run run-ty");
 
     format!(
         r#"name: Testing
@@ -880,8 +1066,7 @@ jobs:
       run: pixi run run-ruff-formar
     - name: Lint with ruff
       run: pixi run run-ruff-check
-    - name: mypy check
-      run: pixi run run-mypy
+{type_check_step}{bandit_step}
   testing:
     strategy:
       fail-fast: false
@@ -915,6 +1100,9 @@ pub fn save_ci_testing_multi_os_file(project_info: &ProjectInfo) -> Result<()> {
                     &project_info.min_python_version,
                     &project_info.github_actions_python_test_versions,
                     pyo3_python_manager,
+                    project_info.include_bandit,
+                    &project_info.type_checker,
+                    project_info.uv_sync_frozen(),
                 )
             } else {
                 bail!("A PyO3 Python Manager is required for maturin");
@@ -924,23 +1112,42 @@ pub fn save_ci_testing_multi_os_file(project_info: &ProjectInfo) -> Result<()> {
             &project_info.source_dir,
             &project_info.min_python_version,
             &project_info.github_actions_python_test_versions,
+            project_info.include_bandit,
+            &project_info.type_checker,
         ),
         ProjectManager::Setuptools => create_setuptools_ci_testing_multi_os_file(
             &project_info.source_dir,
             &project_info.min_python_version,
             &project_info.github_actions_python_test_versions,
+            project_info.include_bandit,
+            &project_info.type_checker,
         ),
         ProjectManager::Uv => create_uv_ci_testing_multi_os_file(
             &project_info.source_dir,
             &project_info.min_python_version,
             &project_info.github_actions_python_test_versions,
+            project_info.include_bandit,
+            &project_info.type_checker,
+            project_info.uv_sync_frozen(),
         ),
         ProjectManager::Pixi => create_pixi_ci_testing_multi_os_file(
             &project_info.min_python_version,
             &project_info.github_actions_python_test_versions,
+            project_info.include_bandit,
+            &project_info.type_checker,
         ),
     };
 
+    let content = if project_info.ci_coverage_comment {
+        add_coverage_comment(
+            &content,
+            &project_info.github_actions_python_test_versions,
+            true,
+        )
+    } else {
+        content
+    };
+
     save_file_with_content(&file_path, &content)?;
 
     Ok(())
@@ -1077,17 +1284,253 @@ pub fn save_dependabot_file(project_info: &ProjectInfo) -> Result<()> {
     Ok(())
 }
 
-fn create_poetry_pypi_publish_file(python_version: &str) -> String {
+/// Without a GitHub username there is no owner to assign so no file is created. A username saved
+/// with its leading `@` is accepted so the owner isn't written as `@@username`.
+pub fn save_codeowners_file(project_info: &ProjectInfo) -> Result<()> {
+    if let Some(github_username) = &project_info.github_username {
+        let file_path = project_info.base_dir().join(".github/CODEOWNERS");
+        let github_username = github_username.strip_prefix('@').unwrap_or(github_username);
+        let content = format!("* @{github_username}\n");
+
+        save_file_with_content(&file_path, &content)?;
+    }
+
+    Ok(())
+}
+
+fn create_bug_report_template() -> String {
+    r#"---
+name: Bug report
+about: Report something that isn't working as expected
+title: ""
+labels: bug
+assignees: ""
+---
+
+## Describe the bug
+
+A clear and concise description of what the bug is.
+
+## To reproduce
+
+Steps to reproduce the behavior:
+
+1.
+2.
+3.
+
+## Expected behavior
+
+A clear and concise description of what you expected to happen.
+
+## Environment
+
+- OS:
+- Python version:
+- Package version:
+
+## Additional context
+
+Add any other context about the problem here.
+"#
+    .to_string()
+}
+
+fn create_feature_request_template() -> String {
+    r#"---
+name: Feature request
+about: Suggest an idea for this project
+title: ""
+labels: enhancement
+assignees: ""
+---
+
+## Is your feature request related to a problem?
+
+A clear and concise description of what the problem is.
+
+## Describe the solution you'd like
+
+A clear and concise description of what you want to happen.
+
+## Describe alternatives you've considered
+
+A clear and concise description of any alternative solutions or features you've considered.
+
+## Additional context
+
+Add any other context about the feature request here.
+"#
+    .to_string()
+}
+
+fn create_pull_request_template() -> String {
+    r#"## Description
+
+Describe the changes and the reason for them.
+
+Closes #
+
+## Checklist
+
+- [ ] Tests have been added or updated
+- [ ] Documentation has been updated if needed
+- [ ] Linting and type checks pass
+"#
+    .to_string()
+}
+
+pub fn save_github_templates(project_info: &ProjectInfo) -> Result<()> {
+    let base = project_info.base_dir().join(".github");
+    let issue_template_dir = base.join("ISSUE_TEMPLATE");
+    create_dir_all(&issue_template_dir)?;
+
+    save_file_with_content(
+        &issue_template_dir.join("bug_report.md"),
+        &create_bug_report_template(),
+    )?;
+    save_file_with_content(
+        &issue_template_dir.join("feature_request.md"),
+        &create_feature_request_template(),
+    )?;
+    save_file_with_content(
+        &base.join("pull_request_template.md"),
+        &create_pull_request_template(),
+    )?;
+
+    Ok(())
+}
+
+/// SBOM and build provenance steps that run once the distributions have been built into
+/// `subject_path`.
+fn create_attestation_steps(subject_path: &str, indent: &str) -> String {
+    format!(
+        r#"{indent}- name: Generate SBOM
+{indent}  uses: anchore/sbom-action@v0
+{indent}  with:
+{indent}    path: .
+{indent}    output-file: sbom.spdx.json
+{indent}- name: Attest build provenance
+{indent}  uses: actions/attest-build-provenance@v2
+{indent}  with:
+{indent}    subject-path: {subject_path}
+"#
+    )
+}
+
+fn attestation_permissions(supply_chain_attestation: bool, indent: &str) -> String {
+    if supply_chain_attestation {
+        format!("\n{indent}attestations: write\n{indent}contents: write")
+    } else {
+        "".to_string()
+    }
+}
+
+/// The OIDC token is needed for trusted publishing and for attestations, so the `id-token`
+/// permission is only dropped when publishing with an API token and not attesting.
+fn publish_permissions(
+    trusted_publishing: bool,
+    supply_chain_attestation: bool,
+    indent: &str,
+) -> String {
+    if !trusted_publishing && !supply_chain_attestation {
+        return "".to_string();
+    }
+
+    let comment = if trusted_publishing {
+        format!("{indent}  # For PyPI's trusted publishing.\n")
+    } else {
+        "".to_string()
+    };
+
+    format!(
+        "{indent}permissions:\n{comment}{indent}  id-token: write{}\n",
+        attestation_permissions(supply_chain_attestation, &format!("{indent}  "))
+    )
+}
+
+/// The environment variables that pass the API token secret to the publish step when publishing
+/// with an API token.
+fn publish_token_env(
+    publish_auth: &PublishAuth,
+    env_vars: &[(&str, &str)],
+    indent: &str,
+) -> String {
+    if publish_auth == &PublishAuth::TrustedPublishing {
+        return "".to_string();
+    }
+
+    let mut env = format!("{indent}env:\n");
+    for (name, value) in env_vars {
+        env.push_str(&format!("{indent}  {name}: {value}\n"));
+    }
+
+    env
+}
+
+/// The index a publish workflow uploads to. PyPI and TestPyPI use the same publish steps with a
+/// different workflow name, release event type, index, and API token secret.
+struct PublishTarget<'a> {
+    workflow_name: &'a str,
+    release_type: &'a str,
+    index_url: Option<&'a str>,
+    token_secret_name: &'a str,
+}
+
+impl PublishTarget<'_> {
+    fn token_secret(&self) -> String {
+        format!("${{{{ secrets.{} }}}}", self.token_secret_name)
+    }
+}
+
+/// The `--repository-url` argument used by twine and maturin when publishing to a custom index.
+fn repository_url_arg(pypi_index_url: Option<&str>) -> String {
+    match pypi_index_url {
+        Some(url) => format!(" --repository-url {url}"),
+        None => "".to_string(),
+    }
+}
+
+fn create_poetry_pypi_publish_file(
+    python_version: &str,
+    supply_chain_attestation: bool,
+    target: &PublishTarget,
+    publish_auth: &PublishAuth,
+) -> String {
+    let (publish_command, token_env_var) = match target.index_url {
+        Some(url) => (
+            format!(
+                "|\n        poetry config repositories.custom {url}\n        poetry publish -r custom"
+            ),
+            "POETRY_PYPI_TOKEN_CUSTOM",
+        ),
+        None => ("poetry publish".to_string(), "POETRY_PYPI_TOKEN_PYPI"),
+    };
+    let token_env = publish_token_env(
+        publish_auth,
+        &[(token_env_var, &target.token_secret())],
+        "      ",
+    );
+    let permissions = publish_permissions(false, supply_chain_attestation, "    ");
+    let publish_steps = if supply_chain_attestation {
+        format!(
+            "    - name: Build package\n      run: poetry build\n{}    - name: Publish package\n      run: {publish_command}\n{token_env}",
+            create_attestation_steps("dist/*", "    ")
+        )
+    } else {
+        format!("    - name: Publish package\n      run: {publish_command} --build\n{token_env}")
+    };
+
     format!(
-        r#"name: PyPi Publish
+        r#"name: {workflow_name}
 on:
   release:
     types:
-    - published
+    - {release_type}
 jobs:
   deploy:
     runs-on: ubuntu-latest
-    steps:
+{permissions}    steps:
     - uses: actions/checkout@v4
     - name: Install Poetry
       run: pipx install poetry
@@ -1099,88 +1542,170 @@ jobs:
     - name: Install Dependencies
       run: |
         poetry install
-    - name: Publish package
-      run: poetry publish --build
-"#
+{publish_steps}"#,
+        workflow_name = target.workflow_name,
+        release_type = target.release_type,
     )
 }
 
-fn create_pyo3_pypi_publish_file(python_version: &str) -> String {
-    format!(
-        r#"name: PyPi Publish
-on:
-  release:
-    types:
-    - published
-permissions:
-  contents: read
-jobs:
-  linux:
-    runs-on: ubuntu-latest
-    strategy:
-      matrix:
-        target: [x86_64, x86, aarch64, armv7, s390x, ppc64le]
-    steps:
-      - uses: actions/checkout@v4
-      - uses: actions/setup-python@v5
-        with:
-          python-version: "{python_version}"
-      - name: Build wheels
-        uses: PyO3/maturin-action@v1
-        with:
-          target: ${{{{ matrix.target }}}}
-          args: --release --out dist --find-interpreter
-          sccache: 'true'
-          manylinux: auto
-      - name: Upload wheels
-        uses: actions/upload-artifact@v4
-        with:
-          name: wheels-linux-${{{{ matrix.target }}}}
-          path: dist
-  windows:
-    runs-on: windows-latest
-    strategy:
-      matrix:
-        target: [x64, x86]
-    steps:
-      - uses: actions/checkout@v4
-      - uses: actions/setup-python@v5
-        with:
-          python-version: "{python_version}"
-          architecture: ${{{{ matrix.target }}}}
-      - name: Build wheels
-        uses: PyO3/maturin-action@v1
-        with:
-          target: ${{{{ matrix.target }}}}
-          args: --release --out dist --find-interpreter
-          sccache: 'true'
-      - name: Upload wheels
-        uses: actions/upload-artifact@v4
-        with:
-          name: wheels-windows-${{{{ matrix.target }}}}
-          path: dist
-  macos:
-    runs-on: macos-latest
+/// Splits the configured Maturin targets into the Linux, Windows, and macOS build matrices. Full
+/// target triples go to the platform they name and short architecture names go to every platform
+/// that builds them by default. Windows triples are replaced with their setup-python architecture
+/// since the target is also used as the interpreter architecture. Without configured targets the
+/// default matrices are used.
+fn split_maturin_targets(
+    maturin_targets: Option<&[String]>,
+) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let Some(targets) = maturin_targets else {
+        return (
+            MATURIN_LINUX_TARGETS
+                .iter()
+                .map(|t| t.to_string())
+                .collect(),
+            MATURIN_WINDOWS_TARGETS
+                .iter()
+                .map(|t| t.to_string())
+                .collect(),
+            MATURIN_MACOS_TARGETS
+                .iter()
+                .map(|t| t.to_string())
+                .collect(),
+        );
+    };
+
+    let for_platform = |defaults: &[&str], os: &str| -> Vec<String> {
+        targets
+            .iter()
+            .filter(|t| t.contains(os) || defaults.contains(&t.as_str()))
+            .cloned()
+            .collect()
+    };
+
+    let mut windows_targets: Vec<String> = Vec::new();
+    for target in for_platform(&MATURIN_WINDOWS_TARGETS, "-windows") {
+        let architecture = MATURIN_WINDOWS_TRIPLES
+            .iter()
+            .find(|(triple, _)| *triple == target)
+            .map_or(target, |(_, architecture)| architecture.to_string());
+
+        if !windows_targets.contains(&architecture) {
+            windows_targets.push(architecture);
+        }
+    }
+
+    (
+        for_platform(&MATURIN_LINUX_TARGETS, "-linux"),
+        windows_targets,
+        for_platform(&MATURIN_MACOS_TARGETS, "-apple-"),
+    )
+}
+
+fn create_pyo3_pypi_publish_file(
+    python_version: &str,
+    supply_chain_attestation: bool,
+    target: &PublishTarget,
+    publish_auth: &PublishAuth,
+    use_abi3: bool,
+    maturin_targets: Option<&[String]>,
+    build_musllinux: bool,
+) -> String {
+    // An abi3 wheel works on every Python version from the minimum so one wheel is built per
+    // target instead of one for each interpreter.
+    let find_interpreter = if use_abi3 { "" } else { " --find-interpreter" };
+    let repository_url = repository_url_arg(target.index_url);
+    let permissions = publish_permissions(
+        publish_auth == &PublishAuth::TrustedPublishing,
+        supply_chain_attestation,
+        "    ",
+    );
+    let token_env = publish_token_env(
+        publish_auth,
+        &[("MATURIN_PYPI_TOKEN", &target.token_secret())],
+        "        ",
+    );
+    let attestation_steps = if supply_chain_attestation {
+        create_attestation_steps("wheels-*/*", "      ")
+    } else {
+        "".to_string()
+    };
+
+    let (linux_targets, windows_targets, macos_targets) = split_maturin_targets(maturin_targets);
+    let musllinux_targets = if build_musllinux {
+        linux_targets
+            .iter()
+            .filter(|t| MATURIN_MUSLLINUX_TARGETS.contains(&t.as_str()))
+            .cloned()
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let mut build_jobs = String::new();
+    let mut needs = Vec::new();
+
+    for (platform, runs_on, targets) in [
+        ("linux", "ubuntu-latest", linux_targets),
+        ("windows", "windows-latest", windows_targets),
+        ("macos", "macos-latest", macos_targets),
+        ("musllinux", "ubuntu-latest", musllinux_targets),
+    ] {
+        if targets.is_empty() {
+            continue;
+        }
+
+        // Windows needs the interpreter architecture to match the target and Linux builds run
+        // in a manylinux or musllinux container.
+        let architecture = if platform == "windows" {
+            "\n          architecture: ${{ matrix.target }}"
+        } else {
+            ""
+        };
+        let manylinux = match platform {
+            "linux" => "\n          manylinux: auto",
+            "musllinux" => "\n          manylinux: musllinux_1_2",
+            _ => "",
+        };
+        let targets = targets.join(", ");
+
+        build_jobs.push_str(&format!(
+            r#"  {platform}:
+    runs-on: {runs_on}
     strategy:
       matrix:
-        target: [x86_64, aarch64]
+        target: [{targets}]
     steps:
       - uses: actions/checkout@v4
       - uses: actions/setup-python@v5
         with:
-          python-version: "{python_version}"
+          python-version: "{python_version}"{architecture}
       - name: Build wheels
         uses: PyO3/maturin-action@v1
         with:
           target: ${{{{ matrix.target }}}}
-          args: --release --out dist --find-interpreter
-          sccache: 'true'
+          args: --release --out dist{find_interpreter}
+          sccache: 'true'{manylinux}
       - name: Upload wheels
         uses: actions/upload-artifact@v4
         with:
-          name: wheels-macos-${{{{ matrix.target }}}}
+          name: wheels-{platform}-${{{{ matrix.target }}}}
           path: dist
-  sdist:
+"#
+        ));
+        needs.push(platform);
+    }
+
+    needs.push("sdist");
+    let needs = needs.join(", ");
+
+    format!(
+        r#"name: {workflow_name}
+on:
+  release:
+    types:
+    - {release_type}
+permissions:
+  contents: read
+jobs:
+{build_jobs}  sdist:
     runs-on: ubuntu-latest
     steps:
       - uses: actions/checkout@v4
@@ -1200,39 +1725,63 @@ jobs:
   release:
     name: Release
     runs-on: ubuntu-latest
-    permissions:
-      # For PyPI's trusted publishing.
-      id-token: write
-    if: "startsWith(github.ref, 'refs/tags/')"
-    needs: [linux, windows, macos, sdist]
+{permissions}    if: "startsWith(github.ref, 'refs/tags/')"
+    needs: [{needs}]
     steps:
       - uses: actions/download-artifact@v4
       - uses: actions/setup-python@v5
         with:
           python-version: "{python_version}"
-      - name: Publish to PyPI
+{attestation_steps}      - name: Publish to PyPI
         uses: PyO3/maturin-action@v1
         with:
           command: upload
-          args: --non-interactive --skip-existing wheels-*/*
-"#
+          args: --non-interactive --skip-existing{repository_url} wheels-*/*
+{token_env}"#,
+        workflow_name = target.workflow_name,
+        release_type = target.release_type,
     )
 }
 
-fn create_setuptools_pypi_publish_file(python_version: &str) -> String {
+fn create_setuptools_pypi_publish_file(
+    python_version: &str,
+    supply_chain_attestation: bool,
+    target: &PublishTarget,
+    publish_auth: &PublishAuth,
+) -> String {
+    let repository_url = repository_url_arg(target.index_url);
+    let permissions = publish_permissions(
+        publish_auth == &PublishAuth::TrustedPublishing,
+        supply_chain_attestation,
+        "    ",
+    );
+    let token_env = publish_token_env(
+        publish_auth,
+        &[
+            ("TWINE_USERNAME", "__token__"),
+            ("TWINE_PASSWORD", &target.token_secret()),
+        ],
+        "      ",
+    );
+    let publish_steps = if supply_chain_attestation {
+        format!(
+            "    - name: Build package\n      run: python -m build\n{}    - name: Publish package\n      run: twine upload{repository_url} dist/*\n{token_env}",
+            create_attestation_steps("dist/*", "    ")
+        )
+    } else {
+        format!("    - name: Build and publish package\n      run: |\n        python -m build\n        twine upload{repository_url} dist/*\n{token_env}")
+    };
+
     format!(
-        r#"name: PyPi Publish
+        r#"name: {workflow_name}
 on:
   release:
     types:
-    - published
+    - {release_type}
 jobs:
   deploy:
     runs-on: ubuntu-latest
-    permissions:
-      # For PyPI's trusted publishing.
-      id-token: write
-    steps:
+{permissions}    steps:
     - uses: actions/checkout@v4
     - name: Set up Python
       uses: actions/setup-python@v5
@@ -1244,28 +1793,50 @@ jobs:
         python -m pip install -U pip
         python -m pip -r requirements-dev.txt
         python -m pip install build setuptools wheel twine
-    - name: Build and publish package
-      run: |
-        python -m build
-        twine upload dist/*
-"#
+{publish_steps}"#,
+        workflow_name = target.workflow_name,
+        release_type = target.release_type,
     )
 }
 
-fn create_uv_pypi_publish_file(python_version: &str) -> String {
+fn create_uv_pypi_publish_file(
+    python_version: &str,
+    supply_chain_attestation: bool,
+    target: &PublishTarget,
+    publish_auth: &PublishAuth,
+    uv_frozen: bool,
+) -> String {
+    let uv_sync = uv_sync_command(uv_frozen);
+    let publish_url = match target.index_url {
+        Some(url) => format!(" --publish-url {url}"),
+        None => "".to_string(),
+    };
+    let permissions = publish_permissions(
+        publish_auth == &PublishAuth::TrustedPublishing,
+        supply_chain_attestation,
+        "    ",
+    );
+    let token_env = publish_token_env(
+        publish_auth,
+        &[("UV_PUBLISH_TOKEN", &target.token_secret())],
+        "      ",
+    );
+    let attestation_steps = if supply_chain_attestation {
+        create_attestation_steps("dist/*", "    ")
+    } else {
+        "".to_string()
+    };
+
     format!(
-        r#"name: PyPi Publish
+        r#"name: {workflow_name}
 on:
   release:
     types:
-    - published
+    - {release_type}
 jobs:
   deploy:
     runs-on: ubuntu-latest
-    permissions:
-      # For PyPI's trusted publishing.
-      id-token: write
-    steps:
+{permissions}    steps:
     - uses: actions/checkout@v4
     - name: Install uv
       uses: astral-sh/setup-uv@v5
@@ -1276,29 +1847,66 @@ jobs:
       with:
         python-version: "{python_version}"
     - name: Install Dependencies
-      run: uv sync --frozen
+      run: {uv_sync}
     - name: Build package
       run: uv build
-    - name: Publish package
-      run: uv publish
-"#
+{attestation_steps}    - name: Publish package
+      run: uv publish{publish_url}
+{token_env}"#,
+        workflow_name = target.workflow_name,
+        release_type = target.release_type,
     )
 }
 
-fn create_pixi_pypi_publish_file(python_version: &str) -> String {
+fn create_pixi_pypi_publish_file(
+    python_version: &str,
+    supply_chain_attestation: bool,
+    target: &PublishTarget,
+    publish_auth: &PublishAuth,
+) -> String {
+    let repository_url = repository_url_arg(target.index_url);
+    let permissions = publish_permissions(
+        publish_auth == &PublishAuth::TrustedPublishing,
+        supply_chain_attestation,
+        "    ",
+    );
+    let token_env = publish_token_env(
+        publish_auth,
+        &[
+            ("TWINE_USERNAME", "__token__"),
+            ("TWINE_PASSWORD", &target.token_secret()),
+        ],
+        "      ",
+    );
+    let publish_steps = if supply_chain_attestation {
+        format!(
+            r#"    - name: Build package
+      run: pixi exec --spec python=="{python_version}.*" --spec python-build pyproject-build
+{}    - name: Publish package
+      run: pixi exec --spec python=="{python_version}.*" --spec twine twine upload{repository_url} dist/*
+{token_env}"#,
+            create_attestation_steps("dist/*", "    ")
+        )
+    } else {
+        format!(
+            r#"    - name: Build and publish package
+      run: |
+        pixi exec --spec python=="{python_version}.*" --spec python-build pyproject-build
+        pixi exec --spec python=="{python_version}.*" --spec twine twine upload{repository_url} dist/*
+{token_env}"#
+        )
+    };
+
     format!(
-        r#"name: PyPi Publish
+        r#"name: {workflow_name}
 on:
   release:
     types:
-    - published
+    - {release_type}
 jobs:
   deploy:
     runs-on: ubuntu-latest
-    permissions:
-      # For PyPI's trusted publishing.
-      id-token: write
-    steps:
+{permissions}    steps:
     - uses: actions/checkout@v4
     - name: Install Pixi
       uses: prefix-dev/setup-pixi@v0.8.1
@@ -1306,39 +1914,131 @@ jobs:
         pixi-version: v0.30.0
     - name: Set up Python
       run: pixi add python=="{python_version}.*"
-    - name: Build and publish package
-      run: |
-        pixi exec --spec python=="{python_version}.*" --spec python-build pyproject-build
-        pixi exec --spec python=="{python_version}.*" --spec twine twine upload dist/*
-"#
+{publish_steps}"#,
+        workflow_name = target.workflow_name,
+        release_type = target.release_type,
     )
 }
 
+/// Adds a `timeout-minutes` to every job so a hung publish job doesn't use CI minutes until
+/// GitHub's six hour limit is reached.
+fn add_job_timeout(content: &str, timeout_minutes: u16) -> String {
+    let mut with_timeout = String::new();
+
+    for line in content.lines() {
+        with_timeout.push_str(line);
+        with_timeout.push('\n');
+
+        if line.starts_with("    runs-on: ") {
+            with_timeout.push_str(&format!("    timeout-minutes: {timeout_minutes}\n"));
+        }
+    }
+
+    with_timeout
+}
+
+fn create_pypi_publish_file(project_info: &ProjectInfo, target: &PublishTarget) -> String {
+    let python_version = &project_info.python_version;
+    let supply_chain_attestation = project_info.supply_chain_attestation;
+    let publish_auth = &project_info.publish_auth;
+    let content = match &project_info.project_manager {
+        ProjectManager::Maturin => create_pyo3_pypi_publish_file(
+            python_version,
+            supply_chain_attestation,
+            target,
+            publish_auth,
+            project_info.use_abi3,
+            project_info.maturin_targets.as_deref(),
+            project_info.build_musllinux,
+        ),
+        ProjectManager::Poetry => create_poetry_pypi_publish_file(
+            python_version,
+            supply_chain_attestation,
+            target,
+            publish_auth,
+        ),
+        ProjectManager::Setuptools => create_setuptools_pypi_publish_file(
+            python_version,
+            supply_chain_attestation,
+            target,
+            publish_auth,
+        ),
+        ProjectManager::Uv => create_uv_pypi_publish_file(
+            python_version,
+            supply_chain_attestation,
+            target,
+            publish_auth,
+            project_info.uv_sync_frozen(),
+        ),
+        ProjectManager::Pixi => create_pixi_pypi_publish_file(
+            python_version,
+            supply_chain_attestation,
+            target,
+            publish_auth,
+        ),
+    };
+
+    add_job_timeout(&content, project_info.ci_job_timeout)
+}
+
 pub fn save_pypi_publish_file(project_info: &ProjectInfo) -> Result<()> {
     let file_path = project_info
         .base_dir()
         .join(".github/workflows/pypi_publish.yml");
-    let content = match &project_info.project_manager {
-        ProjectManager::Maturin => create_pyo3_pypi_publish_file(&project_info.python_version),
-        ProjectManager::Poetry => create_poetry_pypi_publish_file(&project_info.python_version),
-        ProjectManager::Setuptools => {
-            create_setuptools_pypi_publish_file(&project_info.python_version)
-        }
-        ProjectManager::Uv => create_uv_pypi_publish_file(&project_info.python_version),
-        ProjectManager::Pixi => create_pixi_pypi_publish_file(&project_info.python_version),
+    // The published event also fires for pre-releases, which go to TestPyPI instead.
+    let release_type = if project_info.include_testpypi_publish {
+        "released"
+    } else {
+        "published"
     };
+    let content = create_pypi_publish_file(
+        project_info,
+        &PublishTarget {
+            workflow_name: "PyPi Publish",
+            release_type,
+            index_url: project_info.pypi_index_url.as_deref(),
+            token_secret_name: "PYPI_TOKEN",
+        },
+    );
 
     save_file_with_content(&file_path, &content)?;
 
     Ok(())
 }
 
-fn create_poetry_docs_publish_file(python_version: &str) -> String {
-    format!(
-        r#"name: Docs Publish
-on:
-  release:
-    types:
+const TESTPYPI_URL: &str = "https://test.pypi.org/legacy/";
+
+/// Publishes pre-releases to TestPyPI with the same steps as the PyPI publish workflow. TestPyPI
+/// needs its own API token so a separate `TEST_PYPI_TOKEN` secret is used.
+fn create_testpypi_publish_file(project_info: &ProjectInfo) -> String {
+    create_pypi_publish_file(
+        project_info,
+        &PublishTarget {
+            workflow_name: "TestPyPI Publish",
+            release_type: "prereleased",
+            index_url: Some(TESTPYPI_URL),
+            token_secret_name: "TEST_PYPI_TOKEN",
+        },
+    )
+}
+
+pub fn save_testpypi_publish_file(project_info: &ProjectInfo) -> Result<()> {
+    let file_path = project_info
+        .base_dir()
+        .join(".github/workflows/testpypi_publish.yml");
+    let content = create_testpypi_publish_file(project_info);
+
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
+fn create_poetry_docs_publish_file(python_version: &str) -> String {
+    format!(
+        r#"name: Docs Publish
+on:
+  release:
+    types:
     - published
 jobs:
   deploy:
@@ -1407,12 +2107,13 @@ jobs:
     - name: Set up Python
       run: pixi add python=="{python_version}.*"
     - name: Deploy Docs
-      run pixi run run-deploy-docs
+      run: pixi run run-deploy-docs
 "#
     )
 }
 
-fn create_uv_docs_publish_file(python_version: &str) -> String {
+fn create_uv_docs_publish_file(python_version: &str, uv_frozen: bool) -> String {
+    let uv_sync = uv_sync_command(uv_frozen);
     format!(
         r#"name: Docs Publish
 on:
@@ -1433,7 +2134,7 @@ jobs:
       with:
         python-version: "{python_version}"
     - name: Install Dependencies
-      run: uv sync --frozen
+      run: {uv_sync}
     - name: Deploy Docs
       run: uv run mkdocs gh-deploy --force
 "#
@@ -1451,9 +2152,10 @@ pub fn save_docs_publish_file(project_info: &ProjectInfo) -> Result<()> {
                     Pyo3PythonManager::Setuptools => {
                         create_setuptools_docs_publish_file(&project_info.python_version)
                     }
-                    Pyo3PythonManager::Uv => {
-                        create_uv_docs_publish_file(&project_info.python_version)
-                    }
+                    Pyo3PythonManager::Uv => create_uv_docs_publish_file(
+                        &project_info.python_version,
+                        project_info.uv_sync_frozen(),
+                    ),
                 }
             } else {
                 bail!("No PyO3 Python project manager specified");
@@ -1463,10 +2165,14 @@ pub fn save_docs_publish_file(project_info: &ProjectInfo) -> Result<()> {
         ProjectManager::Setuptools => {
             create_setuptools_docs_publish_file(&project_info.python_version)
         }
-        ProjectManager::Uv => create_uv_docs_publish_file(&project_info.python_version),
+        ProjectManager::Uv => {
+            create_uv_docs_publish_file(&project_info.python_version, project_info.uv_sync_frozen())
+        }
         ProjectManager::Pixi => create_pixi_docs_publish_file(&project_info.python_version),
     };
 
+    let content = add_job_timeout(&content, project_info.ci_job_timeout);
+
     save_file_with_content(&file_path, &content)?;
 
     Ok(())
@@ -1493,6 +2199,205 @@ jobs:
     .to_string()
 }
 
+fn create_aws_deploy_file(role: &str) -> String {
+    format!(
+        r#"name: Deploy
+
+on:
+  release:
+    types:
+    - published
+  workflow_dispatch:
+permissions:
+  id-token: write
+  contents: read
+jobs:
+  deploy:
+    runs-on: ubuntu-latest
+    steps:
+    - uses: actions/checkout@v4
+    - name: Configure AWS credentials
+      uses: aws-actions/configure-aws-credentials@v4
+      with:
+        role-to-assume: {role}
+        aws-region: ${{{{ vars.AWS_REGION }}}}
+    - name: Deploy
+      run: echo "Add deploy commands here"
+"#
+    )
+}
+
+fn create_gcp_deploy_file(workload_identity_provider: &str) -> String {
+    format!(
+        r#"name: Deploy
+
+on:
+  release:
+    types:
+    - published
+  workflow_dispatch:
+permissions:
+  id-token: write
+  contents: read
+jobs:
+  deploy:
+    runs-on: ubuntu-latest
+    steps:
+    - uses: actions/checkout@v4
+    - name: Authenticate to Google Cloud
+      uses: google-github-actions/auth@v2
+      with:
+        workload_identity_provider: {workload_identity_provider}
+        service_account: ${{{{ vars.GCP_SERVICE_ACCOUNT }}}}
+    - name: Deploy
+      run: echo "Add deploy commands here"
+"#
+    )
+}
+
+/// Saves a deploy workflow that authenticates with OIDC so no long lived cloud credentials need
+/// to be stored in the repository. Without a saved role the value is read from a repository
+/// variable.
+pub fn save_deploy_file(project_info: &ProjectInfo) -> Result<()> {
+    let file_path = project_info.base_dir().join(".github/workflows/deploy.yml");
+    let content = match &project_info.cloud_provider {
+        Some(CloudProvider::Aws) => create_aws_deploy_file(
+            project_info
+                .cloud_deploy_role
+                .as_deref()
+                .unwrap_or("${{ vars.AWS_ROLE_ARN }}"),
+        ),
+        Some(CloudProvider::Gcp) => create_gcp_deploy_file(
+            project_info
+                .cloud_deploy_role
+                .as_deref()
+                .unwrap_or("${{ vars.GCP_WORKLOAD_IDENTITY_PROVIDER }}"),
+        ),
+        None => bail!("No cloud provider specified"),
+    };
+
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
+fn create_docker_publish_file(project_slug: &str) -> String {
+    format!(
+        r#"name: Docker Publish
+
+on:
+  release:
+    types:
+    - published
+permissions:
+  contents: read
+  packages: write
+env:
+  REGISTRY: ghcr.io
+jobs:
+  publish:
+    runs-on: ubuntu-latest
+    steps:
+    - uses: actions/checkout@v4
+    - name: Set image name
+      run: echo "IMAGE_NAME=${{GITHUB_REPOSITORY_OWNER,,}}/{project_slug}" >> "$GITHUB_ENV"
+    - name: Log in to GHCR
+      uses: docker/login-action@v3
+      with:
+        registry: ${{{{ env.REGISTRY }}}}
+        username: ${{{{ github.actor }}}}
+        password: ${{{{ secrets.GITHUB_TOKEN }}}}
+    - name: Extract image metadata
+      id: meta
+      uses: docker/metadata-action@v5
+      with:
+        images: ${{{{ env.REGISTRY }}}}/${{{{ env.IMAGE_NAME }}}}
+        tags: |
+          type=semver,pattern={{{{version}}}}
+          type=raw,value=latest
+    - name: Build and push
+      uses: docker/build-push-action@v6
+      with:
+        context: .
+        push: true
+        tags: ${{{{ steps.meta.outputs.tags }}}}
+        labels: ${{{{ steps.meta.outputs.labels }}}}
+"#
+    )
+}
+
+pub fn save_docker_publish_file(project_info: &ProjectInfo) -> Result<()> {
+    let file_path = project_info
+        .base_dir()
+        .join(".github/workflows/docker_publish.yml");
+    let content = create_docker_publish_file(&project_info.project_slug);
+
+    let content = add_job_timeout(&content, project_info.ci_job_timeout);
+
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
+/// Rust is analyzed along with Python for maturin projects since the extension module is part
+/// of the package.
+fn create_codeql_file(include_rust: bool) -> String {
+    let languages = if include_rust {
+        "\"python\", \"rust\""
+    } else {
+        "\"python\""
+    };
+
+    format!(
+        r#"name: CodeQL
+
+on:
+  push:
+    branches:
+    - main
+  pull_request:
+  schedule:
+  - cron: "0 6 * * 1"
+permissions:
+  actions: read
+  contents: read
+  security-events: write
+jobs:
+  analyze:
+    runs-on: ubuntu-latest
+    strategy:
+      fail-fast: false
+      matrix:
+        language: [{languages}]
+    steps:
+    - uses: actions/checkout@v4
+    - name: Initialize CodeQL
+      uses: github/codeql-action/init@v3
+      with:
+        languages: ${{{{ matrix.language }}}}
+        build-mode: none
+    - name: Perform CodeQL Analysis
+      uses: github/codeql-action/analyze@v3
+      with:
+        category: "/language:${{{{ matrix.language }}}}"
+"#
+    )
+}
+
+pub fn save_codeql_file(project_info: &ProjectInfo) -> Result<()> {
+    let file_path = project_info.base_dir().join(".github/workflows/codeql.yml");
+    let content = create_codeql_file(matches!(
+        project_info.project_manager,
+        ProjectManager::Maturin
+    ));
+
+    let content = add_job_timeout(&content, project_info.ci_job_timeout);
+
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
 fn create_release_drafter_template_file() -> String {
     r#"name-template: 'v$RESOLVED_VERSION'
 tag-template: 'v$RESOLVED_VERSION'
@@ -1545,6 +2450,7 @@ mod tests {
         DocsInfo, LicenseType, ProjectInfo, ProjectManager, Pyo3PythonManager,
     };
     use insta::assert_yaml_snapshot;
+    use std::collections::HashMap;
     use std::fs::create_dir_all;
     use tmp_path::tmp_path;
 
@@ -1573,6 +2479,7 @@ mod tests {
                 "3.12".to_string(),
             ],
             max_line_length: 100,
+            ruff_line_length: None,
             use_dependabot: true,
             dependabot_schedule: None,
             dependabot_day: None,
@@ -1582,6 +2489,47 @@ mod tests {
             include_docs: false,
             docs_info: None,
             download_latest_packages: false,
+            min_coverage: None,
+            extra_ruff_rules: Vec::new(),
+            include_bandit: false,
+            extra_pre_commit_hooks: Vec::new(),
+            use_precommit_ci: false,
+            include_settings: false,
+            include_logging: false,
+            mypy_files: None,
+            ruff_target_version: None,
+            offline: false,
+            cloud_provider: None,
+            cloud_deploy_role: None,
+            publish_docker: false,
+            supply_chain_attestation: false,
+            pypi_index_url: None,
+            publish_auth: PublishAuth::TrustedPublishing,
+            include_testpypi_publish: false,
+            include_rust_toolchain: false,
+            rust_edition: "2021".to_string(),
+            pyo3_version: None,
+            use_abi3: false,
+            build_musllinux: false,
+            maturin_targets: None,
+            package_version_overrides: HashMap::new(),
+            ci_job_timeout: 30,
+            dependency_bound_style: None,
+            type_checker: TypeChecker::Mypy,
+            include_conda_env: false,
+            copyright_holder: None,
+            additional_authors: Vec::new(),
+            include_codeowners: false,
+            github_username: None,
+            include_github_templates: false,
+            include_contributing: false,
+            include_codeql: false,
+            include_security_policy: false,
+            include_changelog: false,
+            include_citation: false,
+            include_benchmarks: false,
+            ci_coverage_comment: false,
+            uv_frozen: true,
             project_root_dir: Some(tmp_path),
         }
     }
@@ -1590,7 +2538,7 @@ mod tests {
         DocsInfo {
             site_name: "Test Repo".to_string(),
             site_description: "Dummy data for testing".to_string(),
-            site_url: "https://mytest.com".to_string(),
+            site_url: Some("https://mytest.com".to_string()),
             locale: "en".to_string(),
             repo_name: "sanders41/python-project-generator".to_string(),
             repo_url: "https://github.com/sanders41/python-project-generator".to_string(),
@@ -1610,6 +2558,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_github_actions_test_versions_free_threaded() {
+        assert_eq!(
+            build_actions_python_test_versions(&[
+                "3.13".to_string(),
+                "3.14".to_string(),
+                "3.14t".to_string(),
+            ]),
+            r#""3.13", "3.14", "3.14t""#.to_string()
+        );
+    }
+
     #[test]
     fn test_save_poetry_ci_testing_linux_only_file() {
         let mut project_info = project_info_dummy();
@@ -1780,17 +2740,14 @@ mod tests {
     }
 
     #[test]
-    fn test_save_dependabot_file() {
+    fn test_save_uv_ci_testing_linux_only_file_bandit() {
         let mut project_info = project_info_dummy();
-        project_info.project_manager = ProjectManager::Poetry;
-        project_info.use_dependabot = true;
-        project_info.dependabot_schedule = None;
-        project_info.dependabot_day = None;
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.include_bandit = true;
         let base = project_info.base_dir();
-        create_dir_all(base.join(".github")).unwrap();
-        let expected_file = base.join(".github/dependabot.yml");
-
-        save_dependabot_file(&project_info).unwrap();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/testing.yml");
+        save_ci_testing_linux_only_file(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
@@ -1800,17 +2757,15 @@ mod tests {
     }
 
     #[test]
-    fn test_save_dependabot_file_daily() {
+    fn test_save_uv_ci_testing_linux_only_file_ty() {
         let mut project_info = project_info_dummy();
-        project_info.project_manager = ProjectManager::Poetry;
-        project_info.use_dependabot = true;
-        project_info.dependabot_schedule = Some(DependabotSchedule::Daily);
-        project_info.dependabot_day = None;
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.type_checker = TypeChecker::Ty;
+        project_info.include_bandit = true;
         let base = project_info.base_dir();
-        create_dir_all(base.join(".github")).unwrap();
-        let expected_file = base.join(".github/dependabot.yml");
-
-        save_dependabot_file(&project_info).unwrap();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/testing.yml");
+        save_ci_testing_linux_only_file(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
@@ -1820,17 +2775,77 @@ mod tests {
     }
 
     #[test]
-    fn test_save_dependabot_file_weekly_no_day() {
+    fn test_uv_ci_testing_frozen() {
+        let versions = vec!["3.12".to_string()];
+        let frozen = create_uv_ci_testing_linux_only_file(
+            "my_project",
+            "3.12",
+            &versions,
+            false,
+            &TypeChecker::Mypy,
+            true,
+        );
+        let not_frozen = create_uv_ci_testing_linux_only_file(
+            "my_project",
+            "3.12",
+            &versions,
+            false,
+            &TypeChecker::Mypy,
+            false,
+        );
+
+        assert_eq!(frozen.matches("run: uv sync --frozen\n").count(), 2);
+        assert!(!not_frozen.contains("--frozen"));
+        assert_eq!(not_frozen.matches("run: uv sync\n").count(), 2);
+    }
+
+    #[test]
+    fn test_uv_publish_files_not_frozen() {
         let mut project_info = project_info_dummy();
-        project_info.project_manager = ProjectManager::Poetry;
-        project_info.use_dependabot = true;
-        project_info.dependabot_schedule = Some(DependabotSchedule::Weekly);
-        project_info.dependabot_day = None;
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.uv_frozen = false;
+        project_info.include_docs = true;
+        project_info.docs_info = Some(docs_info_dummy());
         let base = project_info.base_dir();
-        create_dir_all(base.join(".github")).unwrap();
-        let expected_file = base.join(".github/dependabot.yml");
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        save_pypi_publish_file(&project_info).unwrap();
+        save_docs_publish_file(&project_info).unwrap();
 
-        save_dependabot_file(&project_info).unwrap();
+        for file in ["pypi_publish.yml", "docs_publish.yml"] {
+            let content =
+                std::fs::read_to_string(base.join(".github/workflows").join(file)).unwrap();
+
+            assert!(content.contains("run: uv sync\n"), "{file}");
+            assert!(!content.contains("--frozen"), "{file}");
+        }
+    }
+
+    #[test]
+    fn test_save_ci_testing_multi_os_file_pyo3_uv_not_frozen() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.pyo3_python_manager = Some(Pyo3PythonManager::Uv);
+        project_info.use_multi_os_ci = true;
+        project_info.uv_frozen = false;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        save_ci_testing_multi_os_file(&project_info).unwrap();
+        let content = std::fs::read_to_string(base.join(".github/workflows/testing.yml")).unwrap();
+
+        assert!(content.contains("uv sync\n"));
+        assert!(!content.contains("--frozen"));
+    }
+
+    #[test]
+    fn test_save_pixi_ci_testing_multi_os_file_ty() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Pixi;
+        project_info.type_checker = TypeChecker::Ty;
+        project_info.use_multi_os_ci = true;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/testing.yml");
+        save_ci_testing_multi_os_file(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
@@ -1840,17 +2855,15 @@ mod tests {
     }
 
     #[test]
-    fn test_save_dependabot_file_weekly_tuesday() {
+    fn test_save_ci_testing_multi_os_file_pyo3_bandit() {
         let mut project_info = project_info_dummy();
-        project_info.project_manager = ProjectManager::Poetry;
-        project_info.use_dependabot = true;
-        project_info.dependabot_schedule = Some(DependabotSchedule::Weekly);
-        project_info.dependabot_day = Some(Day::Tuesday);
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.use_multi_os_ci = true;
+        project_info.include_bandit = true;
         let base = project_info.base_dir();
-        create_dir_all(base.join(".github")).unwrap();
-        let expected_file = base.join(".github/dependabot.yml");
-
-        save_dependabot_file(&project_info).unwrap();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/testing.yml");
+        save_ci_testing_multi_os_file(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
@@ -1860,49 +2873,65 @@ mod tests {
     }
 
     #[test]
-    fn test_save_dependabot_file_monthly() {
+    fn test_save_uv_ci_testing_linux_only_file_coverage_comment() {
         let mut project_info = project_info_dummy();
-        project_info.project_manager = ProjectManager::Poetry;
-        project_info.use_dependabot = true;
-        project_info.dependabot_schedule = Some(DependabotSchedule::Monthly);
-        project_info.dependabot_day = None;
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.ci_coverage_comment = true;
         let base = project_info.base_dir();
-        create_dir_all(base.join(".github")).unwrap();
-        let expected_file = base.join(".github/dependabot.yml");
-
-        save_dependabot_file(&project_info).unwrap();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/testing.yml");
+        save_ci_testing_linux_only_file(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
+        assert!(content.contains("      pull-requests: write\n"));
+        assert!(content.contains("if: ${{ matrix.python-version == '3.9' }}"));
         assert_yaml_snapshot!(content);
     }
 
     #[test]
-    fn test_save_dependabot_file_pyo3() {
+    fn test_save_ci_testing_file_coverage_comment_min_version_not_tested() {
         let mut project_info = project_info_dummy();
-        project_info.project_manager = ProjectManager::Maturin;
-        project_info.dependabot_schedule = None;
-        project_info.dependabot_day = None;
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.min_python_version = "3.9".to_string();
+        project_info.github_actions_python_test_versions =
+            vec!["3.11".to_string(), "3.12".to_string()];
+        project_info.ci_coverage_comment = true;
         let base = project_info.base_dir();
-        create_dir_all(base.join(".github")).unwrap();
-        let expected_file = base.join(".github/dependabot.yml");
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        save_ci_testing_linux_only_file(&project_info).unwrap();
+        let content = std::fs::read_to_string(base.join(".github/workflows/testing.yml")).unwrap();
 
-        save_dependabot_file(&project_info).unwrap();
+        assert!(content.contains("if: ${{ matrix.python-version == '3.11' }}"));
+    }
+
+    #[test]
+    fn test_save_ci_testing_multi_os_file_pyo3_coverage_comment() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.use_multi_os_ci = true;
+        project_info.ci_coverage_comment = true;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/testing.yml");
+        save_ci_testing_multi_os_file(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
+        assert!(content.contains("matrix.os == 'ubuntu-latest'"));
         assert_yaml_snapshot!(content);
     }
 
     #[test]
-    fn test_save_dependabot_file_pyo3_daily() {
+    fn test_save_dependabot_file() {
         let mut project_info = project_info_dummy();
-        project_info.project_manager = ProjectManager::Maturin;
-        project_info.dependabot_schedule = Some(DependabotSchedule::Daily);
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.use_dependabot = true;
+        project_info.dependabot_schedule = None;
         project_info.dependabot_day = None;
         let base = project_info.base_dir();
         create_dir_all(base.join(".github")).unwrap();
@@ -1918,7 +2947,125 @@ mod tests {
     }
 
     #[test]
-    fn test_save_dependabot_file_pyo3_weekly() {
+    fn test_save_dependabot_file_daily() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.use_dependabot = true;
+        project_info.dependabot_schedule = Some(DependabotSchedule::Daily);
+        project_info.dependabot_day = None;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github")).unwrap();
+        let expected_file = base.join(".github/dependabot.yml");
+
+        save_dependabot_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_dependabot_file_weekly_no_day() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.use_dependabot = true;
+        project_info.dependabot_schedule = Some(DependabotSchedule::Weekly);
+        project_info.dependabot_day = None;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github")).unwrap();
+        let expected_file = base.join(".github/dependabot.yml");
+
+        save_dependabot_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_dependabot_file_weekly_tuesday() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.use_dependabot = true;
+        project_info.dependabot_schedule = Some(DependabotSchedule::Weekly);
+        project_info.dependabot_day = Some(Day::Tuesday);
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github")).unwrap();
+        let expected_file = base.join(".github/dependabot.yml");
+
+        save_dependabot_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_dependabot_file_monthly() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.use_dependabot = true;
+        project_info.dependabot_schedule = Some(DependabotSchedule::Monthly);
+        project_info.dependabot_day = None;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github")).unwrap();
+        let expected_file = base.join(".github/dependabot.yml");
+
+        save_dependabot_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_dependabot_file_pyo3() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.dependabot_schedule = None;
+        project_info.dependabot_day = None;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github")).unwrap();
+        let expected_file = base.join(".github/dependabot.yml");
+
+        save_dependabot_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_dependabot_file_pyo3_daily() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.dependabot_schedule = Some(DependabotSchedule::Daily);
+        project_info.dependabot_day = None;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github")).unwrap();
+        let expected_file = base.join(".github/dependabot.yml");
+
+        save_dependabot_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_dependabot_file_pyo3_weekly() {
         let mut project_info = project_info_dummy();
         project_info.project_manager = ProjectManager::Maturin;
         project_info.dependabot_schedule = Some(DependabotSchedule::Weekly);
@@ -1990,6 +3137,326 @@ mod tests {
         assert_yaml_snapshot!(content);
     }
 
+    #[test]
+    fn test_save_pypi_publish_file_job_timeout() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.ci_job_timeout = 45;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        save_pypi_publish_file(&project_info).unwrap();
+        let content =
+            std::fs::read_to_string(base.join(".github/workflows/pypi_publish.yml")).unwrap();
+
+        assert!(content.contains("    runs-on: ubuntu-latest\n    timeout-minutes: 45\n"));
+    }
+
+    #[test]
+    fn test_add_job_timeout_every_job() {
+        let content = create_pyo3_pypi_publish_file(
+            "3.12",
+            false,
+            &PublishTarget {
+                workflow_name: "PyPi Publish",
+                release_type: "published",
+                index_url: None,
+                token_secret_name: "PYPI_TOKEN",
+            },
+            &PublishAuth::TrustedPublishing,
+            false,
+            None,
+            false,
+        );
+        let result = add_job_timeout(&content, 30);
+
+        assert_eq!(
+            result.matches("timeout-minutes: 30").count(),
+            content.matches("    runs-on: ").count()
+        );
+    }
+
+    #[test]
+    fn test_save_pypi_publish_file_uv_supply_chain_attestation() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.supply_chain_attestation = true;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/pypi_publish.yml");
+        save_pypi_publish_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_pypi_publish_file_uv_pypi_index_url() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.pypi_index_url = Some("https://pypi.example.com/legacy/".to_string());
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/pypi_publish.yml");
+        save_pypi_publish_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert!(
+            content.contains("run: uv publish --publish-url https://pypi.example.com/legacy/\n")
+        );
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_pypi_publish_file_poetry_pypi_index_url() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.pypi_index_url = Some("https://pypi.example.com/legacy/".to_string());
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/pypi_publish.yml");
+        save_pypi_publish_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert!(content
+            .contains("poetry config repositories.custom https://pypi.example.com/legacy/\n"));
+        assert!(content.contains("poetry publish -r custom --build\n"));
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_pypi_publish_file_pypi_index_url_repository_url() {
+        let mut project_info = project_info_dummy();
+        project_info.pypi_index_url = Some("https://pypi.example.com/legacy/".to_string());
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+
+        for project_manager in [
+            ProjectManager::Maturin,
+            ProjectManager::Setuptools,
+            ProjectManager::Pixi,
+        ] {
+            project_info.project_manager = project_manager;
+            save_pypi_publish_file(&project_info).unwrap();
+            let content =
+                std::fs::read_to_string(base.join(".github/workflows/pypi_publish.yml")).unwrap();
+
+            assert!(content.contains(" --repository-url https://pypi.example.com/legacy/ "));
+        }
+    }
+
+    #[test]
+    fn test_save_pypi_publish_file_poetry_api_token() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.publish_auth = PublishAuth::ApiToken;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/pypi_publish.yml");
+        save_pypi_publish_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert!(content.contains("POETRY_PYPI_TOKEN_PYPI: ${{ secrets.PYPI_TOKEN }}"));
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_pypi_publish_file_uv_api_token() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.publish_auth = PublishAuth::ApiToken;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/pypi_publish.yml");
+        save_pypi_publish_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert!(!content.contains("id-token: write"));
+        assert!(content.contains("UV_PUBLISH_TOKEN: ${{ secrets.PYPI_TOKEN }}"));
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_testpypi_publish_file_uv() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.include_testpypi_publish = true;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/testpypi_publish.yml");
+        save_testpypi_publish_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert!(content.contains("run: uv publish --publish-url https://test.pypi.org/legacy/\n"));
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_pypi_publish_file_testpypi_skips_pre_releases() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.include_testpypi_publish = true;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        save_pypi_publish_file(&project_info).unwrap();
+        let content =
+            std::fs::read_to_string(base.join(".github/workflows/pypi_publish.yml")).unwrap();
+
+        assert!(content.contains("    types:\n    - released\n"));
+    }
+
+    #[test]
+    fn test_save_testpypi_publish_file_api_token() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Setuptools;
+        project_info.publish_auth = PublishAuth::ApiToken;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        save_testpypi_publish_file(&project_info).unwrap();
+        let content =
+            std::fs::read_to_string(base.join(".github/workflows/testpypi_publish.yml")).unwrap();
+
+        assert!(content.contains("TWINE_PASSWORD: ${{ secrets.TEST_PYPI_TOKEN }}"));
+        assert!(content.contains("twine upload --repository-url https://test.pypi.org/legacy/ "));
+    }
+
+    #[test]
+    fn test_save_pypi_publish_file_pyo3_abi3() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.use_abi3 = true;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        save_pypi_publish_file(&project_info).unwrap();
+        let content =
+            std::fs::read_to_string(base.join(".github/workflows/pypi_publish.yml")).unwrap();
+
+        assert!(!content.contains("--find-interpreter"));
+        assert_eq!(content.matches("args: --release --out dist\n").count(), 3);
+    }
+
+    #[test]
+    fn test_save_pypi_publish_file_pyo3_maturin_targets() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.maturin_targets = Some(vec![
+            "x86_64".to_string(),
+            "aarch64-unknown-linux-musl".to_string(),
+        ]);
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        save_pypi_publish_file(&project_info).unwrap();
+        let content =
+            std::fs::read_to_string(base.join(".github/workflows/pypi_publish.yml")).unwrap();
+
+        assert!(content.contains("  linux:\n"));
+        assert!(content.contains("        target: [x86_64, aarch64-unknown-linux-musl]\n"));
+        assert!(content.contains("  macos:\n"));
+        assert!(content.contains("        target: [x86_64]\n"));
+        assert!(!content.contains("  windows:\n"));
+        assert!(content.contains("    needs: [linux, macos, sdist]\n"));
+    }
+
+    #[test]
+    fn test_save_pypi_publish_file_pyo3_maturin_windows_triples() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.maturin_targets = Some(vec![
+            "x64".to_string(),
+            "x86_64-pc-windows-msvc".to_string(),
+            "i686-pc-windows-msvc".to_string(),
+        ]);
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        save_pypi_publish_file(&project_info).unwrap();
+        let content =
+            std::fs::read_to_string(base.join(".github/workflows/pypi_publish.yml")).unwrap();
+
+        assert!(content.contains("  windows:\n"));
+        assert!(content.contains("        target: [x64, x86]\n"));
+        assert!(content.contains("          architecture: ${{ matrix.target }}\n"));
+        assert!(!content.contains("windows-msvc"));
+    }
+
+    #[test]
+    fn test_save_pypi_publish_file_api_token_all_managers() {
+        let mut project_info = project_info_dummy();
+        project_info.publish_auth = PublishAuth::ApiToken;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+
+        for project_manager in [
+            ProjectManager::Maturin,
+            ProjectManager::Setuptools,
+            ProjectManager::Pixi,
+        ] {
+            project_info.project_manager = project_manager;
+            save_pypi_publish_file(&project_info).unwrap();
+            let content =
+                std::fs::read_to_string(base.join(".github/workflows/pypi_publish.yml")).unwrap();
+
+            assert!(!content.contains("id-token: write"));
+            assert!(content.contains("${{ secrets.PYPI_TOKEN }}"));
+        }
+    }
+
+    #[test]
+    fn test_save_pypi_publish_file_api_token_supply_chain_attestation() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.publish_auth = PublishAuth::ApiToken;
+        project_info.supply_chain_attestation = true;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        save_pypi_publish_file(&project_info).unwrap();
+        let content =
+            std::fs::read_to_string(base.join(".github/workflows/pypi_publish.yml")).unwrap();
+
+        assert!(content.contains("      id-token: write\n      attestations: write"));
+        assert!(!content.contains("trusted publishing"));
+    }
+
+    #[test]
+    fn test_save_pypi_publish_file_supply_chain_attestation_all_managers() {
+        let mut project_info = project_info_dummy();
+        project_info.supply_chain_attestation = true;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+
+        for project_manager in [
+            ProjectManager::Maturin,
+            ProjectManager::Poetry,
+            ProjectManager::Setuptools,
+            ProjectManager::Pixi,
+        ] {
+            project_info.project_manager = project_manager;
+            save_pypi_publish_file(&project_info).unwrap();
+            let content =
+                std::fs::read_to_string(base.join(".github/workflows/pypi_publish.yml")).unwrap();
+
+            assert!(content.contains("attestations: write"));
+            assert!(content.contains("uses: anchore/sbom-action@v0"));
+            assert!(content.contains("uses: actions/attest-build-provenance@v2"));
+        }
+    }
+
     #[test]
     fn test_save_docs_publish_file_poetry() {
         let mut project_info = project_info_dummy();
@@ -2024,6 +3491,23 @@ mod tests {
         assert_yaml_snapshot!(content);
     }
 
+    #[test]
+    fn test_save_pypi_publish_file_pyo3_musllinux() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.build_musllinux = true;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/pypi_publish.yml");
+        save_pypi_publish_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
     #[test]
     fn test_save_docs_publish_file_pyo3() {
         let mut project_info = project_info_dummy();
@@ -2169,4 +3653,177 @@ mod tests {
 
         assert_yaml_snapshot!(release_drafter_file_template_content);
     }
+
+    #[test]
+    fn test_save_docker_publish_file() {
+        let project_info = project_info_dummy();
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/docker_publish.yml");
+        save_docker_publish_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert!(content.contains(
+            r#"echo "IMAGE_NAME=${GITHUB_REPOSITORY_OWNER,,}/my-project" >> "$GITHUB_ENV""#
+        ));
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_github_templates_bug_report() {
+        let mut project_info = project_info_dummy();
+        project_info.include_github_templates = true;
+        let base = project_info.base_dir().join(".github");
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("ISSUE_TEMPLATE/bug_report.md");
+        save_github_templates(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_github_templates_feature_request() {
+        let mut project_info = project_info_dummy();
+        project_info.include_github_templates = true;
+        let base = project_info.base_dir().join(".github");
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("ISSUE_TEMPLATE/feature_request.md");
+        save_github_templates(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_github_templates_pull_request() {
+        let mut project_info = project_info_dummy();
+        project_info.include_github_templates = true;
+        let base = project_info.base_dir().join(".github");
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("pull_request_template.md");
+        save_github_templates(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_codeql_file() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.include_codeql = true;
+        let base = project_info.base_dir().join(".github/workflows");
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("codeql.yml");
+        save_codeql_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert!(content.contains(r#"language: ["python"]"#));
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_codeql_file_pyo3() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.pyo3_python_manager = Some(Pyo3PythonManager::Uv);
+        project_info.include_codeql = true;
+        let base = project_info.base_dir().join(".github/workflows");
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("codeql.yml");
+        save_codeql_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert!(content.contains(r#"language: ["python", "rust"]"#));
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_codeowners_file() {
+        let mut project_info = project_info_dummy();
+        project_info.include_codeowners = true;
+        project_info.github_username = Some("arthurdent".to_string());
+        let base = project_info.base_dir().join(".github");
+        create_dir_all(&base).unwrap();
+        save_codeowners_file(&project_info).unwrap();
+
+        let content = std::fs::read_to_string(base.join("CODEOWNERS")).unwrap();
+
+        assert_eq!(content, "* @arthurdent\n");
+    }
+
+    #[test]
+    fn test_save_codeowners_file_username_with_at() {
+        let mut project_info = project_info_dummy();
+        project_info.include_codeowners = true;
+        project_info.github_username = Some("@arthurdent".to_string());
+        let base = project_info.base_dir().join(".github");
+        create_dir_all(&base).unwrap();
+        save_codeowners_file(&project_info).unwrap();
+
+        let content = std::fs::read_to_string(base.join("CODEOWNERS")).unwrap();
+
+        assert_eq!(content, "* @arthurdent\n");
+    }
+
+    #[test]
+    fn test_save_codeowners_file_no_username() {
+        let mut project_info = project_info_dummy();
+        project_info.include_codeowners = true;
+        let base = project_info.base_dir().join(".github");
+        create_dir_all(&base).unwrap();
+        save_codeowners_file(&project_info).unwrap();
+
+        assert!(!base.join("CODEOWNERS").exists());
+    }
+
+    #[test]
+    fn test_save_aws_deploy_file() {
+        let mut project_info = project_info_dummy();
+        project_info.cloud_provider = Some(CloudProvider::Aws);
+        project_info.cloud_deploy_role = Some("arn:aws:iam::123456789012:role/deploy".to_string());
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        let expected_file = base.join(".github/workflows/deploy.yml");
+        save_deploy_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_gcp_deploy_file_role_from_variable() {
+        let mut project_info = project_info_dummy();
+        project_info.cloud_provider = Some(CloudProvider::Gcp);
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        save_deploy_file(&project_info).unwrap();
+        let content = std::fs::read_to_string(base.join(".github/workflows/deploy.yml")).unwrap();
+
+        assert!(content.contains("id-token: write"));
+        assert!(content
+            .contains("workload_identity_provider: ${{ vars.GCP_WORKLOAD_IDENTITY_PROVIDER }}"));
+    }
 }
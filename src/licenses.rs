@@ -191,11 +191,11 @@ fn save_apache_license(project_info: &ProjectInfo) -> Result<()> {
     Ok(())
 }
 
-fn create_mit_license(copyright_year: &str, creator: &str) -> String {
+fn create_mit_license(copyright_year: &str, copyright_holder: &str) -> String {
     format!(
         r#"MIT License
 
-Copyright (c) {copyright_year} {creator}
+Copyright (c) {copyright_year} {copyright_holder}
 
 Permission is hereby granted, free of charge, to any person obtaining a copy
 of this software and associated documentation files (the "Software"), to deal
@@ -223,7 +223,11 @@ fn save_mit_license(project_info: &ProjectInfo) -> Result<()> {
 
     match &project_info.copyright_year {
         Some(year) => {
-            let content = create_mit_license(year, &project_info.creator);
+            let copyright_holder = project_info
+                .copyright_holder
+                .as_deref()
+                .unwrap_or(&project_info.creator);
+            let content = create_mit_license(year, copyright_holder);
             save_file_with_content(&file_path, &content)?;
         }
         None => bail!("A copyright year is required for a MIT license"),
@@ -253,8 +257,9 @@ pub fn license_str(license: &LicenseType) -> &str {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::project_info::ProjectManager;
+    use crate::project_info::{ProjectManager, PublishAuth, TypeChecker};
     use insta::assert_yaml_snapshot;
+    use std::collections::HashMap;
     use std::fs::create_dir_all;
     use tmp_path::tmp_path;
 
@@ -282,6 +287,7 @@ mod tests {
                 "3.11".to_string(),
             ],
             max_line_length: 100,
+            ruff_line_length: None,
             use_dependabot: true,
             dependabot_schedule: None,
             dependabot_day: None,
@@ -291,6 +297,47 @@ mod tests {
             include_docs: false,
             docs_info: None,
             download_latest_packages: false,
+            min_coverage: None,
+            extra_ruff_rules: Vec::new(),
+            include_bandit: false,
+            extra_pre_commit_hooks: Vec::new(),
+            use_precommit_ci: false,
+            include_settings: false,
+            include_logging: false,
+            mypy_files: None,
+            ruff_target_version: None,
+            offline: false,
+            cloud_provider: None,
+            cloud_deploy_role: None,
+            publish_docker: false,
+            supply_chain_attestation: false,
+            pypi_index_url: None,
+            publish_auth: PublishAuth::TrustedPublishing,
+            include_testpypi_publish: false,
+            include_rust_toolchain: false,
+            rust_edition: "2021".to_string(),
+            pyo3_version: None,
+            use_abi3: false,
+            build_musllinux: false,
+            maturin_targets: None,
+            package_version_overrides: HashMap::new(),
+            ci_job_timeout: 30,
+            dependency_bound_style: None,
+            type_checker: TypeChecker::Mypy,
+            include_conda_env: false,
+            copyright_holder: None,
+            additional_authors: Vec::new(),
+            include_codeowners: false,
+            github_username: None,
+            include_github_templates: false,
+            include_contributing: false,
+            include_codeql: false,
+            include_security_policy: false,
+            include_changelog: false,
+            include_citation: false,
+            include_benchmarks: false,
+            ci_coverage_comment: false,
+            uv_frozen: true,
             project_root_dir: Some(tmp_path),
         }
     }
@@ -327,6 +374,45 @@ mod tests {
         assert_yaml_snapshot!(content);
     }
 
+    #[test]
+    fn test_save_mit_license_copyright_holder_fallback() {
+        let project_info = project_info_dummy();
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        save_mit_license(&project_info).unwrap();
+
+        let content = std::fs::read_to_string(base.join("LICENSE")).unwrap();
+
+        assert!(content.contains("Copyright (c) 2023 Arthur Dent\n"));
+    }
+
+    #[test]
+    fn test_save_mit_license_copyright_holder() {
+        let mut project_info = project_info_dummy();
+        project_info.copyright_holder = Some("Heart of Gold Inc.".to_string());
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        save_mit_license(&project_info).unwrap();
+
+        let content = std::fs::read_to_string(base.join("LICENSE")).unwrap();
+
+        assert!(content.contains("Copyright (c) 2023 Heart of Gold Inc.\n"));
+        assert!(!content.contains("Arthur Dent"));
+    }
+
+    #[test]
+    fn test_save_mit_license_copyright_year_range() {
+        let mut project_info = project_info_dummy();
+        project_info.copyright_year = Some("2021-present".to_string());
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        save_mit_license(&project_info).unwrap();
+
+        let content = std::fs::read_to_string(base.join("LICENSE")).unwrap();
+
+        assert!(content.contains("Copyright (c) 2021-present Arthur Dent\n"));
+    }
+
     #[test]
     fn test_license_str_mit() {
         assert_eq!(license_str(&LicenseType::Mit), "MIT");
@@ -1,40 +1,309 @@
+mod batch;
 mod cli;
 mod config;
 mod file_manager;
 mod github_actions;
 mod licenses;
 mod package_version;
+mod project_detection;
 mod project_generator;
 mod project_info;
 mod python_files;
 mod rust_files;
 mod utils;
 
+use std::ffi::OsStr;
 use std::fs::remove_dir_all;
+use std::io::{ErrorKind, Write};
+use std::path::Path;
 use std::process::exit;
 use std::time::Duration;
 
-use anyhow::{Error, Result};
-use clap::Parser;
+use anyhow::{anyhow, bail, Error, Result};
+use clap::{CommandFactory, Parser, ValueEnum};
 use cli::ApplicationOrLib;
 use colored::*;
 use indicatif::{ProgressBar, ProgressStyle};
 
-use crate::cli::{Args, BooleanChoice, Command, Param};
-use crate::config::Config;
-use crate::project_generator::generate_project;
-use crate::project_info::{get_project_info, ProjectInfo};
+use crate::batch::{create_batch, load_batch_file};
+use crate::cli::{Args, BooleanChoice, Command, Param, ProfileCommand, VersionsCommand};
+use crate::config::{set_config_file, Config};
+use crate::package_version::{offline_versions, VersionCache};
+use crate::project_detection::{apply_saved_config, detect_project_info};
+use crate::project_generator::{
+    docker_publish_warning, generate_project, update_project, update_pyproject, GenerationTimings,
+};
+use crate::project_info::{
+    get_project_info, CloudProvider, LicenseType, ProjectInfo, ProjectManager, Pyo3PythonManager,
+};
 
-fn create(project_info: &ProjectInfo) -> Result<()> {
-    generate_project(project_info)?;
-    std::process::Command::new("git")
-        .args(["init", &project_info.project_slug])
+fn create(
+    project_info: &ProjectInfo,
+    lock: bool,
+    install: bool,
+    format: bool,
+    init_git: bool,
+) -> Result<GenerationTimings> {
+    if let Some(warning) = missing_rust_warning(project_info, std::env::var_os("PATH").as_deref()) {
+        println!("\n{}", warning.yellow());
+    }
+
+    if let Some(warning) = docker_publish_warning(project_info) {
+        println!("\n{}", warning.yellow());
+    }
+
+    let generation_timings = generate_project(project_info)?;
+
+    if format {
+        format_project(project_info)?;
+    }
+
+    // The project files are already written so failing to initialize git shouldn't fail the
+    // project creation.
+    if init_git {
+        if let Err(e) = init_git_repository("git", &project_info.base_dir()) {
+            let message = format!("Skipped initializing git: {e}");
+            eprintln!("{}", message.yellow());
+        }
+    }
+
+    if lock {
+        lock_project(project_info)?;
+    }
+
+    if install {
+        install_project(project_info)?;
+    }
+
+    Ok(generation_timings)
+}
+
+fn init_git_repository(git_program: &str, project_dir: &Path) -> Result<()> {
+    let output = match std::process::Command::new(git_program)
+        .arg("init")
+        .arg(project_dir)
+        .output()
+    {
+        Ok(o) => o,
+        Err(e) if e.kind() == ErrorKind::NotFound => bail!("{git_program} is not installed"),
+        Err(e) => bail!(e),
+    };
+
+    if !output.status.success() {
+        bail!(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(())
+}
+
+fn timings_message(timings: bool, generation_timings: &GenerationTimings) -> Option<String> {
+    timings.then(|| generation_timings.report())
+}
+
+/// The spinner is shown unless it is disabled by the `--no-spinner` flag, a non-empty
+/// `PPG_NO_SPINNER` environment variable, or the saved config, in that order.
+fn spinner_enabled(
+    no_spinner: bool,
+    env_value: Option<&OsStr>,
+    config_value: Option<bool>,
+) -> bool {
+    if no_spinner || env_value.is_some_and(|v| !v.is_empty()) {
+        return false;
+    }
+
+    config_value.unwrap_or(true)
+}
+
+/// Runs `f` with an animated spinner, or prints the message on a single line when the spinner is
+/// disabled so the output works in terminals and logs that don't render the animation.
+fn with_progress<T>(message: &str, use_spinner: bool, f: impl FnOnce() -> T) -> T {
+    if use_spinner {
+        if let Ok(progress_style) = ProgressStyle::with_template("{spinner:.green} {msg}") {
+            let pb = ProgressBar::new_spinner();
+            pb.enable_steady_tick(Duration::from_millis(80));
+            pb.set_style(progress_style.tick_strings(&["⣷", "⣯", "⣟", "⡿", "⢿", "⣻", "⣽", "⣾"]));
+            pb.set_message(message.to_string());
+            let result = f();
+            pb.finish_and_clear();
+
+            return result;
+        }
+    }
+
+    println!("{message}");
+
+    f()
+}
+
+fn is_on_path(program: &str, path: &OsStr) -> bool {
+    std::env::split_paths(path).any(|dir| {
+        let candidate = dir.join(program);
+        candidate.is_file() || candidate.with_extension("exe").is_file()
+    })
+}
+
+/// The project is still generated without Rust, it just can't be built until a toolchain is
+/// installed.
+fn missing_rust_warning(project_info: &ProjectInfo, path: Option<&OsStr>) -> Option<String> {
+    if !matches!(project_info.project_manager, ProjectManager::Maturin) {
+        return None;
+    }
+
+    let rust_installed = path.is_some_and(|p| is_on_path("cargo", p) && is_on_path("rustc", p));
+
+    if rust_installed {
+        None
+    } else {
+        Some(
+            "Rust was not found. Maturin projects need a Rust toolchain to build, it can be installed from https://rustup.rs"
+                .to_string(),
+        )
+    }
+}
+
+/// Locking, installing, and formatting are conveniences run after the project is generated, so a
+/// missing tool or a failed run only produces a warning ending with `what` didn't happen.
+fn run_optional_tool(program: &str, args: &[&str], dir: &Path, what: &str) -> Result<()> {
+    let command = format!("{program} {}", args.join(" "));
+
+    match std::process::Command::new(program)
+        .args(args)
+        .current_dir(dir)
         .output()
-        .expect("Failed to initialize git");
+    {
+        Ok(output) => {
+            if !output.status.success() {
+                let message = format!(
+                    "Error running {command}, {what}: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+                println!("\n{}", message.yellow());
+            }
+        }
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            let message = format!("{program} was not found, {what}");
+            println!("\n{}", message.yellow());
+        }
+        Err(e) => bail!("Error running {command}: {e}"),
+    }
 
     Ok(())
 }
 
+fn lock_command(project_info: &ProjectInfo) -> Option<(&str, &str)> {
+    match project_info.project_manager {
+        ProjectManager::Poetry => Some(("poetry", "lock")),
+        ProjectManager::Uv => Some(("uv", "lock")),
+        ProjectManager::Pixi => Some(("pixi", "install")),
+        ProjectManager::Maturin => match project_info.pyo3_python_manager {
+            Some(Pyo3PythonManager::Uv) => Some(("uv", "lock")),
+            _ => None,
+        },
+        ProjectManager::Setuptools => None,
+    }
+}
+
+fn lock_project(project_info: &ProjectInfo) -> Result<()> {
+    let Some((program, arg)) = lock_command(project_info) else {
+        let message = format!(
+            "{} projects don't use a lock file. Skipping lock.",
+            project_info.project_manager
+        );
+        println!("\n{}", message.yellow());
+        return Ok(());
+    };
+
+    run_optional_tool(
+        program,
+        &[arg],
+        &project_info.base_dir(),
+        "no lock file was created",
+    )
+}
+
+/// pip based projects don't have a managed environment so there is nothing safe to install into.
+fn install_command(project_info: &ProjectInfo) -> Option<(&str, Vec<&str>)> {
+    match project_info.project_manager {
+        ProjectManager::Poetry => Some(("poetry", vec!["install"])),
+        ProjectManager::Uv => Some(("uv", vec!["sync", "--all-extras"])),
+        ProjectManager::Pixi => Some(("pixi", vec!["install"])),
+        ProjectManager::Maturin => match project_info.pyo3_python_manager {
+            Some(Pyo3PythonManager::Uv) => Some(("uv", vec!["sync", "--all-extras"])),
+            _ => None,
+        },
+        ProjectManager::Setuptools => None,
+    }
+}
+
+fn install_project(project_info: &ProjectInfo) -> Result<()> {
+    let Some((program, args)) = install_command(project_info) else {
+        let message = format!(
+            "{} projects don't have a managed environment. Skipping install.",
+            project_info.project_manager
+        );
+        println!("\n{}", message.yellow());
+        return Ok(());
+    };
+
+    run_optional_tool(
+        program,
+        &args,
+        &project_info.base_dir(),
+        "the dependencies were not installed",
+    )
+}
+
+fn format_command(project_info: &ProjectInfo) -> (&str, Vec<&str>) {
+    match project_info.project_manager {
+        ProjectManager::Poetry => ("poetry", vec!["run", "ruff", "format", "."]),
+        ProjectManager::Uv => ("uv", vec!["run", "ruff", "format", "."]),
+        ProjectManager::Pixi => ("pixi", vec!["run", "-e", "dev", "ruff", "format", "."]),
+        ProjectManager::Maturin => match project_info.pyo3_python_manager {
+            Some(Pyo3PythonManager::Uv) => ("uv", vec!["run", "ruff", "format", "."]),
+            _ => ("ruff", vec!["format", "."]),
+        },
+        ProjectManager::Setuptools => ("ruff", vec!["format", "."]),
+    }
+}
+
+fn format_project(project_info: &ProjectInfo) -> Result<()> {
+    let (program, args) = format_command(project_info);
+
+    run_optional_tool(
+        program,
+        &args,
+        &project_info.base_dir(),
+        "the project was not formatted",
+    )
+}
+
+fn value_names<T: ValueEnum>() -> Vec<String> {
+    T::value_variants()
+        .iter()
+        .filter_map(|v| v.to_possible_value())
+        .map(|v| v.get_name().to_string())
+        .collect()
+}
+
+fn features_json() -> serde_json::Value {
+    serde_json::json!({
+        "project_managers": value_names::<ProjectManager>(),
+        "pyo3_python_managers": value_names::<Pyo3PythonManager>(),
+        "licenses": value_names::<LicenseType>(),
+        "ci_providers": ["github-actions"],
+        "cloud_providers": value_names::<CloudProvider>(),
+    })
+}
+
+/// The command is named after the installed binary rather than the crate so the man page matches
+/// what users type.
+fn render_manpage() -> Result<Vec<u8>> {
+    let mut manpage = Vec::new();
+    clap_mangen::Man::new(Args::command().name("python-project")).render(&mut manpage)?;
+
+    Ok(manpage)
+}
+
 fn print_error(err: Error) {
     eprintln!("\n{}", err.to_string().red());
 }
@@ -51,41 +320,119 @@ fn delete_slug(project_info: &ProjectInfo) -> Result<()> {
 
 fn main() {
     let args = Args::parse();
+    set_config_file(args.config);
     match args.command {
         Command::Create {
             skip_download_latest_packages,
+            offline,
             default,
+            lock,
+            install,
+            format,
+            include_settings,
+            include_logging,
+            module_name,
+            authors,
+            batch,
+            atomic,
+            no_spinner,
+            no_git,
+            timings,
+            profile,
         } => {
-            let mut project_info = match get_project_info(default) {
+            let config = match &profile {
+                Some(name) => match Config::default().load_profile(name) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        print_error(e);
+                        exit(1);
+                    }
+                },
+                None => Config::default().load_config(),
+            };
+
+            let init_git = !no_git && config.init_git.unwrap_or(true);
+            let apply_flags = |project_info: &mut ProjectInfo| {
+                project_info.download_latest_packages = !skip_download_latest_packages;
+                project_info.offline = offline;
+                project_info.include_settings = include_settings;
+                project_info.include_logging = include_logging;
+                project_info.additional_authors = authors.clone();
+            };
+
+            if let Some(batch_file) = batch {
+                let projects = match load_batch_file(&batch_file) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        print_error(e);
+                        exit(1);
+                    }
+                };
+                let report = create_batch(&projects, &config, atomic, |project_info| {
+                    println!("\nCreating {}", project_info.project_name);
+                    apply_flags(project_info);
+                    let generation_timings = create(project_info, lock, install, format, init_git)?;
+                    let success_message = format!(
+                        "Project created in the {} directory",
+                        project_info.project_slug
+                    );
+                    println!("{}", success_message.green());
+
+                    if let Some(message) = timings_message(timings, &generation_timings) {
+                        println!("{message}");
+                    }
+
+                    Ok(())
+                });
+
+                for (project_name, e) in report.failures.iter() {
+                    print_error(anyhow!("Error creating {project_name}: {e}"));
+                }
+
+                if report.rolled_back > 0 {
+                    let message = format!(
+                        "\nRemoved {} previously created projects because --atomic was set",
+                        report.rolled_back
+                    );
+                    eprintln!("{}", message.yellow());
+                }
+
+                if !report.failures.is_empty() {
+                    exit(1);
+                }
+
+                return;
+            }
+
+            let use_spinner = spinner_enabled(
+                no_spinner,
+                std::env::var_os("PPG_NO_SPINNER").as_deref(),
+                config.use_spinner,
+            );
+            let mut project_info = match get_project_info(config, default, module_name) {
                 Ok(pi) => pi,
                 Err(e) => {
                     print_error(e);
                     exit(1);
                 }
             };
-            project_info.download_latest_packages = !skip_download_latest_packages;
+            apply_flags(&mut project_info);
 
-            let create_result: Result<()>;
-            if let Ok(progress_style) = ProgressStyle::with_template("{spinner:.green} {msg}") {
-                let pb = ProgressBar::new_spinner();
-                pb.enable_steady_tick(Duration::from_millis(80));
-                pb.set_style(
-                    progress_style.tick_strings(&["⣷", "⣯", "⣟", "⡿", "⢿", "⣻", "⣽", "⣾"]),
-                );
-                pb.set_message("Generating Project...");
-                create_result = create(&project_info);
-                pb.finish_and_clear();
-            } else {
-                create_result = create(&project_info);
-            }
+            let create_result = with_progress("Generating Project...", use_spinner, || {
+                create(&project_info, lock, install, format, init_git)
+            });
 
             match create_result {
-                Ok(_) => {
+                Ok(generation_timings) => {
                     let success_message = format!(
                         "\nProject created in the {} directory",
                         project_info.project_slug
                     );
                     println!("{}", success_message.green());
+
+                    if let Some(message) = timings_message(timings, &generation_timings) {
+                        println!("{message}");
+                    }
                 }
                 Err(e) => {
                     print_error(e);
@@ -96,6 +443,132 @@ fn main() {
                 }
             };
         }
+        Command::Update {
+            path,
+            skip_download_latest_packages,
+        } => {
+            let project_dir = match path {
+                Some(p) => p,
+                None => match std::env::current_dir() {
+                    Ok(dir) => dir,
+                    Err(e) => {
+                        print_error(e.into());
+                        exit(1);
+                    }
+                },
+            };
+            let mut project_info = match detect_project_info(&project_dir) {
+                Ok(pi) => pi,
+                Err(e) => {
+                    print_error(e);
+                    exit(1);
+                }
+            };
+            project_info.download_latest_packages = !skip_download_latest_packages;
+
+            if let Some(warning) = docker_publish_warning(&project_info) {
+                eprintln!("\n{}", warning.yellow());
+            }
+
+            if let Err(e) = update_project(&project_info) {
+                print_error(e);
+                exit(1);
+            }
+
+            let success_message = format!(
+                "\nCI and pre-commit files updated in {}",
+                project_dir.display()
+            );
+            println!("{}", success_message.green());
+        }
+        Command::UpdatePyproject {
+            path,
+            skip_download_latest_packages,
+        } => {
+            let project_dir = match path {
+                Some(p) => p,
+                None => match std::env::current_dir() {
+                    Ok(dir) => dir,
+                    Err(e) => {
+                        print_error(e.into());
+                        exit(1);
+                    }
+                },
+            };
+            let mut project_info = match detect_project_info(&project_dir) {
+                Ok(pi) => pi,
+                Err(e) => {
+                    print_error(e);
+                    exit(1);
+                }
+            };
+            project_info.download_latest_packages = !skip_download_latest_packages;
+            apply_saved_config(&mut project_info, &Config::default().load_config());
+
+            if let Err(e) = update_pyproject(&project_info) {
+                print_error(e);
+                exit(1);
+            }
+
+            let success_message = format!("\npyproject.toml updated in {}", project_dir.display());
+            println!("{}", success_message.green());
+        }
+        Command::Features => match serde_json::to_string_pretty(&features_json()) {
+            Ok(features) => println!("{features}"),
+            Err(e) => {
+                print_error(e.into());
+                exit(1);
+            }
+        },
+        Command::ListManagers => {
+            for project_manager in ProjectManager::value_variants() {
+                println!(
+                    "{}: {} (build backend: {})",
+                    project_manager.to_string().blue(),
+                    project_manager.description(),
+                    project_manager.build_backend()
+                );
+            }
+        }
+        Command::Manpage { output } => {
+            let manpage = match render_manpage() {
+                Ok(m) => m,
+                Err(e) => {
+                    print_error(e);
+                    exit(1);
+                }
+            };
+            let result = match output {
+                Some(path) => std::fs::write(path, manpage),
+                None => std::io::stdout().write_all(&manpage),
+            };
+
+            if let Err(e) = result {
+                print_error(e.into());
+                exit(1);
+            }
+        }
+        Command::Versions(versions) => match versions.command {
+            VersionsCommand::Show => {
+                let overrides = Config::default()
+                    .load_config()
+                    .package_version_overrides
+                    .unwrap_or_default();
+                for (name, version, pinned) in offline_versions(&overrides) {
+                    if pinned {
+                        println!("{}: {version} (pinned)", name.blue());
+                    } else {
+                        println!("{}: {version}", name.blue());
+                    }
+                }
+            }
+            VersionsCommand::Set { name, version } => {
+                if let Err(e) = Config::default().save_package_version(name, version) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+        },
         Command::Config(config) => match config.param {
             Param::Creator { value } => {
                 if let Err(e) = Config::default().save_creator(value) {
@@ -103,7 +576,7 @@ fn main() {
                     exit(1);
                 }
             }
-            Param::ResetCreator {} => {
+            Param::ResetCreator => {
                 if let Err(e) = Config::default().reset_creator() {
                     print_error(e);
                     exit(1);
@@ -115,19 +588,31 @@ fn main() {
                     exit(1);
                 }
             }
-            Param::ResetCreatorEmail {} => {
+            Param::ResetCreatorEmail => {
                 if let Err(e) = Config::default().reset_creator_email() {
                     print_error(e);
                     exit(1);
                 }
             }
+            Param::CopyrightHolder { value } => {
+                if let Err(e) = Config::default().save_copyright_holder(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetCopyrightHolder => {
+                if let Err(e) = Config::default().reset_copyright_holder() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
             Param::License { value } => {
                 if let Err(e) = Config::default().save_license(value) {
                     print_error(e);
                     exit(1);
                 }
             }
-            Param::ResetLicense {} => {
+            Param::ResetLicense => {
                 if let Err(e) = Config::default().reset_license() {
                     print_error(e);
                     exit(1);
@@ -139,7 +624,7 @@ fn main() {
                     exit(1);
                 }
             }
-            Param::ResetPythonVersion {} => {
+            Param::ResetPythonVersion => {
                 if let Err(e) = Config::default().reset_python_version() {
                     print_error(e);
                     exit(1);
@@ -151,7 +636,7 @@ fn main() {
                     exit(1);
                 }
             }
-            Param::ResetMinPythonVersion {} => {
+            Param::ResetMinPythonVersion => {
                 if let Err(e) = Config::default().reset_min_python_version() {
                     print_error(e);
                     exit(1);
@@ -163,7 +648,7 @@ fn main() {
                     exit(1);
                 }
             }
-            Param::ResetProjectManager {} => {
+            Param::ResetProjectManager => {
                 if let Err(e) = Config::default().reset_project_manager() {
                     print_error(e);
                     exit(1);
@@ -175,7 +660,7 @@ fn main() {
                     exit(1);
                 }
             }
-            Param::ResetPyo3PythonManager {} => {
+            Param::ResetPyo3PythonManager => {
                 if let Err(e) = Config::default().reset_pyo3_python_manager() {
                     print_error(e);
                     exit(1);
@@ -195,7 +680,7 @@ fn main() {
                     }
                 }
             },
-            Param::ResetApplicationOrLibrary {} => {
+            Param::ResetApplicationOrLibrary => {
                 if let Err(e) = Config::default().reset_is_application() {
                     print_error(e);
                     exit(1);
@@ -215,7 +700,7 @@ fn main() {
                     }
                 }
             },
-            Param::ResetIsAsyncProject {} => {
+            Param::ResetIsAsyncProject => {
                 if let Err(e) = Config::default().reset_is_async_project() {
                     print_error(e);
                     exit(1);
@@ -227,7 +712,7 @@ fn main() {
                     exit(1);
                 }
             }
-            Param::ResetGithubActionPythonTestVersions {} => {
+            Param::ResetGithubActionPythonTestVersions => {
                 if let Err(e) = Config::default().reset_github_actions_python_test_versions() {
                     print_error(e);
                     exit(1);
@@ -239,183 +724,873 @@ fn main() {
                     exit(1);
                 }
             }
-            Param::ResetMaxLineLength {} => {
+            Param::ResetMaxLineLength => {
                 if let Err(e) = Config::default().reset_max_line_length() {
                     print_error(e);
                     exit(1);
                 }
             }
-            Param::UseDependabot { value } => match value {
+            Param::RuffLineLength { value } => {
+                if let Err(e) = Config::default().save_ruff_line_length(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetRuffLineLength => {
+                if let Err(e) = Config::default().reset_ruff_line_length() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::MinCoverage { value } => {
+                if let Err(e) = Config::default().save_min_coverage(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetMinCoverage => {
+                if let Err(e) = Config::default().reset_min_coverage() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ExtraRuffRules { value } => {
+                if let Err(e) = Config::default().save_extra_ruff_rules(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetExtraRuffRules => {
+                if let Err(e) = Config::default().reset_extra_ruff_rules() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::IncludeBandit { value } => match value {
                 BooleanChoice::True => {
-                    if let Err(e) = Config::default().save_use_dependabot(true) {
+                    if let Err(e) = Config::default().save_include_bandit(true) {
                         print_error(e);
                         exit(1);
                     }
                 }
                 BooleanChoice::False => {
-                    if let Err(e) = Config::default().save_use_dependabot(false) {
+                    if let Err(e) = Config::default().save_include_bandit(false) {
                         print_error(e);
                         exit(1);
                     }
                 }
             },
-            Param::ResetUseDependabot {} => {
-                if let Err(e) = Config::default().reset_use_dependabot() {
+            Param::ResetIncludeBandit => {
+                if let Err(e) = Config::default().reset_include_bandit() {
                     print_error(e);
                     exit(1);
                 }
             }
-            Param::DependabotSchedule { value } => {
-                if let Err(e) = Config::default().save_dependabot_schedule(value) {
+            Param::IncludeCondaEnv { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_include_conda_env(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_include_conda_env(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetIncludeCondaEnv => {
+                if let Err(e) = Config::default().reset_include_conda_env() {
                     print_error(e);
                     exit(1);
                 }
             }
-            Param::ResetDependabotSchedule {} => {
-                if let Err(e) = Config::default().reset_dependabot_schedule() {
+            Param::IncludeCodeowners { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_include_codeowners(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_include_codeowners(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetIncludeCodeowners => {
+                if let Err(e) = Config::default().reset_include_codeowners() {
                     print_error(e);
                     exit(1);
                 }
             }
-            Param::DependabotDay { value } => {
-                if let Err(e) = Config::default().save_dependabot_day(value) {
+            Param::GithubUsername { value } => {
+                if let Err(e) = Config::default().save_github_username(value) {
                     print_error(e);
                     exit(1);
                 }
             }
-            Param::ResetDependabotDay {} => {
-                if let Err(e) = Config::default().reset_dependabot_day() {
+            Param::ResetGithubUsername => {
+                if let Err(e) = Config::default().reset_github_username() {
                     print_error(e);
                     exit(1);
                 }
             }
-            Param::UseContinuousDeployment { value } => match value {
+            Param::IncludeGithubTemplates { value } => match value {
                 BooleanChoice::True => {
-                    if let Err(e) = Config::default().save_use_continuous_deployment(true) {
+                    if let Err(e) = Config::default().save_include_github_templates(true) {
                         print_error(e);
                         exit(1);
                     }
                 }
                 BooleanChoice::False => {
-                    if let Err(e) = Config::default().save_use_continuous_deployment(false) {
+                    if let Err(e) = Config::default().save_include_github_templates(false) {
                         print_error(e);
                         exit(1);
                     }
                 }
             },
-            Param::ResetUseContinuousDeployment {} => {
-                if let Err(e) = Config::default().reset_use_continuous_deployment() {
+            Param::ResetIncludeGithubTemplates => {
+                if let Err(e) = Config::default().reset_include_github_templates() {
                     print_error(e);
                     exit(1);
                 }
             }
-            Param::UseReleaseDrafter { value } => match value {
+            Param::IncludeContributing { value } => match value {
                 BooleanChoice::True => {
-                    if let Err(e) = Config::default().save_use_release_drafter(true) {
+                    if let Err(e) = Config::default().save_include_contributing(true) {
                         print_error(e);
                         exit(1);
                     }
                 }
                 BooleanChoice::False => {
-                    if let Err(e) = Config::default().save_use_release_drafter(false) {
+                    if let Err(e) = Config::default().save_include_contributing(false) {
                         print_error(e);
                         exit(1);
                     }
                 }
             },
-            Param::ResetUseReleaseDrafter {} => {
-                if let Err(e) = Config::default().reset_use_release_drafter() {
+            Param::ResetIncludeContributing => {
+                if let Err(e) = Config::default().reset_include_contributing() {
                     print_error(e);
                     exit(1);
                 }
             }
-            Param::UseMultiOsCi { value } => match value {
+            Param::IncludeCodeql { value } => match value {
                 BooleanChoice::True => {
-                    if let Err(e) = Config::default().save_use_multi_os_ci(true) {
+                    if let Err(e) = Config::default().save_include_codeql(true) {
                         print_error(e);
                         exit(1);
                     }
                 }
                 BooleanChoice::False => {
-                    if let Err(e) = Config::default().save_use_multi_os_ci(false) {
+                    if let Err(e) = Config::default().save_include_codeql(false) {
                         print_error(e);
                         exit(1);
                     }
                 }
             },
-            Param::ResetUseMultiOsCi {} => {
-                if let Err(e) = Config::default().reset_use_multi_os_ci() {
+            Param::ResetIncludeCodeql => {
+                if let Err(e) = Config::default().reset_include_codeql() {
                     print_error(e);
                     exit(1);
                 }
             }
-            Param::IncludeDocs { value } => match value {
+            Param::IncludeSecurityPolicy { value } => match value {
                 BooleanChoice::True => {
-                    if let Err(e) = Config::default().save_include_docs(true) {
+                    if let Err(e) = Config::default().save_include_security_policy(true) {
                         print_error(e);
                         exit(1);
                     }
                 }
                 BooleanChoice::False => {
-                    if let Err(e) = Config::default().save_include_docs(false) {
+                    if let Err(e) = Config::default().save_include_security_policy(false) {
                         print_error(e);
                         exit(1);
                     }
                 }
             },
-            Param::ResetIncludeDocs {} => {
-                if let Err(e) = Config::default().reset_include_docs() {
+            Param::ResetIncludeSecurityPolicy => {
+                if let Err(e) = Config::default().reset_include_security_policy() {
                     print_error(e);
                     exit(1);
                 }
             }
-            Param::DownloadLatestPackages { value } => match value {
+            Param::IncludeChangelog { value } => match value {
                 BooleanChoice::True => {
-                    if let Err(e) = Config::default().save_download_latest_packages(true) {
+                    if let Err(e) = Config::default().save_include_changelog(true) {
                         print_error(e);
                         exit(1);
                     }
                 }
                 BooleanChoice::False => {
-                    if let Err(e) = Config::default().save_download_latest_packages(false) {
+                    if let Err(e) = Config::default().save_include_changelog(false) {
                         print_error(e);
                         exit(1);
                     }
                 }
             },
-            Param::ResetDownloadLatestPackages {} => {
-                if let Err(e) = Config::default().reset_download_latest_packages() {
+            Param::ResetIncludeChangelog => {
+                if let Err(e) = Config::default().reset_include_changelog() {
                     print_error(e);
                     exit(1);
                 }
             }
-            Param::Reset => {
-                if Config::reset().is_err() {
-                    let message = "Error resetting config.";
-                    eprintln!("{}", message.red());
-                    exit(1);
+            Param::IncludeCitation { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_include_citation(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_include_citation(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetIncludeCitation => {
+                if let Err(e) = Config::default().reset_include_citation() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::IncludeBenchmarks { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_include_benchmarks(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_include_benchmarks(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetIncludeBenchmarks => {
+                if let Err(e) = Config::default().reset_include_benchmarks() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::UsePrecommitCi { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_use_precommit_ci(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_use_precommit_ci(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetUsePrecommitCi => {
+                if let Err(e) = Config::default().reset_use_precommit_ci() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ExtraPreCommitHooks { value } => {
+                if let Err(e) = Config::default().save_extra_pre_commit_hooks(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetExtraPreCommitHooks => {
+                if let Err(e) = Config::default().reset_extra_pre_commit_hooks() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::MypyFiles { value } => {
+                if let Err(e) = Config::default().save_mypy_files(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetMypyFiles => {
+                if let Err(e) = Config::default().reset_mypy_files() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::RuffTargetVersion { value } => {
+                if let Err(e) = Config::default().save_ruff_target_version(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetRuffTargetVersion => {
+                if let Err(e) = Config::default().reset_ruff_target_version() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::SetPackageVersion { name, version } => {
+                if let Err(e) = Config::default().save_package_version(name, version) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetPackageVersion { name } => {
+                if let Err(e) = Config::default().reset_package_version(name) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetPackageVersions => {
+                if let Err(e) = Config::default().reset_package_versions() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::DependencyBoundStyle { value } => {
+                if let Err(e) = Config::default().save_dependency_bound_style(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetDependencyBoundStyle => {
+                if let Err(e) = Config::default().reset_dependency_bound_style() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::TypeChecker { value } => {
+                if let Err(e) = Config::default().save_type_checker(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetTypeChecker => {
+                if let Err(e) = Config::default().reset_type_checker() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::UseDependabot { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_use_dependabot(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_use_dependabot(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetUseDependabot => {
+                if let Err(e) = Config::default().reset_use_dependabot() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::DependabotSchedule { value } => {
+                if let Err(e) = Config::default().save_dependabot_schedule(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetDependabotSchedule => {
+                if let Err(e) = Config::default().reset_dependabot_schedule() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::DependabotDay { value } => {
+                if let Err(e) = Config::default().save_dependabot_day(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetDependabotDay => {
+                if let Err(e) = Config::default().reset_dependabot_day() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::UseContinuousDeployment { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_use_continuous_deployment(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_use_continuous_deployment(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetUseContinuousDeployment => {
+                if let Err(e) = Config::default().reset_use_continuous_deployment() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::UseReleaseDrafter { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_use_release_drafter(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_use_release_drafter(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetUseReleaseDrafter => {
+                if let Err(e) = Config::default().reset_use_release_drafter() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::CloudProvider { value } => {
+                if let Err(e) = Config::default().save_cloud_provider(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetCloudProvider => {
+                if let Err(e) = Config::default().reset_cloud_provider() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::CloudDeployRole { value } => {
+                if let Err(e) = Config::default().save_cloud_deploy_role(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetCloudDeployRole => {
+                if let Err(e) = Config::default().reset_cloud_deploy_role() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::PublishDocker { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_publish_docker(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_publish_docker(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetPublishDocker => {
+                if let Err(e) = Config::default().reset_publish_docker() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::SupplyChainAttestation { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_supply_chain_attestation(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_supply_chain_attestation(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetSupplyChainAttestation => {
+                if let Err(e) = Config::default().reset_supply_chain_attestation() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::PypiIndexUrl { value } => {
+                if let Err(e) = Config::default().save_pypi_index_url(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetPypiIndexUrl => {
+                if let Err(e) = Config::default().reset_pypi_index_url() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::PublishAuth { value } => {
+                if let Err(e) = Config::default().save_publish_auth(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetPublishAuth => {
+                if let Err(e) = Config::default().reset_publish_auth() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::IncludeTestpypiPublish { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_include_testpypi_publish(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_include_testpypi_publish(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetIncludeTestpypiPublish => {
+                if let Err(e) = Config::default().reset_include_testpypi_publish() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::IncludeRustToolchain { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_include_rust_toolchain(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_include_rust_toolchain(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetIncludeRustToolchain => {
+                if let Err(e) = Config::default().reset_include_rust_toolchain() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::RustEdition { value } => {
+                if let Err(e) = Config::default().save_rust_edition(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetRustEdition => {
+                if let Err(e) = Config::default().reset_rust_edition() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::Pyo3Version { value } => {
+                if let Err(e) = Config::default().save_pyo3_version(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetPyo3Version => {
+                if let Err(e) = Config::default().reset_pyo3_version() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::UseAbi3 { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_use_abi3(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_use_abi3(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetUseAbi3 => {
+                if let Err(e) = Config::default().reset_use_abi3() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::BuildMusllinux { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_build_musllinux(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_build_musllinux(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetBuildMusllinux => {
+                if let Err(e) = Config::default().reset_build_musllinux() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::MaturinTargets { value } => {
+                if let Err(e) = Config::default().save_maturin_targets(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetMaturinTargets => {
+                if let Err(e) = Config::default().reset_maturin_targets() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::CiJobTimeout { value } => {
+                if let Err(e) = Config::default().save_ci_job_timeout(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetCiJobTimeout => {
+                if let Err(e) = Config::default().reset_ci_job_timeout() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::CiCoverageComment { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_ci_coverage_comment(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_ci_coverage_comment(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetCiCoverageComment => {
+                if let Err(e) = Config::default().reset_ci_coverage_comment() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::UvFrozen { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_uv_frozen(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_uv_frozen(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetUvFrozen => {
+                if let Err(e) = Config::default().reset_uv_frozen() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::UseMultiOsCi { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_use_multi_os_ci(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_use_multi_os_ci(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetUseMultiOsCi => {
+                if let Err(e) = Config::default().reset_use_multi_os_ci() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::IncludeDocs { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_include_docs(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_include_docs(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetIncludeDocs => {
+                if let Err(e) = Config::default().reset_include_docs() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::DownloadLatestPackages { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_download_latest_packages(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_download_latest_packages(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetDownloadLatestPackages => {
+                if let Err(e) = Config::default().reset_download_latest_packages() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::UseSpinner { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_use_spinner(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_use_spinner(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetUseSpinner => {
+                if let Err(e) = Config::default().reset_use_spinner() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::InitGit { value } => match value {
+                BooleanChoice::True => {
+                    if let Err(e) = Config::default().save_init_git(true) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                BooleanChoice::False => {
+                    if let Err(e) = Config::default().save_init_git(false) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
+            Param::ResetInitGit => {
+                if let Err(e) = Config::default().reset_init_git() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::VersionCacheTtl { value } => {
+                if let Err(e) = Config::default().save_version_cache_ttl(value) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::ResetVersionCacheTtl => {
+                if let Err(e) = Config::default().reset_version_cache_ttl() {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::CacheClear => {
+                if let Err(e) = VersionCache::from_config().and_then(|cache| cache.clear()) {
+                    print_error(e);
+                    exit(1);
+                }
+            }
+            Param::Reset => {
+                if Config::reset().is_err() {
+                    let message = "Error resetting config.";
+                    eprintln!("{}", message.red());
+                    exit(1);
                 }
             }
             Param::Show => Config::default().show(),
+            Param::Get { key } => match Config::default().get(&key) {
+                Ok(value) => println!("{value}"),
+                Err(e) => {
+                    print_error(e);
+                    exit(1);
+                }
+            },
+            Param::Profile(profile) => match profile.command {
+                ProfileCommand::Save { name } => {
+                    if let Err(e) = Config::default().save_profile(&name) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+                ProfileCommand::List => match Config::default().list_profiles() {
+                    Ok(profiles) => {
+                        for profile in profiles {
+                            println!("{profile}");
+                        }
+                    }
+                    Err(e) => {
+                        print_error(e);
+                        exit(1);
+                    }
+                },
+                ProfileCommand::Delete { name } => {
+                    if let Err(e) = Config::default().delete_profile(&name) {
+                        print_error(e);
+                        exit(1);
+                    }
+                }
+            },
         },
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::project_info::{LicenseType, ProjectManager};
+    use super::project_info::{LicenseType, ProjectManager, PublishAuth, TypeChecker};
     use super::*;
+    use std::collections::HashMap;
     use std::fs::create_dir_all;
+    use std::path::PathBuf;
     use tmp_path::tmp_path;
 
-    #[test]
-    #[tmp_path]
-    fn test_delete_slug() {
-        let project_slug = "test-project";
-        let slug_dir = tmp_path.join(project_slug);
-        let project_info = ProjectInfo {
+    fn project_info_dummy(tmp_path: PathBuf) -> ProjectInfo {
+        ProjectInfo {
             project_name: "My project".to_string(),
-            project_slug: project_slug.to_string(),
+            project_slug: "test-project".to_string(),
             source_dir: "my_project".to_string(),
             project_description: "This is a test".to_string(),
             creator: "Arthur Dent".to_string(),
@@ -436,6 +1611,7 @@ mod tests {
                 "3.12".to_string(),
             ],
             max_line_length: 100,
+            ruff_line_length: None,
             use_dependabot: true,
             dependabot_schedule: None,
             dependabot_day: None,
@@ -445,11 +1621,308 @@ mod tests {
             include_docs: false,
             docs_info: None,
             download_latest_packages: false,
+            min_coverage: None,
+            extra_ruff_rules: Vec::new(),
+            include_bandit: false,
+            extra_pre_commit_hooks: Vec::new(),
+            use_precommit_ci: false,
+            include_settings: false,
+            include_logging: false,
+            mypy_files: None,
+            ruff_target_version: None,
+            offline: false,
+            cloud_provider: None,
+            cloud_deploy_role: None,
+            publish_docker: false,
+            supply_chain_attestation: false,
+            pypi_index_url: None,
+            publish_auth: PublishAuth::TrustedPublishing,
+            include_testpypi_publish: false,
+            include_rust_toolchain: false,
+            rust_edition: "2021".to_string(),
+            pyo3_version: None,
+            use_abi3: false,
+            build_musllinux: false,
+            maturin_targets: None,
+            package_version_overrides: HashMap::new(),
+            ci_job_timeout: 30,
+            dependency_bound_style: None,
+            type_checker: TypeChecker::Mypy,
+            include_conda_env: false,
+            copyright_holder: None,
+            additional_authors: Vec::new(),
+            include_codeowners: false,
+            github_username: None,
+            include_github_templates: false,
+            include_contributing: false,
+            include_codeql: false,
+            include_security_policy: false,
+            include_changelog: false,
+            include_citation: false,
+            include_benchmarks: false,
+            ci_coverage_comment: false,
+            uv_frozen: true,
             project_root_dir: Some(tmp_path),
-        };
+        }
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_delete_slug() {
+        let project_slug = "test-project";
+        let slug_dir = tmp_path.join(project_slug);
+        let project_info = project_info_dummy(tmp_path);
         create_dir_all(&slug_dir).unwrap();
         assert!(slug_dir.exists());
         delete_slug(&project_info).unwrap();
         assert!(!slug_dir.exists());
     }
+
+    #[test]
+    fn test_spinner_enabled_by_default() {
+        assert!(spinner_enabled(false, None, None));
+    }
+
+    #[test]
+    fn test_spinner_disabled() {
+        assert!(!spinner_enabled(true, None, None));
+        assert!(!spinner_enabled(false, Some(OsStr::new("1")), None));
+        assert!(!spinner_enabled(false, None, Some(false)));
+        assert!(spinner_enabled(false, Some(OsStr::new("")), Some(true)));
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_generate_project_spinner_disabled() {
+        let mut project_info = project_info_dummy(tmp_path.clone());
+        project_info.offline = true;
+        let result = with_progress("Generating Project...", false, || {
+            generate_project(&project_info)
+        });
+
+        assert!(result.is_ok());
+        assert!(project_info.base_dir().join("pyproject.toml").is_file());
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_create_no_git() {
+        let mut project_info = project_info_dummy(tmp_path.clone());
+        project_info.offline = true;
+        create(&project_info, false, false, false, false).unwrap();
+
+        assert!(project_info.base_dir().join("pyproject.toml").is_file());
+        assert!(!project_info.base_dir().join(".git").exists());
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_init_git_repository_git_not_installed() {
+        let mut project_info = project_info_dummy(tmp_path.clone());
+        project_info.offline = true;
+        generate_project(&project_info).unwrap();
+        let result = init_git_repository("ppg-missing-git", &project_info.base_dir());
+
+        assert!(result.is_err());
+        assert!(project_info.base_dir().join("pyproject.toml").is_file());
+        assert!(!project_info.base_dir().join(".git").exists());
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_timings_message() {
+        let mut project_info = project_info_dummy(tmp_path.clone());
+        project_info.offline = true;
+        let generation_timings = generate_project(&project_info).unwrap();
+        let message = timings_message(true, &generation_timings).unwrap();
+
+        assert!(message.starts_with("Generated in "));
+        assert!(message.contains("\n  python files: "));
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_timings_message_not_set() {
+        let mut project_info = project_info_dummy(tmp_path.clone());
+        project_info.offline = true;
+        let generation_timings = generate_project(&project_info).unwrap();
+
+        assert!(timings_message(false, &generation_timings).is_none());
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_missing_rust_warning_maturin() {
+        let mut project_info = project_info_dummy(tmp_path.clone());
+        project_info.project_manager = ProjectManager::Maturin;
+
+        assert!(missing_rust_warning(&project_info, Some(tmp_path.as_os_str())).is_some());
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_missing_rust_warning_maturin_rust_installed() {
+        let mut project_info = project_info_dummy(tmp_path.clone());
+        project_info.project_manager = ProjectManager::Maturin;
+        std::fs::write(tmp_path.join("cargo"), "").unwrap();
+        std::fs::write(tmp_path.join("rustc"), "").unwrap();
+
+        assert!(missing_rust_warning(&project_info, Some(tmp_path.as_os_str())).is_none());
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_missing_rust_warning_python_managers() {
+        for project_manager in [
+            ProjectManager::Poetry,
+            ProjectManager::Setuptools,
+            ProjectManager::Uv,
+            ProjectManager::Pixi,
+        ] {
+            let mut project_info = project_info_dummy(tmp_path.clone());
+            project_info.project_manager = project_manager;
+
+            assert!(missing_rust_warning(&project_info, Some(tmp_path.as_os_str())).is_none());
+        }
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_lock_project_uv() {
+        if std::process::Command::new("uv")
+            .arg("--version")
+            .output()
+            .is_err()
+        {
+            return;
+        }
+
+        let mut project_info = project_info_dummy(tmp_path);
+        project_info.project_manager = ProjectManager::Uv;
+        generate_project(&project_info).unwrap();
+        lock_project(&project_info).unwrap();
+
+        assert!(project_info.base_dir().join("uv.lock").is_file());
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_run_optional_tool_missing_program() {
+        assert!(run_optional_tool(
+            "python-project-missing-tool",
+            &["--version"],
+            &tmp_path,
+            "nothing was run"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_render_manpage() {
+        let manpage = String::from_utf8(render_manpage().unwrap()).unwrap();
+
+        assert!(manpage.contains(".TH python-project 1"));
+        assert!(manpage.contains("python\\-project \\- Generates a Python project"));
+    }
+
+    #[test]
+    fn test_features_json() {
+        let features = features_json();
+
+        assert_eq!(
+            features["project_managers"],
+            serde_json::json!(["maturin", "poetry", "setuptools", "uv", "pixi"])
+        );
+        assert!(features["licenses"]
+            .as_array()
+            .unwrap()
+            .contains(&"mit".into()));
+        assert_eq!(
+            features["ci_providers"],
+            serde_json::json!(["github-actions"])
+        );
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_install_command() {
+        let mut project_info = project_info_dummy(tmp_path);
+
+        for (project_manager, pyo3_python_manager, expected) in [
+            (ProjectManager::Poetry, None, Some("poetry install")),
+            (ProjectManager::Uv, None, Some("uv sync --all-extras")),
+            (ProjectManager::Pixi, None, Some("pixi install")),
+            (ProjectManager::Setuptools, None, None),
+            (
+                ProjectManager::Maturin,
+                Some(Pyo3PythonManager::Uv),
+                Some("uv sync --all-extras"),
+            ),
+            (
+                ProjectManager::Maturin,
+                Some(Pyo3PythonManager::Setuptools),
+                None,
+            ),
+        ] {
+            project_info.project_manager = project_manager;
+            project_info.pyo3_python_manager = pyo3_python_manager;
+            let command = install_command(&project_info)
+                .map(|(program, args)| format!("{program} {}", args.join(" ")));
+
+            assert_eq!(command.as_deref(), expected);
+        }
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_format_command() {
+        let mut project_info = project_info_dummy(tmp_path);
+
+        for (project_manager, pyo3_python_manager, expected) in [
+            (ProjectManager::Poetry, None, "poetry run ruff format ."),
+            (ProjectManager::Uv, None, "uv run ruff format ."),
+            (ProjectManager::Pixi, None, "pixi run -e dev ruff format ."),
+            (ProjectManager::Setuptools, None, "ruff format ."),
+            (
+                ProjectManager::Maturin,
+                Some(Pyo3PythonManager::Uv),
+                "uv run ruff format .",
+            ),
+            (
+                ProjectManager::Maturin,
+                Some(Pyo3PythonManager::Setuptools),
+                "ruff format .",
+            ),
+        ] {
+            project_info.project_manager = project_manager;
+            project_info.pyo3_python_manager = pyo3_python_manager;
+            let (program, args) = format_command(&project_info);
+
+            assert_eq!(format!("{program} {}", args.join(" ")), expected);
+        }
+    }
+
+    #[test]
+    #[tmp_path]
+    fn test_format_project_setuptools() {
+        if std::process::Command::new("ruff")
+            .arg("--version")
+            .output()
+            .is_err()
+        {
+            return;
+        }
+
+        let mut project_info = project_info_dummy(tmp_path);
+        project_info.project_manager = ProjectManager::Setuptools;
+        generate_project(&project_info).unwrap();
+        let unformatted = project_info.base_dir().join("my_project/unformatted.py");
+        std::fs::write(&unformatted, "x = {  'a':1 }\n").unwrap();
+        format_project(&project_info).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(unformatted).unwrap(),
+            "x = {\"a\": 1}\n"
+        );
+    }
 }
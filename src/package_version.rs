@@ -1,43 +1,213 @@
-use std::{fmt, thread, time::Duration};
+use std::{
+    collections::HashMap,
+    fmt,
+    fs::{create_dir_all, read_to_string, remove_file, write},
+    path::PathBuf,
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(feature = "network")]
+use std::thread;
+
+#[cfg(test)]
+use std::sync::atomic::AtomicUsize;
+#[cfg(all(test, feature = "network"))]
+use std::sync::atomic::Ordering;
 
 use anyhow::{bail, Result};
+#[cfg(feature = "network")]
 use exponential_backoff::Backoff;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+
+/// The number of hours a fetched version is cached for when no TTL is saved in the config.
+const DEFAULT_VERSION_CACHE_TTL_HOURS: u64 = 24;
+
+/// Serializes writes to the cache file since versions are fetched in parallel.
+static VERSION_CACHE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Deserialize, Serialize)]
+struct CacheEntry {
+    version: String,
+    timestamp: u64,
+}
+
+/// Latest versions are cached on disk so generating several projects in a row doesn't fetch the
+/// same versions again.
+#[derive(Debug)]
+pub struct VersionCache {
+    path: PathBuf,
+    ttl: Duration,
+}
+
+impl VersionCache {
+    pub fn new(path: PathBuf, ttl: Duration) -> Self {
+        VersionCache { path, ttl }
+    }
+
+    pub fn from_config() -> Result<Self> {
+        let config = Config::default().load_config();
+        let Some(path) = config.version_cache_path() else {
+            bail!("No config directory could be located for the version cache");
+        };
+        let ttl_hours = config
+            .version_cache_ttl
+            .unwrap_or(DEFAULT_VERSION_CACHE_TTL_HOURS);
+
+        Ok(VersionCache::new(
+            path,
+            Duration::from_secs(ttl_hours.saturating_mul(3600)),
+        ))
+    }
+
+    /// Returns the cached version unless it is older than the TTL.
+    pub fn get(&self, key: &str) -> Option<String> {
+        let entries = self.read_entries();
+        let entry = entries.get(key)?;
+
+        if now().saturating_sub(entry.timestamp) < self.ttl.as_secs() {
+            Some(entry.version.clone())
+        } else {
+            None
+        }
+    }
+
+    pub fn set(&self, key: &str, version: &str) -> Result<()> {
+        self.insert(key, version, now())
+    }
+
+    pub fn clear(&self) -> Result<()> {
+        let _lock = VERSION_CACHE_LOCK.lock();
+
+        if self.path.exists() {
+            remove_file(&self.path)?;
+        }
+
+        Ok(())
+    }
+
+    fn insert(&self, key: &str, version: &str, timestamp: u64) -> Result<()> {
+        let _lock = VERSION_CACHE_LOCK.lock();
+        let mut entries = self.read_entries();
+        entries.insert(
+            key.to_string(),
+            CacheEntry {
+                version: version.to_string(),
+                timestamp,
+            },
+        );
+
+        if let Some(parent) = self.path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        write(&self.path, serde_json::to_string(&entries)?)?;
+
+        Ok(())
+    }
+
+    // A missing or corrupt cache is treated as empty so it never stops a version being fetched.
+    fn read_entries(&self) -> HashMap<String, CacheEntry> {
+        read_to_string(&self.path)
+            .ok()
+            .and_then(|c| serde_json::from_str(&c).ok())
+            .unwrap_or_default()
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Uses the cached version for `key` if there is one, otherwise fetches it and updates the cache.
+fn cached_or_fetch(
+    cache: Option<&VersionCache>,
+    key: &str,
+    fetch: impl FnOnce() -> Result<String>,
+) -> Result<String> {
+    if let Some(version) = cache.and_then(|c| c.get(key)) {
+        return Ok(version);
+    }
+
+    let version = fetch()?;
+
+    if let Some(cache) = cache {
+        let _ = cache.set(key, &version);
+    }
+
+    Ok(version)
+}
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum PythonPackage {
+    Bandit,
     Maturin,
     Mkdocs,
     MkdocsMaterial,
     Mkdocstrings,
     MyPy,
     PreCommit,
+    PydanticSettings,
     Pytest,
     PytestAsyncio,
+    PytestBenchmark,
     PytestCov,
     Ruff,
     Tomli,
+    Ty,
+}
+
+impl PythonPackage {
+    pub const ALL: [PythonPackage; 15] = [
+        PythonPackage::Bandit,
+        PythonPackage::Maturin,
+        PythonPackage::Mkdocs,
+        PythonPackage::MkdocsMaterial,
+        PythonPackage::Mkdocstrings,
+        PythonPackage::MyPy,
+        PythonPackage::PreCommit,
+        PythonPackage::PydanticSettings,
+        PythonPackage::Pytest,
+        PythonPackage::PytestAsyncio,
+        PythonPackage::PytestBenchmark,
+        PythonPackage::PytestCov,
+        PythonPackage::Ruff,
+        PythonPackage::Tomli,
+        PythonPackage::Ty,
+    ];
 }
 
 impl fmt::Display for PythonPackage {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            PythonPackage::Bandit => write!(f, "bandit"),
             PythonPackage::Maturin => write!(f, "maturin"),
             PythonPackage::Mkdocs => write!(f, "mkdocs"),
             PythonPackage::MkdocsMaterial => write!(f, "mkdocs-material"),
             PythonPackage::Mkdocstrings => write!(f, "mkdocstrings"),
             PythonPackage::MyPy => write!(f, "mypy"),
             PythonPackage::PreCommit => write!(f, "pre-commit"),
+            PythonPackage::PydanticSettings => write!(f, "pydantic-settings"),
             PythonPackage::Pytest => write!(f, "pytest"),
             PythonPackage::PytestAsyncio => write!(f, "pytest-asyncio"),
+            PythonPackage::PytestBenchmark => write!(f, "pytest-benchmark"),
             PythonPackage::PytestCov => write!(f, "pytest-cov"),
             PythonPackage::Ruff => write!(f, "ruff"),
             PythonPackage::Tomli => write!(f, "tomli"),
+            PythonPackage::Ty => write!(f, "ty"),
         }
     }
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum PreCommitHook {
+    Bandit,
+    Codespell,
     PreCommit,
     MyPy,
     Ruff,
@@ -46,6 +216,8 @@ pub enum PreCommitHook {
 impl fmt::Display for PreCommitHook {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            PreCommitHook::Bandit => write!(f, "bandit"),
+            PreCommitHook::Codespell => write!(f, "codespell"),
             PreCommitHook::MyPy => write!(f, "mypy"),
             PreCommitHook::PreCommit => write!(f, "pre-commit"),
             PreCommitHook::Ruff => write!(f, "ruff"),
@@ -53,8 +225,76 @@ impl fmt::Display for PreCommitHook {
     }
 }
 
+/// The number of times to try retrieving a latest version before falling back to the default.
+pub const RETRY_ATTEMPTS: u32 = 3;
+
+/// The version cache is loaded once by the caller and shared, rather than each version reading the
+/// config again while the versions are fetched in parallel.
 pub trait LatestVersion {
-    fn get_latest_version(&mut self) -> Result<()>;
+    fn get_latest_version(&mut self, cache: Option<&VersionCache>) -> Result<()>;
+
+    /// Retries `get_latest_version` with exponential backoff so a flaky network doesn't
+    /// immediately fall back to the default version.
+    #[cfg(feature = "network")]
+    fn get_latest_version_with_retry(
+        &mut self,
+        attempts: u32,
+        cache: Option<&VersionCache>,
+    ) -> Result<()> {
+        let min = Duration::from_millis(100);
+        let max = Duration::from_secs(1);
+        let backoff = Backoff::new(attempts, min, max);
+
+        for duration in backoff {
+            match self.get_latest_version(cache) {
+                Ok(_) => return Ok(()),
+                Err(e) => match duration {
+                    Some(duration) => thread::sleep(duration),
+                    None => bail!("{e}"),
+                },
+            }
+        }
+        bail!("Error retrieving latest version");
+    }
+
+    #[cfg(not(feature = "network"))]
+    fn get_latest_version_with_retry(
+        &mut self,
+        _attempts: u32,
+        cache: Option<&VersionCache>,
+    ) -> Result<()> {
+        self.get_latest_version(cache)
+    }
+}
+
+/// Counts the HTTP clients created so tests can verify when no network requests are made.
+#[cfg(test)]
+pub static HTTP_CLIENTS_CREATED: AtomicUsize = AtomicUsize::new(0);
+
+/// Held by tests that make HTTP requests or check `HTTP_CLIENTS_CREATED`.
+#[cfg(test)]
+pub static NETWORK_LOCK: Mutex<()> = Mutex::new(());
+
+#[cfg(feature = "network")]
+fn get_response_text(url: &str) -> Result<String> {
+    #[cfg(test)]
+    HTTP_CLIENTS_CREATED.fetch_add(1, Ordering::SeqCst);
+
+    let client = reqwest::blocking::Client::new();
+    let response = client
+        .get(url)
+        .header(reqwest::header::USER_AGENT, "python-project-generator")
+        .timeout(Duration::new(5, 0))
+        .send()?
+        .error_for_status()?
+        .text()?;
+
+    Ok(response)
+}
+
+#[cfg(not(feature = "network"))]
+fn get_response_text(_url: &str) -> Result<String> {
+    bail!("Latest versions can't be downloaded because the network feature isn't enabled");
 }
 
 #[derive(Debug)]
@@ -65,45 +305,26 @@ pub struct PreCommitHookVersion {
 }
 
 impl LatestVersion for PreCommitHookVersion {
-    fn get_latest_version(&mut self) -> Result<()> {
-        let client = reqwest::blocking::Client::new();
-        let attempts = 3;
-        let min = Duration::from_millis(100); // 10ms
-        let max = Duration::from_secs(1);
-        let backoff = Backoff::new(attempts, min, max);
+    fn get_latest_version(&mut self, cache: Option<&VersionCache>) -> Result<()> {
         let api_url = format!(
             "{}/releases",
             self.repo
                 .replace("https://github.com", "https://api.github.com/repos")
         );
-
-        for duration in backoff {
-            let response = client
-                .get(&api_url)
-                .header(reqwest::header::USER_AGENT, "python-project-generator")
-                .timeout(Duration::new(5, 0))
-                .send();
-
-            match response {
-                Ok(r) => {
-                    let result = r.text()?;
-                    let info: Vec<serde_json::Value> = serde_json::from_str(&result)?;
-                    for i in info {
-                        if i["draft"] == false && i["prerelease"] == false {
-                            self.rev = i["tag_name"].to_string().replace('"', "");
-                            break;
-                        }
-                    }
-
-                    return Ok(());
+        let default_rev = self.rev.clone();
+        self.rev = cached_or_fetch(cache, &format!("pre-commit/{}", self.repo), || {
+            let result = get_response_text(&api_url)?;
+            let info: Vec<serde_json::Value> = serde_json::from_str(&result)?;
+            for i in info {
+                if i["draft"] == false && i["prerelease"] == false {
+                    return Ok(i["tag_name"].to_string().replace('"', ""));
                 }
-                Err(e) => match duration {
-                    Some(duration) => thread::sleep(duration),
-                    None => bail!("{e}"),
-                },
             }
-        }
-        bail!("Error retrieving latest version");
+
+            Ok(default_rev)
+        })?;
+
+        Ok(())
     }
 }
 
@@ -122,8 +343,11 @@ pub struct PythonPackageVersion {
 }
 
 impl LatestVersion for PythonPackageVersion {
-    fn get_latest_version(&mut self) -> Result<()> {
-        self.version = get_latest_python_version(&self.package.to_string())?;
+    fn get_latest_version(&mut self, cache: Option<&VersionCache>) -> Result<()> {
+        let name = self.package.to_string();
+        self.version = cached_or_fetch(cache, &format!("pypi/{name}"), || {
+            get_latest_python_version(&name)
+        })?;
 
         Ok(())
     }
@@ -145,20 +369,16 @@ pub struct RustPackageVersion {
 }
 
 impl LatestVersion for RustPackageVersion {
-    fn get_latest_version(&mut self) -> Result<()> {
+    fn get_latest_version(&mut self, cache: Option<&VersionCache>) -> Result<()> {
         let url = format!("https://crates.io/api/v1/crates/{}", self.name);
-        let client = reqwest::blocking::Client::new();
-        let response = client
-            .get(url)
-            .header(reqwest::header::USER_AGENT, "python-project-generator")
-            .timeout(Duration::new(5, 0))
-            .send()?
-            .text()?;
-        let info: serde_json::Value = serde_json::from_str(&response)?;
-        self.name = info["crate"]["id"].to_string().replace('"', "");
-        self.version = info["crate"]["max_stable_version"]
-            .to_string()
-            .replace('"', "");
+        self.version = cached_or_fetch(cache, &format!("crates/{}", self.name), || {
+            let response = get_response_text(&url)?;
+            let info: serde_json::Value = serde_json::from_str(&response)?;
+
+            Ok(info["crate"]["max_stable_version"]
+                .to_string()
+                .replace('"', ""))
+        })?;
 
         Ok(())
     }
@@ -166,22 +386,58 @@ impl LatestVersion for RustPackageVersion {
 
 pub fn default_version(package: &PythonPackage) -> String {
     match package {
+        PythonPackage::Bandit => "1.8.2".to_string(),
         PythonPackage::Maturin => "1.8.1".to_string(),
         PythonPackage::Mkdocs => "1.6.1".to_string(),
         PythonPackage::MkdocsMaterial => "9.6.2".to_string(),
         PythonPackage::Mkdocstrings => "0.28.0".to_string(),
         PythonPackage::MyPy => "1.15.0".to_string(),
         PythonPackage::PreCommit => "4.1.0".to_string(),
+        PythonPackage::PydanticSettings => "2.7.1".to_string(),
         PythonPackage::Pytest => "8.3.4".to_string(),
         PythonPackage::PytestAsyncio => "0.25.3".to_string(),
+        PythonPackage::PytestBenchmark => "5.1.0".to_string(),
         PythonPackage::PytestCov => "6.0.0".to_string(),
         PythonPackage::Ruff => "0.9.4".to_string(),
         PythonPackage::Tomli => "2.0.1".to_string(),
+        PythonPackage::Ty => "0.0.1a1".to_string(),
     }
 }
 
+/// The versions used when the latest versions aren't downloaded as `(package, version, pinned)`.
+/// Pinned versions come from the config and replace the built in default.
+pub fn offline_versions(overrides: &HashMap<String, String>) -> Vec<(String, String, bool)> {
+    PythonPackage::ALL
+        .iter()
+        .map(|package| {
+            let name = package.to_string();
+            match overrides.get(&name) {
+                Some(version) => (name, version.clone(), true),
+                None => (name, default_version(package), false),
+            }
+        })
+        .collect()
+}
+
+pub fn validate_package_name(name: &str) -> Result<()> {
+    let name = name.trim().to_lowercase();
+
+    if !PythonPackage::ALL.iter().any(|p| p.to_string() == name) {
+        let valid = PythonPackage::ALL
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        bail!("{name} is not a package with a default version, valid packages are: {valid}");
+    }
+
+    Ok(())
+}
+
 pub fn default_pre_commit_rev(hook: &PreCommitHook) -> String {
     match hook {
+        PreCommitHook::Bandit => "1.8.2".to_string(),
+        PreCommitHook::Codespell => "v2.4.1".to_string(),
         PreCommitHook::MyPy => "v1.15.0".to_string(),
         PreCommitHook::PreCommit => "v5.0.0".to_string(),
         PreCommitHook::Ruff => "v0.9.4".to_string(),
@@ -190,6 +446,8 @@ pub fn default_pre_commit_rev(hook: &PreCommitHook) -> String {
 
 pub fn pre_commit_repo(hook: &PreCommitHook) -> String {
     match hook {
+        PreCommitHook::Bandit => "https://github.com/PyCQA/bandit".to_string(),
+        PreCommitHook::Codespell => "https://github.com/codespell-project/codespell".to_string(),
         PreCommitHook::MyPy => "https://github.com/pre-commit/mirrors-mypy".to_string(),
         PreCommitHook::PreCommit => "https://github.com/pre-commit/pre-commit-hooks".to_string(),
         PreCommitHook::Ruff => "https://github.com/astral-sh/ruff-pre-commit".to_string(),
@@ -198,26 +456,165 @@ pub fn pre_commit_repo(hook: &PreCommitHook) -> String {
 
 fn get_latest_python_version(name: &str) -> Result<String> {
     let url = format!("https://pypi.org/pypi/{}/json", name);
-    let client = reqwest::blocking::Client::new();
-    let attempts = 3;
-    let min = Duration::from_millis(100); // 10ms
-    let max = Duration::from_secs(1);
-    let backoff = Backoff::new(attempts, min, max);
-
-    for duration in backoff {
-        let response = client.get(&url).timeout(Duration::new(5, 0)).send();
-
-        match response {
-            Ok(r) => {
-                let result = r.text()?;
-                let info: serde_json::Value = serde_json::from_str(&result)?;
-                return Ok(info["info"]["version"].to_string().replace('"', ""));
-            }
-            Err(e) => match duration {
-                Some(duration) => thread::sleep(duration),
-                None => bail!("{e}"),
-            },
+    let result = get_response_text(&url)?;
+    let info: serde_json::Value = serde_json::from_str(&result)?;
+
+    Ok(info["info"]["version"].to_string().replace('"', ""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "network")]
+    use std::io::{Read, Write};
+    #[cfg(feature = "network")]
+    use std::net::TcpListener;
+    use tmp_path::tmp_path;
+
+    #[cfg(feature = "network")]
+    struct MockVersion {
+        url: String,
+        version: String,
+    }
+
+    #[cfg(feature = "network")]
+    impl LatestVersion for MockVersion {
+        fn get_latest_version(&mut self, _cache: Option<&VersionCache>) -> Result<()> {
+            let result = get_response_text(&self.url)?;
+            let info: serde_json::Value = serde_json::from_str(&result)?;
+            self.version = info["info"]["version"].to_string().replace('"', "");
+
+            Ok(())
         }
     }
-    bail!("Error retrieving latest version");
+
+    /// Starts a server that answers each request with the next status code in `statuses`.
+    #[cfg(feature = "network")]
+    fn mock_server(statuses: Vec<u16>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            for status in statuses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut buffer = [0; 1024];
+                let _ = stream.read(&mut buffer).unwrap();
+                let body = r#"{"info": {"version": "2.0.0"}}"#;
+                let response = format!(
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        url
+    }
+
+    #[test]
+    fn test_offline_versions() {
+        let overrides = HashMap::from([("ruff".to_string(), "0.6.0".to_string())]);
+        let versions = offline_versions(&overrides);
+
+        assert_eq!(versions.len(), PythonPackage::ALL.len());
+        assert!(versions.contains(&("ruff".to_string(), "0.6.0".to_string(), true)));
+        assert!(versions.contains(&(
+            "pytest".to_string(),
+            default_version(&PythonPackage::Pytest),
+            false
+        )));
+    }
+
+    #[test]
+    fn test_validate_package_name() {
+        assert!(validate_package_name("Ruff").is_ok());
+        assert!(validate_package_name("mkdocs-material").is_ok());
+        assert!(validate_package_name("not-a-package").is_err());
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_version_cache_hit() {
+        let cache = VersionCache::new(
+            tmp_path.join("version_cache.json"),
+            Duration::from_secs(3600),
+        );
+        cache.set("pypi/ruff", "0.9.4").unwrap();
+
+        assert_eq!(cache.get("pypi/ruff"), Some("0.9.4".to_string()));
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_version_cache_miss() {
+        let cache = VersionCache::new(
+            tmp_path.join("version_cache.json"),
+            Duration::from_secs(3600),
+        );
+
+        assert_eq!(cache.get("pypi/ruff"), None);
+
+        cache.set("pypi/mypy", "1.15.0").unwrap();
+
+        assert_eq!(cache.get("pypi/ruff"), None);
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_version_cache_expired() {
+        let cache = VersionCache::new(
+            tmp_path.join("version_cache.json"),
+            Duration::from_secs(3600),
+        );
+        cache.insert("pypi/ruff", "0.9.4", now() - 7200).unwrap();
+
+        assert_eq!(cache.get("pypi/ruff"), None);
+
+        cache.set("pypi/ruff", "0.9.5").unwrap();
+
+        assert_eq!(cache.get("pypi/ruff"), Some("0.9.5".to_string()));
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_version_cache_clear() {
+        let path = tmp_path.join("version_cache.json");
+        let cache = VersionCache::new(path.clone(), Duration::from_secs(3600));
+        cache.set("pypi/ruff", "0.9.4").unwrap();
+        cache.clear().unwrap();
+
+        assert!(!path.exists());
+        assert_eq!(cache.get("pypi/ruff"), None);
+    }
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_get_latest_version_with_retry() {
+        let _lock = NETWORK_LOCK.lock().unwrap();
+        let url = mock_server(vec![500, 503, 200]);
+        let mut package = MockVersion {
+            url,
+            version: "1.0.0".to_string(),
+        };
+        package
+            .get_latest_version_with_retry(RETRY_ATTEMPTS, None)
+            .unwrap();
+
+        assert_eq!(package.version, "2.0.0");
+    }
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_get_latest_version_with_retry_gives_up() {
+        let _lock = NETWORK_LOCK.lock().unwrap();
+        let url = mock_server(vec![500, 500, 500]);
+        let mut package = MockVersion {
+            url,
+            version: "1.0.0".to_string(),
+        };
+        let result = package.get_latest_version_with_retry(RETRY_ATTEMPTS, None);
+
+        assert!(result.is_err());
+        assert_eq!(package.version, "1.0.0");
+    }
 }
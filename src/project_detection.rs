@@ -0,0 +1,1047 @@
+use std::fs::read_to_string;
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use toml::Value;
+
+use crate::config::Config;
+use crate::project_generator::lock_file_name;
+use crate::project_info::{
+    CloudProvider, LicenseType, ProjectInfo, ProjectManager, PublishAuth, Pyo3PythonManager,
+    TypeChecker, DEFAULT_CI_JOB_TIMEOUT, DEFAULT_RUST_EDITION,
+};
+
+/// Infers the project manager from the shape of a `pyproject.toml` file generated by this tool.
+pub fn detect_project_manager(
+    pyproject: &Value,
+) -> Result<(ProjectManager, Option<Pyo3PythonManager>)> {
+    if pyproject
+        .get("tool")
+        .and_then(|t| t.get("poetry"))
+        .is_some()
+    {
+        return Ok((ProjectManager::Poetry, None));
+    }
+
+    if pyproject.get("tool").and_then(|t| t.get("pixi")).is_some() {
+        return Ok((ProjectManager::Pixi, None));
+    }
+
+    let build_backend = pyproject
+        .get("build-system")
+        .and_then(|b| b.get("build-backend"))
+        .and_then(|b| b.as_str())
+        .unwrap_or_default();
+    let has_dependency_groups = pyproject.get("dependency-groups").is_some();
+
+    match build_backend {
+        "maturin" => {
+            let pyo3_python_manager = if has_dependency_groups {
+                Pyo3PythonManager::Uv
+            } else {
+                Pyo3PythonManager::Setuptools
+            };
+            Ok((ProjectManager::Maturin, Some(pyo3_python_manager)))
+        }
+        "setuptools.build_meta" => Ok((ProjectManager::Setuptools, None)),
+        "hatchling.build" if has_dependency_groups => Ok((ProjectManager::Uv, None)),
+        _ => bail!("Unable to determine the project manager from pyproject.toml"),
+    }
+}
+
+/// Values detected from the project are preferred when regenerating CI files, but when
+/// regenerating pyproject.toml the saved config wins so changes to it are picked up.
+pub fn apply_saved_config(project_info: &mut ProjectInfo, config: &Config) {
+    if let Some(max_line_length) = config.max_line_length {
+        project_info.max_line_length = max_line_length;
+    }
+
+    if config.ruff_line_length.is_some() {
+        project_info.ruff_line_length = config.ruff_line_length;
+    }
+}
+
+/// Reads an existing project's `pyproject.toml` and builds enough of a `ProjectInfo` to
+/// regenerate its CI and pre-commit files. Values that can't be read from the project fall back
+/// to the saved config.
+pub fn detect_project_info(project_dir: &Path) -> Result<ProjectInfo> {
+    let Ok(project_dir) = project_dir.canonicalize() else {
+        bail!(format!(
+            "The {} directory does not exist",
+            project_dir.display()
+        ));
+    };
+    let project_dir = project_dir.as_path();
+    let pyproject_path = project_dir.join("pyproject.toml");
+    let Ok(pyproject_str) = read_to_string(&pyproject_path) else {
+        bail!(format!(
+            "No pyproject.toml file found in {}",
+            project_dir.display()
+        ));
+    };
+    let Ok(pyproject) = pyproject_str.parse::<Value>() else {
+        bail!("Error parsing pyproject.toml");
+    };
+
+    let (project_manager, pyo3_python_manager) = detect_project_manager(&pyproject)?;
+    let config = Config::default().load_config();
+
+    let project_table = if project_manager == ProjectManager::Poetry {
+        pyproject.get("tool").and_then(|t| t.get("poetry"))
+    } else {
+        pyproject.get("project")
+    };
+
+    let Some(project_name) = project_table
+        .and_then(|p| p.get("name"))
+        .and_then(|n| n.as_str())
+    else {
+        bail!("No project name found in pyproject.toml");
+    };
+
+    let source_dir = match pyproject
+        .get("tool")
+        .and_then(|t| t.get("maturin"))
+        .and_then(|m| m.get("module-name"))
+        .and_then(|m| m.as_str())
+    {
+        Some(module_name) => module_name
+            .split('.')
+            .next()
+            .unwrap_or(module_name)
+            .to_string(),
+        None => project_name.replace([' ', '-'], "_").to_lowercase(),
+    };
+
+    let project_description = project_table
+        .and_then(|p| p.get("description"))
+        .and_then(|d| d.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    let min_python_version = detect_min_python_version(&pyproject, &project_manager)
+        .or(config.min_python_version)
+        .unwrap_or("3.9".to_string());
+
+    let max_line_length = pyproject
+        .get("tool")
+        .and_then(|t| t.get("ruff"))
+        .and_then(|r| r.get("line-length"))
+        .and_then(|l| l.as_integer())
+        .and_then(|l| u8::try_from(l).ok())
+        .or(config.max_line_length)
+        .unwrap_or(100);
+
+    let version = project_table
+        .and_then(|p| p.get("version"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("0.1.0")
+        .to_string();
+
+    let (creator, creator_email) = match detect_creator(project_table) {
+        Some((creator, creator_email)) => (creator, creator_email),
+        None => (
+            config.creator.unwrap_or_default(),
+            config.creator_email.unwrap_or_default(),
+        ),
+    };
+
+    let is_application = detect_is_application(
+        project_dir,
+        project_table,
+        &project_manager,
+        pyo3_python_manager.as_ref(),
+    )
+    .or(config.is_application)
+    .unwrap_or(true);
+
+    let workflows_dir = project_dir.join(".github/workflows");
+    let testing_file = read_to_string(workflows_dir.join("testing.yml")).unwrap_or_default();
+    let use_multi_os_ci = testing_file.contains("windows-latest");
+    let justfile = read_to_string(project_dir.join("justfile")).unwrap_or_default();
+    let pypi_publish_file =
+        read_to_string(workflows_dir.join("pypi_publish.yml")).unwrap_or_default();
+    let docs_publish_file =
+        read_to_string(workflows_dir.join("docs_publish.yml")).unwrap_or_default();
+    let (cloud_provider, cloud_deploy_role) = detect_cloud_provider(
+        &read_to_string(workflows_dir.join("deploy.yml")).unwrap_or_default(),
+    );
+    let python_version = detect_python_version(&pypi_publish_file)
+        .or_else(|| detect_python_version(&docs_publish_file))
+        .or(config.python_version)
+        .unwrap_or("3.13".to_string());
+    let github_actions_python_test_versions = detect_python_test_versions(&testing_file)
+        .or(config.github_actions_python_test_versions)
+        .unwrap_or(vec![min_python_version.clone()]);
+
+    let Some(project_slug) = project_dir.file_name().and_then(|s| s.to_str()) else {
+        bail!("Unable to determine the project directory name");
+    };
+
+    Ok(ProjectInfo {
+        project_name: project_name.to_string(),
+        project_slug: project_slug.to_string(),
+        source_dir,
+        project_description,
+        creator,
+        creator_email,
+        additional_authors: Vec::new(),
+        copyright_holder: config.copyright_holder,
+        license: config.license.unwrap_or(LicenseType::Mit),
+        copyright_year: None,
+        version,
+        python_version,
+        min_python_version,
+        project_manager,
+        pyo3_python_manager,
+        is_async_project: pyproject
+            .get("tool")
+            .and_then(|t| t.get("pytest"))
+            .and_then(|p| p.get("ini_options"))
+            .and_then(|i| i.get("asyncio_mode"))
+            .is_some()
+            || pyproject_str.contains("pytest-asyncio"),
+        is_application,
+        github_actions_python_test_versions,
+        max_line_length,
+        ruff_line_length: config.ruff_line_length,
+        min_coverage: config.min_coverage,
+        extra_ruff_rules: config.extra_ruff_rules.unwrap_or_default(),
+        include_bandit: pyproject_str.contains("bandit"),
+        include_conda_env: project_dir.join("environment.yml").is_file(),
+        include_codeowners: project_dir.join(".github/CODEOWNERS").is_file(),
+        github_username: config.github_username,
+        include_github_templates: project_dir
+            .join(".github/pull_request_template.md")
+            .is_file(),
+        include_contributing: project_dir.join("CONTRIBUTING.md").is_file(),
+        include_codeql: workflows_dir.join("codeql.yml").is_file(),
+        include_security_policy: project_dir.join("SECURITY.md").is_file(),
+        include_changelog: project_dir.join("CHANGELOG.md").is_file(),
+        include_citation: project_dir.join("CITATION.cff").is_file(),
+        include_benchmarks: project_dir.join("benchmarks").is_dir(),
+        extra_pre_commit_hooks: config.extra_pre_commit_hooks.unwrap_or_default(),
+        use_precommit_ci: read_to_string(project_dir.join(".pre-commit-config.yaml"))
+            .is_ok_and(|pre_commit| pre_commit.contains("\nci:\n")),
+        include_settings: false,
+        include_logging: false,
+        mypy_files: config.mypy_files,
+        ruff_target_version: config.ruff_target_version,
+        package_version_overrides: config.package_version_overrides.unwrap_or_default(),
+        dependency_bound_style: config.dependency_bound_style,
+        type_checker: if testing_file.contains("ty check") || justfile.contains("ty check") {
+            TypeChecker::Ty
+        } else {
+            config.type_checker.unwrap_or_default()
+        },
+        use_dependabot: false,
+        dependabot_schedule: None,
+        dependabot_day: None,
+        use_continuous_deployment: workflows_dir.join("pypi_publish.yml").exists(),
+        use_release_drafter: workflows_dir.join("release_drafter.yml").exists(),
+        cloud_provider,
+        cloud_deploy_role,
+        publish_docker: workflows_dir.join("docker_publish.yml").exists()
+            || config.publish_docker.unwrap_or(false),
+        supply_chain_attestation: pypi_publish_file.contains("attest-build-provenance"),
+        pypi_index_url: config.pypi_index_url,
+        publish_auth: if pypi_publish_file.contains("secrets.PYPI_TOKEN") {
+            PublishAuth::ApiToken
+        } else {
+            config.publish_auth.unwrap_or_default()
+        },
+        include_testpypi_publish: workflows_dir.join("testpypi_publish.yml").exists(),
+        include_rust_toolchain: project_dir.join("rust-toolchain.toml").is_file(),
+        rust_edition: config
+            .rust_edition
+            .unwrap_or(DEFAULT_RUST_EDITION.to_string()),
+        pyo3_version: config.pyo3_version,
+        use_abi3: read_to_string(project_dir.join("Cargo.toml"))
+            .is_ok_and(|cargo_toml| cargo_toml.contains("\"abi3-py")),
+        build_musllinux: pypi_publish_file.contains("manylinux: musllinux_"),
+        maturin_targets: config.maturin_targets,
+        ci_job_timeout: config.ci_job_timeout.unwrap_or(DEFAULT_CI_JOB_TIMEOUT),
+        ci_coverage_comment: testing_file.contains("python-coverage-comment-action"),
+        uv_frozen: if testing_file.contains("uv sync") {
+            testing_file.contains("uv sync --frozen")
+        } else {
+            config.uv_frozen.unwrap_or(true)
+        },
+        use_multi_os_ci,
+        include_docs: workflows_dir.join("docs_publish.yml").exists(),
+        docs_info: None,
+        download_latest_packages: false,
+        offline: false,
+        project_root_dir: project_dir.parent().map(|p| p.to_path_buf()),
+    })
+}
+
+fn detect_min_python_version(
+    pyproject: &Value,
+    project_manager: &ProjectManager,
+) -> Option<String> {
+    let requires_python = if project_manager == &ProjectManager::Poetry {
+        pyproject
+            .get("tool")
+            .and_then(|t| t.get("poetry"))
+            .and_then(|p| p.get("dependencies"))
+            .and_then(|d| d.get("python"))
+    } else {
+        pyproject
+            .get("project")
+            .and_then(|p| p.get("requires-python"))
+    }?
+    .as_str()?;
+
+    let version = requires_python
+        .trim_start_matches(['>', '=', '^', '~'])
+        .trim();
+
+    if version.is_empty() {
+        None
+    } else {
+        Some(version.to_string())
+    }
+}
+
+/// The first author is the creator. Poetry stores authors as `Name <email>` strings while the
+/// other managers use `{ name, email }` tables.
+fn detect_creator(project_table: Option<&Value>) -> Option<(String, String)> {
+    let author = project_table?.get("authors")?.as_array()?.first()?;
+
+    if let Some(author) = author.as_str() {
+        let (name, email) = author.split_once('<').unwrap_or((author, ""));
+        return Some((
+            name.trim().to_string(),
+            email.trim().trim_end_matches('>').to_string(),
+        ));
+    }
+
+    let name = author
+        .get("name")
+        .and_then(|n| n.as_str())
+        .unwrap_or_default();
+    let email = author
+        .get("email")
+        .and_then(|e| e.as_str())
+        .unwrap_or_default();
+
+    Some((name.to_string(), email.to_string()))
+}
+
+/// Libraries gitignore their lock file and applications commit it, so the `.gitignore` tells
+/// which one the project is. Project managers without a lock file fall back to the runtime
+/// dependencies, where applications pin an exact version and libraries use a minimum version.
+fn detect_is_application(
+    project_dir: &Path,
+    project_table: Option<&Value>,
+    project_manager: &ProjectManager,
+    pyo3_python_manager: Option<&Pyo3PythonManager>,
+) -> Option<bool> {
+    if let Some(lock_file) = lock_file_name(project_manager, pyo3_python_manager) {
+        return match read_to_string(project_dir.join(".gitignore")) {
+            Ok(gitignore) => Some(!gitignore.lines().any(|line| line.trim() == lock_file)),
+            Err(_) => project_dir.join(lock_file).is_file().then_some(true),
+        };
+    }
+
+    let dependencies = project_table?.get("dependencies")?;
+
+    if project_manager == &ProjectManager::Poetry {
+        let version = dependencies
+            .as_table()?
+            .iter()
+            .filter(|(name, _)| name.as_str() != "python")
+            .find_map(|(_, d)| d.as_str().or_else(|| d.get("version")?.as_str()))?;
+
+        return Some(!version.starts_with(['>', '^', '~']));
+    }
+
+    dependencies.as_array()?.iter().find_map(|d| {
+        let specifier = d.as_str()?;
+        if specifier.contains("==") {
+            Some(true)
+        } else if specifier.contains(">=") {
+            Some(false)
+        } else {
+            None
+        }
+    })
+}
+
+/// The deploy workflow authenticates with the cloud provider's own action, and the role or
+/// workload identity provider it uses is kept so it isn't replaced with the repository variable.
+fn detect_cloud_provider(deploy_file: &str) -> (Option<CloudProvider>, Option<String>) {
+    let find_value = |key: &str| {
+        deploy_file
+            .lines()
+            .find_map(|line| line.trim().strip_prefix(key))
+            .map(|value| value.trim().to_string())
+    };
+
+    if deploy_file.contains("aws-actions/configure-aws-credentials") {
+        (Some(CloudProvider::Aws), find_value("role-to-assume:"))
+    } else if deploy_file.contains("google-github-actions/auth") {
+        (
+            Some(CloudProvider::Gcp),
+            find_value("workload_identity_provider:"),
+        )
+    } else {
+        (None, None)
+    }
+}
+
+/// The publish workflows set up the project's Python version rather than the minimum version.
+fn detect_python_version(workflow_file: &str) -> Option<String> {
+    workflow_file.lines().find_map(|line| {
+        let version = line.trim().strip_prefix("python-version: \"")?;
+        version.split_once('"').map(|(v, _)| v.to_string())
+    })
+}
+
+fn detect_python_test_versions(testing_file: &str) -> Option<Vec<String>> {
+    let line = testing_file
+        .lines()
+        .find(|l| l.trim_start().starts_with("python-version: ["))?;
+    let start = line.find('[')?;
+    let end = line.find(']')?;
+    let versions: Vec<String> = line[start + 1..end]
+        .split(',')
+        .map(|v| v.trim().trim_matches('"').to_string())
+        .filter(|v| !v.is_empty())
+        .collect();
+
+    if versions.is_empty() {
+        None
+    } else {
+        Some(versions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project_generator::update_pyproject;
+    use std::fs::{create_dir_all, write};
+    use tmp_path::tmp_path;
+
+    fn parse(pyproject: &str) -> Value {
+        pyproject.parse::<Value>().unwrap()
+    }
+
+    #[test]
+    fn test_detect_poetry() {
+        let pyproject = parse(
+            r#"[tool.poetry]
+name = "my-project"
+version = "0.1.0"
+
+[tool.poetry.dependencies]
+python = "^3.10"
+
+[build-system]
+requires = ["poetry-core>=1.0.0"]
+build-backend = "poetry.core.masonry.api"
+"#,
+        );
+
+        assert_eq!(
+            detect_project_manager(&pyproject).unwrap(),
+            (ProjectManager::Poetry, None)
+        );
+        assert_eq!(
+            detect_min_python_version(&pyproject, &ProjectManager::Poetry),
+            Some("3.10".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_uv() {
+        let pyproject = parse(
+            r#"[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+
+[project]
+name = "my-project"
+requires-python = ">=3.11"
+dependencies = []
+
+[dependency-groups]
+dev = ["pytest==8.3.4"]
+"#,
+        );
+
+        assert_eq!(
+            detect_project_manager(&pyproject).unwrap(),
+            (ProjectManager::Uv, None)
+        );
+        assert_eq!(
+            detect_min_python_version(&pyproject, &ProjectManager::Uv),
+            Some("3.11".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_maturin_uv() {
+        let pyproject = parse(
+            r#"[build-system]
+requires = ["maturin>=1.5,<2.0"]
+build-backend = "maturin"
+
+[project]
+name = "my-project"
+requires-python = ">=3.9"
+dependencies = []
+
+[dependency-groups]
+dev = ["pytest==8.3.4"]
+
+[tool.maturin]
+module-name = "my_project._my_project"
+"#,
+        );
+
+        assert_eq!(
+            detect_project_manager(&pyproject).unwrap(),
+            (ProjectManager::Maturin, Some(Pyo3PythonManager::Uv))
+        );
+    }
+
+    #[test]
+    fn test_detect_maturin_setuptools() {
+        let pyproject = parse(
+            r#"[build-system]
+requires = ["maturin>=1.5,<2.0"]
+build-backend = "maturin"
+
+[project]
+name = "my-project"
+dependencies = []
+
+[tool.maturin]
+module-name = "my_project._my_project"
+"#,
+        );
+
+        assert_eq!(
+            detect_project_manager(&pyproject).unwrap(),
+            (ProjectManager::Maturin, Some(Pyo3PythonManager::Setuptools))
+        );
+    }
+
+    #[test]
+    fn test_detect_pixi() {
+        let pyproject = parse(
+            r#"[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+
+[project]
+name = "my-project"
+
+[tool.pixi.project]
+channels = ["conda-forge"]
+"#,
+        );
+
+        assert_eq!(
+            detect_project_manager(&pyproject).unwrap(),
+            (ProjectManager::Pixi, None)
+        );
+    }
+
+    #[test]
+    fn test_detect_setuptools() {
+        let pyproject = parse(
+            r#"[build-system]
+requires = ["setuptools", "wheel"]
+build-backend = "setuptools.build_meta"
+
+[project]
+name = "my-project"
+"#,
+        );
+
+        assert_eq!(
+            detect_project_manager(&pyproject).unwrap(),
+            (ProjectManager::Setuptools, None)
+        );
+    }
+
+    #[test]
+    fn test_detect_unknown_project_manager() {
+        let pyproject = parse(
+            r#"[build-system]
+requires = ["flit_core"]
+build-backend = "flit_core.buildapi"
+"#,
+        );
+
+        assert!(detect_project_manager(&pyproject).is_err());
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_detect_project_info() {
+        let project_dir = tmp_path.join("my-project");
+        create_dir_all(project_dir.join(".github/workflows")).unwrap();
+        write(
+            project_dir.join("pyproject.toml"),
+            r#"[build-system]
+requires = ["maturin>=1.5,<2.0"]
+build-backend = "maturin"
+
+[project]
+name = "my-project"
+description = "This is a test"
+requires-python = ">=3.10"
+dependencies = []
+
+[dependency-groups]
+dev = ["bandit==1.8.2"]
+
+[tool.maturin]
+module-name = "my_module._my_module"
+
+[tool.ruff]
+line-length = 88
+"#,
+        )
+        .unwrap();
+        write(
+            project_dir.join(".github/workflows/testing.yml"),
+            "    strategy:\n      matrix:\n        python-version: [\"3.10\", \"3.11\"]\n        os: [ubuntu-latest, windows-latest, macos-latest]\n",
+        )
+        .unwrap();
+        write(project_dir.join(".github/workflows/pypi_publish.yml"), "").unwrap();
+
+        let project_info = detect_project_info(&project_dir).unwrap();
+
+        assert_eq!(project_info.project_name, "my-project");
+        assert_eq!(project_info.project_slug, "my-project");
+        assert_eq!(project_info.source_dir, "my_module");
+        assert_eq!(project_info.project_description, "This is a test");
+        assert_eq!(project_info.min_python_version, "3.10");
+        assert_eq!(project_info.max_line_length, 88);
+        assert_eq!(
+            project_info.github_actions_python_test_versions,
+            vec!["3.10".to_string(), "3.11".to_string()]
+        );
+        assert!(project_info.include_bandit);
+        assert!(project_info.use_multi_os_ci);
+        assert!(project_info.use_continuous_deployment);
+        assert!(!project_info.use_release_drafter);
+        assert!(!project_info.include_docs);
+        assert_eq!(project_info.base_dir(), project_dir.canonicalize().unwrap());
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_detect_project_info_reads_project_table() {
+        let project_dir = tmp_path.join("my-project");
+        create_dir_all(project_dir.join(".github/workflows")).unwrap();
+        write(
+            project_dir.join("pyproject.toml"),
+            r#"[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+
+[project]
+name = "my-project"
+version = "2.3.1"
+authors = [
+  { name = "Ford Prefect", email = "ford@betelgeuse.com" },
+]
+requires-python = ">=3.10"
+dependencies = ["httpx>=0.28.1"]
+
+[dependency-groups]
+dev = ["pytest==8.3.4"]
+"#,
+        )
+        .unwrap();
+        write(
+            project_dir.join(".github/workflows/pypi_publish.yml"),
+            "    - name: Set up Python\n      uses: actions/setup-python@v5\n      with:\n        python-version: \"3.11\"\n",
+        )
+        .unwrap();
+        write(project_dir.join(".gitignore"), "# Lock file\nuv.lock\n").unwrap();
+
+        let project_info = detect_project_info(&project_dir).unwrap();
+
+        assert_eq!(project_info.version, "2.3.1");
+        assert_eq!(project_info.creator, "Ford Prefect");
+        assert_eq!(project_info.creator_email, "ford@betelgeuse.com");
+        assert_eq!(project_info.python_version, "3.11");
+        assert!(!project_info.is_application);
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_detect_project_info_reads_poetry_table() {
+        let project_dir = tmp_path.join("my-project");
+        create_dir_all(&project_dir).unwrap();
+        write(
+            project_dir.join("pyproject.toml"),
+            r#"[tool.poetry]
+name = "my-project"
+version = "4.0.2"
+authors = ["Ford Prefect <ford@betelgeuse.com>"]
+
+[tool.poetry.dependencies]
+python = "^3.10"
+httpx = "0.28.1"
+"#,
+        )
+        .unwrap();
+        write(project_dir.join("poetry.lock"), "").unwrap();
+
+        let project_info = detect_project_info(&project_dir).unwrap();
+
+        assert_eq!(project_info.version, "4.0.2");
+        assert_eq!(project_info.creator, "Ford Prefect");
+        assert_eq!(project_info.creator_email, "ford@betelgeuse.com");
+        assert!(project_info.is_application);
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_detect_is_application_library_no_dependencies() {
+        let pyproject = parse(
+            r#"[project]
+name = "my-project"
+dependencies = []
+"#,
+        );
+        write(tmp_path.join(".gitignore"), "# Lock file\nuv.lock\n").unwrap();
+
+        assert_eq!(
+            detect_is_application(
+                &tmp_path,
+                pyproject.get("project"),
+                &ProjectManager::Uv,
+                None
+            ),
+            Some(false)
+        );
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_detect_is_application_application_no_dependencies() {
+        let pyproject = parse(
+            r#"[project]
+name = "my-project"
+dependencies = []
+"#,
+        );
+        write(tmp_path.join(".gitignore"), "__pycache__/\n").unwrap();
+
+        assert_eq!(
+            detect_is_application(
+                &tmp_path,
+                pyproject.get("project"),
+                &ProjectManager::Uv,
+                None
+            ),
+            Some(true)
+        );
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_detect_is_application_no_lock_file_no_dependencies() {
+        let pyproject = parse(
+            r#"[project]
+name = "my-project"
+dependencies = []
+"#,
+        );
+
+        assert_eq!(
+            detect_is_application(
+                &tmp_path,
+                pyproject.get("project"),
+                &ProjectManager::Setuptools,
+                None
+            ),
+            None
+        );
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_detect_project_info_async_and_cloud_provider() {
+        let project_dir = tmp_path.join("my-project");
+        create_dir_all(project_dir.join(".github/workflows")).unwrap();
+        write(
+            project_dir.join("pyproject.toml"),
+            r#"[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+
+[project]
+name = "my-project"
+dependencies = []
+
+[dependency-groups]
+dev = ["pytest-asyncio==0.25.3"]
+
+[tool.pytest.ini_options]
+asyncio_mode = "auto"
+"#,
+        )
+        .unwrap();
+        write(
+            project_dir.join(".github/workflows/deploy.yml"),
+            "    - name: Configure AWS credentials\n      uses: aws-actions/configure-aws-credentials@v4\n      with:\n        role-to-assume: arn:aws:iam::123456789012:role/deploy\n",
+        )
+        .unwrap();
+
+        let project_info = detect_project_info(&project_dir).unwrap();
+
+        assert!(project_info.is_async_project);
+        assert_eq!(project_info.cloud_provider, Some(CloudProvider::Aws));
+        assert_eq!(
+            project_info.cloud_deploy_role,
+            Some("arn:aws:iam::123456789012:role/deploy".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_cloud_provider_gcp() {
+        let deploy_file = "      uses: google-github-actions/auth@v2\n      with:\n        workload_identity_provider: ${{ vars.GCP_WORKLOAD_IDENTITY_PROVIDER }}\n";
+
+        assert_eq!(
+            detect_cloud_provider(deploy_file),
+            (
+                Some(CloudProvider::Gcp),
+                Some("${{ vars.GCP_WORKLOAD_IDENTITY_PROVIDER }}".to_string())
+            )
+        );
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_update_pyproject_uses_saved_max_line_length() {
+        let project_dir = tmp_path.join("my-project");
+        create_dir_all(&project_dir).unwrap();
+        write(
+            project_dir.join("pyproject.toml"),
+            r#"[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+
+[project]
+name = "my-project"
+requires-python = ">=3.10"
+dependencies = []
+
+[dependency-groups]
+dev = ["pytest==8.3.4"]
+
+[tool.ruff]
+line-length = 88
+"#,
+        )
+        .unwrap();
+
+        let mut project_info = detect_project_info(&project_dir).unwrap();
+        project_info.download_latest_packages = false;
+        let mut config = Config::default();
+        config.max_line_length = Some(120);
+        apply_saved_config(&mut project_info, &config);
+        update_pyproject(&project_info).unwrap();
+
+        let pyproject = read_to_string(project_dir.join("pyproject.toml")).unwrap();
+
+        assert!(pyproject.contains("line-length = 120"));
+        assert!(!pyproject.contains("line-length = 88"));
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_update_pyproject_keeps_project_keys() {
+        let project_dir = tmp_path.join("my-project");
+        create_dir_all(&project_dir).unwrap();
+        write(
+            project_dir.join("pyproject.toml"),
+            r#"[tool.poetry]
+name = "my-project"
+version = "2.3.1"
+description = "My project"
+authors = ["Ford Prefect <ford@heartofgold.com>"]
+license = "Apache-2.0"
+readme = "README.md"
+
+[tool.poetry.dependencies]
+python = "^3.10"
+httpx = "^0.28.1"
+
+[tool.poetry.group.dev.dependencies]
+pytest = "8.3.4"
+
+[build-system]
+requires = ["poetry-core>=1.0.0"]
+build-backend = "poetry.core.masonry.api"
+"#,
+        )
+        .unwrap();
+
+        let mut project_info = detect_project_info(&project_dir).unwrap();
+        project_info.download_latest_packages = false;
+        update_pyproject(&project_info).unwrap();
+
+        let pyproject = read_to_string(project_dir.join("pyproject.toml")).unwrap();
+
+        assert!(pyproject.contains("version = \"2.3.1\""));
+        assert!(pyproject.contains("authors = [\"Ford Prefect <ford@heartofgold.com>\"]"));
+        assert!(pyproject.contains("license = \"Apache-2.0\""));
+        assert!(pyproject
+            .contains("[tool.poetry.dependencies]\npython = \"^3.10\"\nhttpx = \"^0.28.1\"\n"));
+        assert!(pyproject.contains("[tool.ruff]"));
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_update_pyproject_keeps_project_dependencies() {
+        let project_dir = tmp_path.join("my-project");
+        create_dir_all(&project_dir).unwrap();
+        write(
+            project_dir.join("pyproject.toml"),
+            r#"[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+
+[project]
+name = "my-project"
+authors = [{ name = "Ford Prefect", email = "ford@heartofgold.com" }]
+requires-python = ">=3.10"
+dynamic = ["version"]
+dependencies = ["httpx>=0.28.1", "rich>=13.9.4"]
+
+[dependency-groups]
+dev = ["pytest==8.3.4"]
+"#,
+        )
+        .unwrap();
+
+        let mut project_info = detect_project_info(&project_dir).unwrap();
+        project_info.download_latest_packages = false;
+        update_pyproject(&project_info).unwrap();
+
+        let pyproject = read_to_string(project_dir.join("pyproject.toml")).unwrap();
+        let parsed = parse(&pyproject);
+        let project = parsed.get("project").unwrap();
+
+        assert_eq!(
+            project.get("dependencies").unwrap(),
+            &parse(r#"d = ["httpx>=0.28.1", "rich>=13.9.4"]"#)["d"]
+        );
+        assert_eq!(project["authors"][0]["name"].as_str(), Some("Ford Prefect"));
+        assert!(project.get("license").is_none());
+        assert!(project.get("version").is_none());
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_update_pyproject_keeps_async_and_user_dev_dependencies() {
+        let project_dir = tmp_path.join("my-project");
+        create_dir_all(&project_dir).unwrap();
+        write(
+            project_dir.join("pyproject.toml"),
+            r#"[build-system]
+requires = ["hatchling"]
+build-backend = "hatchling.build"
+
+[project]
+name = "my-project"
+authors = [{ name = "Ford Prefect", email = "ford@heartofgold.com" }]
+requires-python = ">=3.10"
+dynamic = ["version"]
+dependencies = []
+
+[dependency-groups]
+dev = ["pytest==8.3.4", "pytest-asyncio==0.25.0", "hypothesis==6.122.3"]
+
+[tool.pytest.ini_options]
+minversion = "6.0"
+addopts = "--cov=my_project --cov-report term-missing --no-cov-on-fail"
+asyncio_mode = "auto"
+markers = ["slow: marks tests as slow"]
+"#,
+        )
+        .unwrap();
+
+        let mut project_info = detect_project_info(&project_dir).unwrap();
+        project_info.download_latest_packages = false;
+        update_pyproject(&project_info).unwrap();
+
+        let pyproject = read_to_string(project_dir.join("pyproject.toml")).unwrap();
+        let parsed = parse(&pyproject);
+        let dev: Vec<&str> = parsed["dependency-groups"]["dev"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|value| value.as_str().unwrap())
+            .collect();
+        let pytest_options = &parsed["tool"]["pytest"]["ini_options"];
+        let ruff_rules = parsed["tool"]["ruff"]["lint"]["select"].as_array().unwrap();
+
+        assert!(dev.iter().any(|d| d.starts_with("pytest-asyncio")));
+        assert!(dev.contains(&"hypothesis==6.122.3"));
+        assert_eq!(dev.iter().filter(|d| d.starts_with("pytest==")).count(), 1);
+        assert_eq!(pytest_options["asyncio_mode"].as_str(), Some("auto"));
+        assert_eq!(
+            pytest_options["markers"][0].as_str(),
+            Some("slow: marks tests as slow")
+        );
+        assert!(ruff_rules.iter().any(|rule| rule.as_str() == Some("ASYNC")));
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_update_pyproject_keeps_poetry_user_dev_dependencies() {
+        let project_dir = tmp_path.join("my-project");
+        create_dir_all(&project_dir).unwrap();
+        write(
+            project_dir.join("pyproject.toml"),
+            r#"[tool.poetry]
+name = "my-project"
+version = "1.2.3"
+description = ""
+authors = ["Ford Prefect <ford@heartofgold.com>"]
+
+[tool.poetry.dependencies]
+python = "^3.10"
+
+[tool.poetry.group.dev.dependencies]
+pytest = "8.3.4"
+hypothesis = "6.122.3"
+
+[build-system]
+requires = ["poetry-core>=1.0.0"]
+build-backend = "poetry.core.masonry.api"
+"#,
+        )
+        .unwrap();
+
+        let mut project_info = detect_project_info(&project_dir).unwrap();
+        project_info.download_latest_packages = false;
+        update_pyproject(&project_info).unwrap();
+
+        let pyproject = read_to_string(project_dir.join("pyproject.toml")).unwrap();
+        let parsed = parse(&pyproject);
+        let dev = &parsed["tool"]["poetry"]["group"]["dev"]["dependencies"];
+
+        assert_eq!(dev["hypothesis"].as_str(), Some("6.122.3"));
+        assert!(dev.get("mypy").is_some());
+    }
+
+    #[tmp_path]
+    #[test]
+    fn test_detect_project_info_no_pyproject() {
+        assert!(detect_project_info(&tmp_path).is_err());
+    }
+}
@@ -1,23 +1,35 @@
-use std::fs::create_dir_all;
+use std::{
+    fs::{create_dir_all, read_to_string},
+    time::{Duration, Instant},
+};
 
 use anyhow::{bail, Result};
 use colored::*;
 use minijinja::render;
 use rayon::prelude::*;
+use toml_edit::{DocumentMut, Item, Table};
 
 use crate::file_manager::{save_empty_src_file, save_file_with_content};
 use crate::github_actions::{
-    save_ci_testing_linux_only_file, save_ci_testing_multi_os_file, save_dependabot_file,
-    save_docs_publish_file, save_pypi_publish_file, save_release_drafter_file,
+    save_ci_testing_linux_only_file, save_ci_testing_multi_os_file, save_codeowners_file,
+    save_codeql_file, save_dependabot_file, save_deploy_file, save_docker_publish_file,
+    save_docs_publish_file, save_github_templates, save_pypi_publish_file,
+    save_release_drafter_file, save_testpypi_publish_file,
 };
 use crate::licenses::{generate_license, license_str};
 use crate::package_version::{
     LatestVersion, PreCommitHook, PreCommitHookVersion, PythonPackage, PythonPackageVersion,
+    VersionCache, RETRY_ATTEMPTS,
+};
+use crate::project_info::{
+    DependencyBoundStyle, ExtraPreCommitHook, LicenseType, ProjectInfo, ProjectManager,
+    Pyo3PythonManager, TypeChecker, DEFAULT_RUFF_RULES,
 };
-use crate::project_info::{ProjectInfo, ProjectManager, Pyo3PythonManager};
 use crate::python_files::generate_python_files;
-use crate::rust_files::{save_cargo_toml_file, save_lib_file};
-use crate::utils::is_python_312_or_greater;
+use crate::rust_files::{
+    abi3_feature, save_cargo_toml_file, save_lib_file, save_rust_toolchain_file,
+};
+use crate::utils::{is_python_312_or_greater, uv_sync_command};
 
 fn create_directories(project_info: &ProjectInfo) -> Result<()> {
     let module = project_info.source_dir.replace([' ', '-'], "_");
@@ -41,10 +53,31 @@ fn create_directories(project_info: &ProjectInfo) -> Result<()> {
         create_dir_all(docs_css_dir)?;
     }
 
+    if project_info.include_benchmarks {
+        let benchmarks_dir = base.join("benchmarks");
+        create_dir_all(benchmarks_dir)?;
+    }
+
     Ok(())
 }
 
-fn create_gitigngore_file(project_manager: &ProjectManager) -> String {
+pub fn lock_file_name(
+    project_manager: &ProjectManager,
+    pyo3_python_manager: Option<&Pyo3PythonManager>,
+) -> Option<&'static str> {
+    match project_manager {
+        ProjectManager::Poetry => Some("poetry.lock"),
+        ProjectManager::Uv => Some("uv.lock"),
+        ProjectManager::Pixi => Some("pixi.lock"),
+        ProjectManager::Maturin => match pyo3_python_manager {
+            Some(Pyo3PythonManager::Uv) => Some("uv.lock"),
+            _ => None,
+        },
+        ProjectManager::Setuptools => None,
+    }
+}
+
+fn create_gitigngore_file(project_info: &ProjectInfo) -> String {
     let mut gitignore = r#"
 # Byte-compiled / optimized / DLL files
 __pycache__/
@@ -129,9 +162,6 @@ target/
 # Jupyter Notebook
 .ipynb_checkpoints
 
-# pixi environments
-.pixi
-
 # IPython
 profile_default/
 ipython_config.py
@@ -189,13 +219,29 @@ dmypy.json
 "#
     .to_string();
 
-    if let ProjectManager::Maturin = project_manager {
-        gitignore.push_str(
+    match &project_info.project_manager {
+        ProjectManager::Maturin => gitignore.push_str(
             r#"
 # Rust
 /target
 "#,
-        );
+        ),
+        ProjectManager::Pixi => gitignore.push_str(
+            r#"
+# pixi environments
+.pixi
+"#,
+        ),
+        _ => (),
+    }
+
+    if !project_info.commits_lock_file() {
+        if let Some(lock_file) = lock_file_name(
+            &project_info.project_manager,
+            project_info.pyo3_python_manager.as_ref(),
+        ) {
+            gitignore.push_str(&format!("\n# Lock file\n{lock_file}\n"));
+        }
     }
 
     gitignore
@@ -203,24 +249,40 @@ dmypy.json
 
 fn save_gitigngore_file(project_info: &ProjectInfo) -> Result<()> {
     let file_path = project_info.base_dir().join(".gitignore");
-    let content = create_gitigngore_file(&project_info.project_manager);
+    let content = create_gitigngore_file(project_info);
     save_file_with_content(&file_path, &content)?;
 
     Ok(())
 }
 
-fn build_latest_pre_commit_dependencies(
-    download_latest_packages: bool,
-) -> Vec<PreCommitHookVersion> {
-    let mut hooks = vec![
-        PreCommitHookVersion::new(PreCommitHook::PreCommit),
-        PreCommitHookVersion::new(PreCommitHook::MyPy),
-        PreCommitHookVersion::new(PreCommitHook::Ruff),
-    ];
+fn build_latest_pre_commit_dependencies(project_info: &ProjectInfo) -> Vec<PreCommitHookVersion> {
+    let mut hooks = vec![PreCommitHookVersion::new(PreCommitHook::PreCommit)];
+
+    // There is no ty pre-commit hook yet so type checking is left to the justfile and CI.
+    if project_info.type_checker == TypeChecker::Mypy {
+        hooks.push(PreCommitHookVersion::new(PreCommitHook::MyPy));
+    }
 
-    if download_latest_packages {
+    hooks.push(PreCommitHookVersion::new(PreCommitHook::Ruff));
+
+    if project_info.include_bandit {
+        hooks.push(PreCommitHookVersion::new(PreCommitHook::Bandit));
+    }
+
+    if project_info
+        .extra_pre_commit_hooks
+        .contains(&ExtraPreCommitHook::Codespell)
+    {
+        hooks.push(PreCommitHookVersion::new(PreCommitHook::Codespell));
+    }
+
+    if project_info.should_download_latest_packages() {
+        let cache = VersionCache::from_config().ok();
         hooks.par_iter_mut().for_each(|hook| {
-            if hook.get_latest_version().is_err() {
+            if hook
+                .get_latest_version_with_retry(RETRY_ATTEMPTS, cache.as_ref())
+                .is_err()
+            {
                 let error_message = format!(
                     "Error retrieving latest pre-commit version for {}. Using default.",
                     hook.hook
@@ -233,14 +295,69 @@ fn build_latest_pre_commit_dependencies(
     hooks
 }
 
-fn create_pre_commit_file(download_latest_packages: bool) -> String {
-    let mut pre_commit_str = "repos:".to_string();
-    let hooks = build_latest_pre_commit_dependencies(download_latest_packages);
+/// Pre-commit expects the interpreter name, e.g. `python3.10`, so any version specifier, such as
+/// `>=` or `^`, and patch version are dropped.
+fn pre_commit_python_version(min_python_version: &str) -> String {
+    let major_minor = min_python_version
+        .trim_start_matches(['^', '~', '>', '='])
+        .trim()
+        .split('.')
+        .take(2)
+        .collect::<Vec<&str>>()
+        .join(".");
+
+    format!("python{major_minor}")
+}
+
+fn create_pre_commit_file(project_info: &ProjectInfo) -> String {
+    let mut pre_commit_str = format!(
+        "default_language_version:\n  python: {}\nrepos:",
+        pre_commit_python_version(&project_info.min_python_version)
+    );
+    let hooks = build_latest_pre_commit_dependencies(project_info);
+    let mut base_hook_ids = vec!["check-added-large-files".to_string()];
+    for extra_hook in [
+        ExtraPreCommitHook::CheckCaseConflict,
+        ExtraPreCommitHook::CheckMergeConflict,
+    ] {
+        if project_info.extra_pre_commit_hooks.contains(&extra_hook) {
+            base_hook_ids.push(extra_hook.to_string());
+        }
+    }
+    base_hook_ids.extend(
+        [
+            "check-toml",
+            "check-yaml",
+            "debug-statements",
+            "end-of-file-fixer",
+            "trailing-whitespace",
+        ]
+        .map(String::from),
+    );
+    let base_hooks = base_hook_ids
+        .iter()
+        .map(|id| format!("\n    - id: {id}"))
+        .collect::<String>();
+
     for hook in hooks {
         match hook.hook {
+            PreCommitHook::Bandit => {
+                let info = format!(
+                    "\n  - repo: {}\n    rev: {}\n    hooks:\n    - id: bandit\n      exclude: ^tests/",
+                    hook.repo, hook.rev
+                );
+                pre_commit_str.push_str(&info);
+            }
+            PreCommitHook::Codespell => {
+                let info = format!(
+                    "\n  - repo: {}\n    rev: {}\n    hooks:\n    - id: codespell",
+                    hook.repo, hook.rev
+                );
+                pre_commit_str.push_str(&info);
+            }
             PreCommitHook::PreCommit => {
                 let info = format!(
-                    "\n  - repo: {}\n    rev: {}\n    hooks:\n    - id: check-added-large-files\n    - id: check-toml\n    - id: check-yaml\n    - id: debug-statements\n    - id: end-of-file-fixer\n    - id: trailing-whitespace",
+                    "\n  - repo: {}\n    rev: {}\n    hooks:{base_hooks}",
                     hook.repo, hook.rev
                 );
                 pre_commit_str.push_str(&info);
@@ -263,32 +380,144 @@ fn create_pre_commit_file(download_latest_packages: bool) -> String {
     }
 
     pre_commit_str.push('\n');
+
+    // pre-commit.ci opens pull requests with hook fixes and keeps the hook versions up to date.
+    if project_info.use_precommit_ci {
+        pre_commit_str.push_str("ci:\n  autofix_prs: true\n  autoupdate_schedule: weekly\n");
+    }
+
     pre_commit_str
 }
 
 fn save_pre_commit_file(project_info: &ProjectInfo) -> Result<()> {
     let file_path = project_info.base_dir().join(".pre-commit-config.yaml");
-    let content = create_pre_commit_file(project_info.download_latest_packages);
+    let content = create_pre_commit_file(project_info);
     save_file_with_content(&file_path, &content)?;
 
     Ok(())
 }
 
-fn build_latest_dev_dependencies(project_info: &ProjectInfo) -> Result<String> {
-    let mut version_string = String::new();
+fn latest_dependency_versions(project_info: &ProjectInfo) -> Vec<PythonPackageVersion> {
+    let mut packages = Vec::new();
+
+    if project_info.include_settings {
+        packages.push(PythonPackageVersion::new(PythonPackage::PydanticSettings));
+    }
+
+    if project_info.should_download_latest_packages() {
+        let cache = VersionCache::from_config().ok();
+        packages.par_iter_mut().for_each(|package| {
+            if package
+                .get_latest_version_with_retry(RETRY_ATTEMPTS, cache.as_ref())
+                .is_err()
+            {
+                let error_message = format!(
+                    "Error retrieving latest python package version for {}. Using default.",
+                    package.package
+                );
+                println!("\n{}", error_message.yellow());
+            }
+        })
+    }
+
+    packages
+}
+
+/// The runtime dependencies as requirement specifiers. Libraries use a minimum version so they
+/// don't conflict with the dependencies of the projects that install them, applications pin the
+/// exact version.
+fn dependency_specifiers(
+    project_info: &ProjectInfo,
+    packages: &[PythonPackageVersion],
+) -> Vec<String> {
+    let operator = if project_info.is_application {
+        "=="
+    } else {
+        ">="
+    };
+
+    packages
+        .iter()
+        .map(|package| format!("{}{operator}{}", package.package, package.version))
+        .collect()
+}
+
+fn build_latest_dependencies(
+    project_info: &ProjectInfo,
+    packages: &[PythonPackageVersion],
+) -> String {
+    if let ProjectManager::Poetry = project_info.project_manager {
+        return packages
+            .iter()
+            .map(|package| {
+                if project_info.is_application {
+                    format!("{} = \"{}\"", package.package, package.version)
+                } else {
+                    format!("{} = \">={}\"", package.package, package.version)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+    }
+
+    let specifiers = dependency_specifiers(project_info, packages);
+
+    if specifiers.is_empty() {
+        return "[]".to_string();
+    }
+
+    let mut version_string = "[\n".to_string();
+    for specifier in specifiers {
+        version_string.push_str(&format!("  \"{specifier}\",\n"));
+    }
+    version_string.push(']');
+
+    version_string
+}
+
+/// The version specifier used for dev dependencies in a PEP 735 `dependency-groups` list.
+fn dependency_bound_operator(
+    dependency_bound_style: &Option<DependencyBoundStyle>,
+) -> &'static str {
+    match dependency_bound_style {
+        Some(DependencyBoundStyle::Minimum) => ">=",
+        Some(DependencyBoundStyle::Compatible) => "~=",
+        Some(DependencyBoundStyle::Exact) | None => "==",
+    }
+}
+
+/// The `[tool.uv] add-bounds` value that matches the dependency bound style so packages added
+/// later with `uv add` get the same kind of constraint.
+fn uv_add_bounds(dependency_bound_style: &Option<DependencyBoundStyle>) -> Option<&'static str> {
+    match dependency_bound_style {
+        Some(DependencyBoundStyle::Exact) => Some("exact"),
+        Some(DependencyBoundStyle::Minimum) => Some("lower"),
+        Some(DependencyBoundStyle::Compatible) => Some("minor"),
+        None => None,
+    }
+}
+
+fn latest_dev_dependency_versions(project_info: &ProjectInfo) -> Result<Vec<PythonPackageVersion>> {
     let mut packages = if matches!(project_info.project_manager, ProjectManager::Maturin) {
         vec![PythonPackageVersion::new(PythonPackage::Maturin)]
     } else {
         Vec::new()
     };
 
+    if project_info.include_bandit {
+        packages.push(PythonPackageVersion::new(PythonPackage::Bandit));
+    }
+
     if project_info.include_docs {
         packages.push(PythonPackageVersion::new(PythonPackage::Mkdocs));
         packages.push(PythonPackageVersion::new(PythonPackage::MkdocsMaterial));
         packages.push(PythonPackageVersion::new(PythonPackage::Mkdocstrings));
     }
 
-    packages.push(PythonPackageVersion::new(PythonPackage::MyPy));
+    if project_info.type_checker == TypeChecker::Mypy {
+        packages.push(PythonPackageVersion::new(PythonPackage::MyPy));
+    }
+
     packages.push(PythonPackageVersion::new(PythonPackage::PreCommit));
     packages.push(PythonPackageVersion::new(PythonPackage::Pytest));
 
@@ -296,6 +525,10 @@ fn build_latest_dev_dependencies(project_info: &ProjectInfo) -> Result<String> {
         packages.push(PythonPackageVersion::new(PythonPackage::PytestAsyncio));
     }
 
+    if project_info.include_benchmarks {
+        packages.push(PythonPackageVersion::new(PythonPackage::PytestBenchmark));
+    }
+
     packages.push(PythonPackageVersion::new(PythonPackage::PytestCov));
     packages.push(PythonPackageVersion::new(PythonPackage::Ruff));
 
@@ -305,17 +538,67 @@ fn build_latest_dev_dependencies(project_info: &ProjectInfo) -> Result<String> {
         packages.push(PythonPackageVersion::new(PythonPackage::Tomli));
     }
 
-    if project_info.download_latest_packages {
-        packages.par_iter_mut().for_each(|package| {
-            if package.get_latest_version().is_err() {
-                let error_message = format!(
-                    "Error retrieving latest python package version for {}. Using default.",
-                    package.package
-                );
-                println!("\n{}", error_message.yellow());
-            }
+    if project_info.type_checker == TypeChecker::Ty {
+        packages.push(PythonPackageVersion::new(PythonPackage::Ty));
+    }
+
+    let overrides = &project_info.package_version_overrides;
+
+    if project_info.should_download_latest_packages() {
+        let cache = VersionCache::from_config().ok();
+        packages
+            .par_iter_mut()
+            .filter(|package| !overrides.contains_key(&package.package.to_string()))
+            .for_each(|package| {
+                if package
+                    .get_latest_version_with_retry(RETRY_ATTEMPTS, cache.as_ref())
+                    .is_err()
+                {
+                    let error_message = format!(
+                        "Error retrieving latest python package version for {}. Using default.",
+                        package.package
+                    );
+                    println!("\n{}", error_message.yellow());
+                }
+            })
+    }
+
+    for package in packages.iter_mut() {
+        if let Some(version) = overrides.get(&package.package.to_string()) {
+            package.version = version.clone();
+        }
+    }
+
+    Ok(packages)
+}
+
+/// The latest runtime and dev dependency versions. They are resolved once before the project
+/// files are written so the files that list the same packages don't each look them up.
+struct DependencyVersions {
+    dependencies: Vec<PythonPackageVersion>,
+    dev_dependencies: Vec<PythonPackageVersion>,
+}
+
+impl DependencyVersions {
+    fn resolve(project_info: &ProjectInfo) -> Result<Self> {
+        let (dependencies, dev_dependencies) = rayon::join(
+            || latest_dependency_versions(project_info),
+            || latest_dev_dependency_versions(project_info),
+        );
+
+        Ok(Self {
+            dependencies,
+            dev_dependencies: dev_dependencies?,
         })
     }
+}
+
+fn build_latest_dev_dependencies(
+    project_info: &ProjectInfo,
+    packages: &[PythonPackageVersion],
+) -> Result<String> {
+    let mut version_string = String::new();
+    let operator = dependency_bound_operator(&project_info.dependency_bound_style);
 
     if let ProjectManager::Uv | ProjectManager::Pixi = project_info.project_manager {
         version_string.push_str("[\n");
@@ -355,17 +638,17 @@ fn build_latest_dev_dependencies(project_info: &ProjectInfo) -> Result<String> {
             ProjectManager::Uv | ProjectManager::Pixi => {
                 if package.package == PythonPackage::MyPy {
                     version_string.push_str(&format!(
-                        "  \"{}[faster-cache]=={}\",\n",
+                        "  \"{}[faster-cache]{operator}{}\",\n",
                         package.package, package.version
                     ));
                 } else if package.package == PythonPackage::Mkdocstrings {
                     version_string.push_str(&format!(
-                        "  \"{}[python]=={}\",\n",
+                        "  \"{}[python]{operator}{}\",\n",
                         package.package, package.version
                     ));
                 } else {
                     version_string.push_str(&format!(
-                        "  \"{}=={}\",\n",
+                        "  \"{}{operator}{}\",\n",
                         package.package, package.version
                     ));
                 }
@@ -376,17 +659,17 @@ fn build_latest_dev_dependencies(project_info: &ProjectInfo) -> Result<String> {
                         Pyo3PythonManager::Uv => {
                             if package.package == PythonPackage::MyPy {
                                 version_string.push_str(&format!(
-                                    "  \"{}[faster-cache]=={}\",\n",
+                                    "  \"{}[faster-cache]{operator}{}\",\n",
                                     package.package, package.version
                                 ));
                             } else if package.package == PythonPackage::Mkdocstrings {
                                 version_string.push_str(&format!(
-                                    "  \"{}[python]=={}\",\n",
+                                    "  \"{}[python]{operator}{}\",\n",
                                     package.package, package.version
                                 ));
                             } else {
                                 version_string.push_str(&format!(
-                                    "  \"{}=={}\",\n",
+                                    "  \"{}{operator}{}\",\n",
                                     package.package, package.version
                                 ));
                             }
@@ -465,10 +748,41 @@ fn build_latest_dev_dependencies(project_info: &ProjectInfo) -> Result<String> {
     }
 }
 
-fn create_pyproject_toml(project_info: &ProjectInfo) -> Result<String> {
+/// Builds the ruff target version suffix from a Python version, dropping any caret and patch
+/// component so `^3.10` and `3.10.2` both become `310`.
+fn pyupgrade_version(python_version: &str) -> String {
+    python_version
+        .trim_start_matches('^')
+        .split('.')
+        .take(2)
+        .collect::<Vec<&str>>()
+        .join("")
+}
+
+fn create_pyproject_toml(
+    project_info: &ProjectInfo,
+    versions: &DependencyVersions,
+) -> Result<String> {
     let module = project_info.source_dir.replace([' ', '-'], "_");
-    let pyupgrade_version = &project_info.min_python_version.replace(['.', '^'], "");
+    let pyupgrade_version = pyupgrade_version(
+        project_info
+            .ruff_target_version
+            .as_ref()
+            .unwrap_or(&project_info.min_python_version),
+    );
     let license_text = license_str(&project_info.license);
+    let extra_ruff_rules: Vec<&String> = project_info
+        .extra_ruff_rules
+        .iter()
+        .filter(|rule| {
+            let is_async_rule = project_info.is_async_project && rule.as_str() == "ASYNC";
+            !DEFAULT_RUFF_RULES.contains(&rule.as_str()) && !is_async_rule
+        })
+        .collect();
+    let mypy_files = match &project_info.mypy_files {
+        Some(files) => files.clone(),
+        None => vec![module.clone(), "tests".to_string()],
+    };
     let mut pyproject = match &project_info.project_manager {
         ProjectManager::Maturin => {
             if let Some(pyo3_python_manager) = &project_info.pyo3_python_manager {
@@ -482,6 +796,9 @@ name = "{{ project_name }}"
 description = "{{ project_description }}"
 authors = [
   { name = "{{ creator }}", email = "{{ creator_email }}" },
+{% for author in additional_authors -%}
+  { name = "{{ author[0] }}", email = "{{ author[1] }}" },
+{% endfor -%}
 ]
 {% if license != "NoLicense" -%}
 license = { file = "LICENSE" }
@@ -489,15 +806,20 @@ license = { file = "LICENSE" }
 readme = "README.md"
 dynamic = ["version"]
 requires-python = ">={{ min_python_version }}"
-dependencies = []
+dependencies = {{ dependencies }}
 
 [dependency-groups]
 dev = {{ dev_dependencies }}
 
+{% if add_bounds -%}
+[tool.uv]
+add-bounds = "{{ add_bounds }}"
+
+{% endif -%}
 [tool.maturin]
 module-name = "{{ module }}._{{ module }}"
 binding = "pyo3"
-features = ["pyo3/extension-module"]
+features = ["pyo3/extension-module"{% if abi3_feature %}, "pyo3/{{ abi3_feature }}"{% endif %}]
 
 "#
                     .to_string(),
@@ -508,18 +830,18 @@ build-backend = "maturin"
 [project]
 name = "{{ project_name }}"
 description = "{{ project_description }}"
-authors = [{name = "{{ creator }}", email =  "{{ creator_email }}"}]
+authors = [{name = "{{ creator }}", email =  "{{ creator_email }}"}{% for author in additional_authors %}, {name = "{{ author[0] }}", email = "{{ author[1] }}"}{% endfor %}]
 {% if license != "NoLicense" -%}
 license = "{{ license }}"
 {% endif -%}
 readme = "README.md"
 dynamic = ["version"]
-dependencies = []
+dependencies = {{ dependencies }}
 
 [tool.maturin]
 module-name = "{{ module }}._{{ module }}"
 binding = "pyo3"
-features = ["pyo3/extension-module"]
+features = ["pyo3/extension-module"{% if abi3_feature %}, "pyo3/{{ abi3_feature }}"{% endif %}]
 
 "#
                     .to_string(),
@@ -532,7 +854,7 @@ features = ["pyo3/extension-module"]
 name = "{{ project_name }}"
 version = "{{ version }}"
 description = "{{ project_description }}"
-authors = ["{{ creator }} <{{ creator_email }}>"]
+authors = ["{{ creator }} <{{ creator_email }}>"{% for author in additional_authors %}, "{{ author[0] }} <{{ author[1] }}>"{% endfor %}]
 {% if license != "NoLicense" -%}
 license = "{{ license }}"
 {% endif -%}
@@ -540,6 +862,9 @@ readme = "README.md"
 
 [tool.poetry.dependencies]
 python = "^{{ min_python_version }}"
+{%- if dependencies %}
+{{ dependencies }}
+{%- endif %}
 
 [tool.poetry.group.dev.dependencies]
 {{ dev_dependencies }}
@@ -558,14 +883,15 @@ build-backend = "setuptools.build_meta"
 name = "{{ project_name }}"
 description = "{{ project_description }}"
 authors = [
-  { name = "{{ creator }}", email = "{{ creator_email }}" }
+  { name = "{{ creator }}", email = "{{ creator_email }}" }{% for author in additional_authors %},
+  { name = "{{ author[0] }}", email = "{{ author[1] }}" }{% endfor %}
 ]
 {% if license != "NoLicense" -%}
 license = { text = "{{ license }}" }
 {% endif -%}
 requires-python = ">={{ min_python_version }}"
 dynamic = ["version", "readme"]
-dependencies = []
+dependencies = {{ dependencies }}
 
 [tool.setuptools.dynamic]
 version = {attr = "{{ module }}.__version__"}
@@ -587,7 +913,8 @@ build-backend = "hatchling.build"
 name = "{{ project_name }}"
 description = "{{ project_description }}"
 authors = [
-  { name = "{{ creator }}", email = "{{ creator_email }}" }
+  { name = "{{ creator }}", email = "{{ creator_email }}" }{% for author in additional_authors %},
+  { name = "{{ author[0] }}", email = "{{ author[1] }}" }{% endfor %}
 ]
 {% if license != "NoLicense" -%}
 license = { file = "LICENSE" }
@@ -595,11 +922,16 @@ license = { file = "LICENSE" }
 readme = "README.md"
 requires-python = ">={{ min_python_version }}"
 dynamic = ["version"]
-dependencies = []
+dependencies = {{ dependencies }}
 
 [dependency-groups]
 dev = {{ dev_dependencies }}
 
+{% if add_bounds -%}
+[tool.uv]
+add-bounds = "{{ add_bounds }}"
+
+{% endif -%}
 [tool.hatch.version]
 path = "{{ module }}/_version.py"
 
@@ -613,7 +945,8 @@ build-backend = "hatchling.build"
 name = "{{ project_name }}"
 description = "{{ project_description }}"
 authors = [
-  { name = "{{ creator }}", email = "{{ creator_email }}" }
+  { name = "{{ creator }}", email = "{{ creator_email }}" }{% for author in additional_authors %},
+  { name = "{{ author[0] }}", email = "{{ author[1] }}" }{% endfor %}
 ]
 {% if license != "NoLicense" -%}
 license = { file = "LICENSE" }
@@ -621,17 +954,28 @@ license = { file = "LICENSE" }
 readme = "README.md"
 requires-python = ">={{ min_python_version }}"
 dynamic = ["version"]
-dependencies = []
+dependencies = {{ dependencies }}
 
 [tool.pixi.project]
 channels = ["conda-forge", "bioconda"]
 platforms = ["linux-64", "osx-arm64", "osx-64", "win-64"]
 
 [tool.pixi.feature.dev.tasks]
-run-mypy = "mypy {{ module }} tests"
+{% if type_checker == "Ty" -%}
+run-ty = "ty check {{ module }} tests"
+{% else -%}
+run-mypy = "mypy"
+{% endif -%}
 run-ruff-check = "ruff check {{ module }} tests"
 run-ruff-format = "ruff format {{ module }} tests"
 run-pytest = "pytest -x"
+run-coverage = "pytest --cov-report html"
+{% if include_bandit -%}
+run-bandit = "bandit -r {{ module }}"
+{% endif -%}
+{% if include_benchmarks -%}
+run-bench = "pytest benchmarks --benchmark-only --no-cov"
+{% endif -%}
 {% if include_docs -%}
 run-deploy-docs = "mkdocs gh-deploy --force"
 {%- endif %}
@@ -651,7 +995,9 @@ path = "{{ module }}/_version.py"
     };
 
     pyproject.push_str(
-        r#"[tool.mypy]
+        r#"{% if type_checker == "Mypy" -%}
+[tool.mypy]
+files = {{ mypy_files }}
 check_untyped_defs = true
 disallow_untyped_defs = true
 
@@ -659,18 +1005,25 @@ disallow_untyped_defs = true
 module = ["tests.*"]
 disallow_untyped_defs = false
 
+{% endif -%}
 [tool.pytest.ini_options]
 minversion = "6.0"
-addopts = "--cov={{ module }} --cov-report term-missing --no-cov-on-fail"
+addopts = "--cov={{ module }} --cov-report term-missing --no-cov-on-fail{% if min_coverage is not none %} --cov-fail-under={{ min_coverage }}{% endif %}"
 {%- if is_async_project %}
 asyncio_mode = "auto"
 {%- endif %}
 
-[tool.coverage.report]
+{% if ci_coverage_comment %}[tool.coverage.run]
+relative_files = true
+
+{% endif %}[tool.coverage.report]
 exclude_lines = ["if __name__ == .__main__.:", "pragma: no cover"]
+{%- if min_coverage is not none %}
+fail_under = {{ min_coverage }}
+{%- endif %}
 
 [tool.ruff]
-line-length = {{ max_line_length }}
+line-length = {{ ruff_line_length }}
 target-version = "py{{ pyupgrade_version }}"
 fix = true
 
@@ -688,7 +1041,10 @@ select = [
   "RUF023",  # Unforted __slots__
   {%- if is_async_project %}
   "ASYNC",  # flake8-async
-  {% endif %}
+  {%- endif %}
+  {%- for rule in extra_ruff_rules %}
+  "{{ rule }}",
+  {%- endfor %}
 ]
 ignore=[
   # Recommended ignores by ruff when using formatter
@@ -719,30 +1075,145 @@ ignore=[
         project_description => project_info.project_description,
         creator => project_info.creator,
         creator_email => project_info.creator_email,
+        additional_authors => project_info.additional_authors,
         license => license_text,
         min_python_version => project_info.min_python_version,
-        dev_dependencies => build_latest_dev_dependencies(project_info)?,
-        max_line_length => project_info.max_line_length,
+        dependencies => build_latest_dependencies(project_info, &versions.dependencies),
+        dev_dependencies => build_latest_dev_dependencies(project_info, &versions.dev_dependencies)?,
+        add_bounds => uv_add_bounds(&project_info.dependency_bound_style),
+        ruff_line_length => project_info
+            .ruff_line_length
+            .unwrap_or(project_info.max_line_length),
+        min_coverage => project_info.min_coverage,
+        ci_coverage_comment => project_info.ci_coverage_comment,
+        mypy_files => format!(
+            "[{}]",
+            mypy_files
+                .iter()
+                .map(|f| format!("\"{f}\""))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        type_checker => project_info.type_checker.to_string(),
+        abi3_feature => project_info
+            .use_abi3
+            .then(|| abi3_feature(&project_info.min_python_version)),
         module => module,
         is_application => project_info.is_application,
         is_async_project => project_info.is_async_project,
+        extra_ruff_rules => extra_ruff_rules,
+        include_bandit => project_info.include_bandit,
+        include_benchmarks => project_info.include_benchmarks,
         include_docs => project_info.include_docs,
         pyupgrade_version => pyupgrade_version,
     ))
 }
 
-fn save_pyproject_toml_file(project_info: &ProjectInfo) -> Result<()> {
+/// Catches template output that isn't valid TOML, for example from an unescaped value, before
+/// it is written.
+fn validate_toml(content: &str) -> Result<()> {
+    if let Err(e) = content.parse::<toml::Table>() {
+        let message = e.message().to_string();
+        match e.span() {
+            Some(span) => {
+                let line_number = content[..span.start].matches('\n').count() + 1;
+                let line = content.lines().nth(line_number - 1).unwrap_or_default();
+                bail!("Invalid TOML on line {line_number}, {message}: {line}");
+            }
+            None => bail!("Invalid TOML, {message}"),
+        }
+    }
+
+    Ok(())
+}
+
+fn save_pyproject_toml_file(
+    project_info: &ProjectInfo,
+    versions: &DependencyVersions,
+) -> Result<()> {
     let file_path = project_info.base_dir().join("pyproject.toml");
-    let content = create_pyproject_toml(project_info)?;
+    let content = create_pyproject_toml(project_info, versions)?;
+    validate_toml(&content)?;
+
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
+fn save_runtime_requirements(
+    project_info: &ProjectInfo,
+    versions: &DependencyVersions,
+) -> Result<()> {
+    let file_path = project_info.base_dir().join("requirements.txt");
+    let content: String = dependency_specifiers(project_info, &versions.dependencies)
+        .iter()
+        .map(|specifier| format!("{specifier}\n"))
+        .collect();
 
     save_file_with_content(&file_path, &content)?;
 
     Ok(())
 }
 
-fn save_dev_requirements(project_info: &ProjectInfo) -> Result<()> {
+/// The dev requirements include the runtime requirements so installing them sets up the full
+/// development environment.
+fn save_dev_requirements(project_info: &ProjectInfo, versions: &DependencyVersions) -> Result<()> {
     let file_path = project_info.base_dir().join("requirements-dev.txt");
-    let content = build_latest_dev_dependencies(project_info)?;
+    let content = format!(
+        "-r requirements.txt\n{}",
+        build_latest_dev_dependencies(project_info, &versions.dev_dependencies)?
+    );
+
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
+/// Conda can't install extras so packages that need them are installed with pip, along with the
+/// project itself.
+fn create_conda_environment_file(
+    project_info: &ProjectInfo,
+    versions: &DependencyVersions,
+) -> Result<String> {
+    let mut conda_packages = String::new();
+    let mut pip_packages = String::new();
+
+    for package in &versions.dev_dependencies {
+        match package.package {
+            PythonPackage::MyPy => pip_packages.push_str(&format!(
+                "      - {}[faster-cache]=={}\n",
+                package.package, package.version
+            )),
+            PythonPackage::Mkdocstrings => pip_packages.push_str(&format!(
+                "      - {}[python]=={}\n",
+                package.package, package.version
+            )),
+            _ => {
+                conda_packages.push_str(&format!("  - {}=={}\n", package.package, package.version))
+            }
+        }
+    }
+
+    Ok(format!(
+        r#"name: {}
+channels:
+  - conda-forge
+dependencies:
+  - python>={}
+{}  - pip
+  - pip:
+{}      - -e .
+"#,
+        project_info.project_slug, project_info.min_python_version, conda_packages, pip_packages
+    ))
+}
+
+fn save_conda_environment_file(
+    project_info: &ProjectInfo,
+    versions: &DependencyVersions,
+) -> Result<()> {
+    let file_path = project_info.base_dir().join("environment.yml");
+    let content = create_conda_environment_file(project_info, versions)?;
 
     save_file_with_content(&file_path, &content)?;
 
@@ -789,7 +1260,7 @@ plugins:
 "#,
             docs_info.site_name,
             docs_info.site_description,
-            docs_info.site_url,
+            docs_info.resolved_site_url()?,
             docs_info.locale,
             docs_info.repo_name,
             docs_info.repo_url,
@@ -808,12 +1279,15 @@ fn save_mkdocs_yaml(project_info: &ProjectInfo) -> Result<()> {
     Ok(())
 }
 
+/// A CNAME file is only needed for a custom domain, without one GitHub Pages uses its default url.
 fn save_docs_cname(project_info: &ProjectInfo) -> Result<()> {
     if let Some(docs_info) = &project_info.docs_info {
-        let file_path = project_info.base_dir().join("docs/CNAME");
-        let content = format!("{}\n", &docs_info.site_url);
+        if let Some(site_url) = &docs_info.site_url {
+            let file_path = project_info.base_dir().join("docs/CNAME");
+            let content = format!("{}\n", site_url);
 
-        save_file_with_content(&file_path, &content)?;
+            save_file_with_content(&file_path, &content)?;
+        }
 
         Ok(())
     } else {
@@ -862,21 +1336,38 @@ fn save_docs_css(project_info: &ProjectInfo) -> Result<()> {
     Ok(())
 }
 
-fn create_poetry_justfile(module: &str) -> String {
+/// The lint recipe calls the type check recipe by name so the name follows the type checker.
+fn create_type_check_recipe(
+    type_checker: &TypeChecker,
+    mypy_command: &str,
+    ty_command: &str,
+) -> (&'static str, String) {
+    match type_checker {
+        TypeChecker::Mypy => ("mypy", mypy_command.to_string()),
+        TypeChecker::Ty => ("ty", ty_command.to_string()),
+    }
+}
+
+fn create_poetry_justfile(module: &str, type_checker: &TypeChecker) -> String {
+    let (type_check, type_check_command) = create_type_check_recipe(
+        type_checker,
+        "poetry run mypy",
+        &format!("poetry run ty check {module} tests"),
+    );
     format!(
         r#"@_default:
   just --list
 
 @lint:
-  echo mypy
-  just --justfile {{{{justfile()}}}} mypy
+  echo {type_check}
+  just --justfile {{{{justfile()}}}} {type_check}
   echo ruff-check
   just --justfile {{{{justfile()}}}} ruff-check
   echo ruff-format
   just --justfile {{{{justfile()}}}} ruff-format
 
-@mypy:
-  poetry run mypy {module} tests
+@{type_check}:
+  {type_check_command}
 
 @ruff-check:
   poetry run ruff check {module} tests
@@ -887,15 +1378,30 @@ fn create_poetry_justfile(module: &str) -> String {
 @test *args="":
   -poetry run pytest {{{{args}}}}
 
+@coverage:
+  poetry run pytest --cov-report html
+  echo "Coverage report written to htmlcov/index.html"
+
 @install:
   poetry install
 "#
     )
 }
 
-fn create_pyo3_justfile(module: &str, pyo3_python_manager: &Pyo3PythonManager) -> String {
+fn create_pyo3_justfile(
+    module: &str,
+    pyo3_python_manager: &Pyo3PythonManager,
+    type_checker: &TypeChecker,
+    uv_frozen: bool,
+) -> String {
     match pyo3_python_manager {
         Pyo3PythonManager::Uv => {
+            let uv_sync = uv_sync_command(uv_frozen);
+            let (type_check, type_check_command) = create_type_check_recipe(
+                type_checker,
+                "uv run mypy",
+                &format!("uv run ty check {module} tests"),
+            );
             format!(
                 r#"@_default:
   just --list
@@ -913,10 +1419,10 @@ fn create_pyo3_justfile(module: &str, pyo3_python_manager: &Pyo3PythonManager) -
   uv run maturin develop -r --uv
 
 @install: && develop
-  uv sync --frozen --all-extras
+  {uv_sync} --all-extras
 
 @install-release: && develop-release
-  uv sync --frozen --all-extras
+  {uv_sync} --all-extras
 
 @lint:
   echo cargo check
@@ -925,8 +1431,8 @@ fn create_pyo3_justfile(module: &str, pyo3_python_manager: &Pyo3PythonManager) -
   just --justfile {{{{justfile()}}}} clippy
   echo cargo fmt
   just --justfile {{{{justfile()}}}} fmt
-  echo mypy
-  just --justfile {{{{justfile()}}}} mypy
+  echo {type_check}
+  just --justfile {{{{justfile()}}}} {type_check}
   echo ruff check
   just --justfile {{{{justfile()}}}} ruff-check
   echo ruff formatting
@@ -941,8 +1447,8 @@ fn create_pyo3_justfile(module: &str, pyo3_python_manager: &Pyo3PythonManager) -
 @fmt:
   cargo fmt --all -- --check
 
-@mypy:
-  uv run mypy {module} tests
+@{type_check}:
+  {type_check_command}
 
 @ruff-check:
   uv run ruff check {module} tests --fix
@@ -952,10 +1458,16 @@ fn create_pyo3_justfile(module: &str, pyo3_python_manager: &Pyo3PythonManager) -
 
 @test *args="":
   uv run pytest {{{{args}}}}
+
+@coverage:
+  uv run pytest --cov-report html
+  echo "Coverage report written to htmlcov/index.html"
 "#
             )
         }
         Pyo3PythonManager::Setuptools => {
+            let (type_check, type_check_command) =
+                create_type_check_recipe(type_checker, "mypy", &format!("ty check {module} tests"));
             format!(
                 r#"@_default:
   just --list
@@ -979,8 +1491,8 @@ fn create_pyo3_justfile(module: &str, pyo3_python_manager: &Pyo3PythonManager) -
   just --justfile {{{{justfile()}}}} clippy
   echo cargo fmt
   just --justfile {{{{justfile()}}}} fmt
-  echo mypy
-  just --justfile {{{{justfile()}}}} mypy
+  echo {type_check}
+  just --justfile {{{{justfile()}}}} {type_check}
   echo ruff check
   just --justfile {{{{justfile()}}}} ruff-check
   echo ruff formatting
@@ -995,8 +1507,8 @@ fn create_pyo3_justfile(module: &str, pyo3_python_manager: &Pyo3PythonManager) -
 @fmt:
   cargo fmt --all -- --check
 
-@mypy:
-  mypy {module} tests
+@{type_check}:
+  {type_check_command}
 
 @ruff-check:
   ruff check {module} tests --fix
@@ -1006,27 +1518,36 @@ fn create_pyo3_justfile(module: &str, pyo3_python_manager: &Pyo3PythonManager) -
 
 @test *arg="":
   pytest {{{{args}}}}
+
+@coverage:
+  pytest --cov-report html
+  echo "Coverage report written to htmlcov/index.html"
 "#
             )
         }
     }
 }
 
-fn create_setuptools_justfile(module: &str) -> String {
+fn create_setuptools_justfile(module: &str, type_checker: &TypeChecker) -> String {
+    let (type_check, type_check_command) = create_type_check_recipe(
+        type_checker,
+        "python -m mypy",
+        &format!("python -m ty check {module} tests"),
+    );
     format!(
         r#"@_default:
   just --list
 
 @lint:
-  echo mypy
-  just --justfile {{{{justfile()}}}} mypy
+  echo {type_check}
+  just --justfile {{{{justfile()}}}} {type_check}
   echo ruff-check
   just --justfile {{{{justfile()}}}} ruff-check
   echo ruff-format
   just --justfile {{{{justfile()}}}} ruff-format
 
-@mypy:
-  python -m mypy {module} tests
+@{type_check}:
+  {type_check_command}
 
 @ruff-check:
   python -m ruff check {module} tests
@@ -1037,27 +1558,37 @@ fn create_setuptools_justfile(module: &str) -> String {
 @test *args="":
   -python -m pytest {{{{args}}}}
 
+@coverage:
+  python -m pytest --cov-report html
+  echo "Coverage report written to htmlcov/index.html"
+
 @install:
   python -m pip install -r requirements-dev.txt
 "#
     )
 }
 
-fn create_uv_justfile(module: &str) -> String {
+fn create_uv_justfile(module: &str, type_checker: &TypeChecker, uv_frozen: bool) -> String {
+    let (type_check, type_check_command) = create_type_check_recipe(
+        type_checker,
+        "uv run mypy",
+        &format!("uv run ty check {module} tests"),
+    );
+    let uv_sync = uv_sync_command(uv_frozen);
     format!(
         r#"@_default:
   just --list
 
 @lint:
-  echo mypy
-  just --justfile {{{{justfile()}}}} mypy
+  echo {type_check}
+  just --justfile {{{{justfile()}}}} {type_check}
   echo ruff-check
   just --justfile {{{{justfile()}}}} ruff-check
   echo ruff-format
   just --justfile {{{{justfile()}}}} ruff-format
 
-@mypy:
-  uv run mypy {module} tests
+@{type_check}:
+  {type_check_command}
 
 @ruff-check:
   uv run ruff check {module} tests
@@ -1068,6 +1599,10 @@ fn create_uv_justfile(module: &str) -> String {
 @test *args="":
   -uv run pytest {{{{args}}}}
 
+@coverage:
+  uv run pytest --cov-report html
+  echo "Coverage report written to htmlcov/index.html"
+
 @lock:
   uv lock
 
@@ -1075,25 +1610,28 @@ fn create_uv_justfile(module: &str) -> String {
   uv lock --upgrade
 
 @install:
-  uv sync --frozen --all-extras
+  {uv_sync} --all-extras
 "#
     )
 }
 
-fn create_pixi_justfile() -> String {
-    (r#"@_default:
+fn create_pixi_justfile(type_checker: &TypeChecker) -> String {
+    let (type_check, type_check_command) =
+        create_type_check_recipe(type_checker, "pixi run run-mypy", "pixi run run-ty");
+    format!(
+        r#"@_default:
   just --list
 
 @lint:
-  echo mypy
-  just --justfile {{{{justfile()}}}} mypy
+  echo {type_check}
+  just --justfile {{{{justfile()}}}} {type_check}
   echo ruff-check
   just --justfile {{{{justfile()}}}} ruff-check
   echo ruff-format
   just --justfile {{{{justfile()}}}} ruff-format
 
-@mypy:
-  pixi run run-mypy
+@{type_check}:
+  {type_check_command}
 
 @ruff-check:
   pixi run run-ruff-check
@@ -1104,358 +1642,1929 @@ fn create_pixi_justfile() -> String {
 @test:
   -pixi run run-pytest
 
+@coverage:
+  pixi run run-coverage
+  echo "Coverage report written to htmlcov/index.html"
+
 @install:
   pixi install
-"#)
-    .to_string()
+"#
+    )
+}
+
+fn create_security_recipe(project_info: &ProjectInfo, module: &str) -> String {
+    let bandit_command = match &project_info.project_manager {
+        ProjectManager::Poetry => format!("poetry run bandit -r {module}"),
+        ProjectManager::Maturin => match &project_info.pyo3_python_manager {
+            Some(Pyo3PythonManager::Uv) => format!("uv run bandit -r {module}"),
+            _ => format!("bandit -r {module}"),
+        },
+        ProjectManager::Setuptools => format!("python -m bandit -r {module}"),
+        ProjectManager::Uv => format!("uv run bandit -r {module}"),
+        ProjectManager::Pixi => "pixi run run-bandit".to_string(),
+    };
+
+    format!("\n@security:\n  {bandit_command}\n")
+}
+
+/// Coverage is turned off because the benchmarks don't exercise the whole project and would fail
+/// a minimum coverage check.
+fn create_bench_recipe(project_info: &ProjectInfo) -> String {
+    let pytest_args = "benchmarks --benchmark-only --no-cov";
+    let bench_command = match &project_info.project_manager {
+        ProjectManager::Poetry => format!("poetry run pytest {pytest_args}"),
+        ProjectManager::Maturin => match &project_info.pyo3_python_manager {
+            Some(Pyo3PythonManager::Uv) => format!("uv run pytest {pytest_args}"),
+            _ => format!("pytest {pytest_args}"),
+        },
+        ProjectManager::Setuptools => format!("python -m pytest {pytest_args}"),
+        ProjectManager::Uv => format!("uv run pytest {pytest_args}"),
+        ProjectManager::Pixi => "pixi run run-bench".to_string(),
+    };
+
+    format!("\n@bench:\n  {bench_command}\n")
 }
 
 fn save_justfile(project_info: &ProjectInfo) -> Result<()> {
     let module = project_info.source_dir.replace([' ', '-'], "_");
     let file_path = project_info.base_dir().join("justfile");
-    let content = match &project_info.project_manager {
-        ProjectManager::Poetry => create_poetry_justfile(&module),
+    let mut content = match &project_info.project_manager {
+        ProjectManager::Poetry => create_poetry_justfile(&module, &project_info.type_checker),
         ProjectManager::Maturin => {
             if let Some(pyo3_python_manager) = &project_info.pyo3_python_manager {
-                create_pyo3_justfile(&module, pyo3_python_manager)
+                create_pyo3_justfile(
+                    &module,
+                    pyo3_python_manager,
+                    &project_info.type_checker,
+                    project_info.uv_sync_frozen(),
+                )
             } else {
                 bail!("A PyO3 Python manager is required for maturin");
             }
         }
-        ProjectManager::Setuptools => create_setuptools_justfile(&module),
-        ProjectManager::Uv => create_uv_justfile(&module),
-        ProjectManager::Pixi => create_pixi_justfile(),
+        ProjectManager::Setuptools => {
+            create_setuptools_justfile(&module, &project_info.type_checker)
+        }
+        ProjectManager::Uv => create_uv_justfile(
+            &module,
+            &project_info.type_checker,
+            project_info.uv_sync_frozen(),
+        ),
+        ProjectManager::Pixi => create_pixi_justfile(&project_info.type_checker),
     };
 
+    if project_info.include_bandit {
+        content.push_str(&create_security_recipe(project_info, &module));
+    }
+
+    if project_info.include_benchmarks {
+        content.push_str(&create_bench_recipe(project_info));
+    }
+
     save_file_with_content(&file_path, &content)?;
 
     Ok(())
 }
 
-fn create_readme_file(project_name: &str, project_description: &str) -> String {
-    format!(
-        r#"# {project_name}
+/// Documents the justfile recipes so the commands match what was generated for the project
+/// manager.
+fn create_contributing_file(project_info: &ProjectInfo) -> Result<String> {
+    let (manager_name, manager_url, pre_commit_install) = match &project_info.project_manager {
+        ProjectManager::Poetry => (
+            "Poetry",
+            "https://python-poetry.org/docs/#installation",
+            "poetry run pre-commit install",
+        ),
+        ProjectManager::Uv => (
+            "uv",
+            "https://docs.astral.sh/uv/getting-started/installation/",
+            "uv run pre-commit install",
+        ),
+        ProjectManager::Pixi => (
+            "Pixi",
+            "https://pixi.sh/latest/#installation",
+            "pixi run -e dev pre-commit install",
+        ),
+        ProjectManager::Setuptools => (
+            "pip",
+            "https://pip.pypa.io/en/stable/installation/",
+            "pre-commit install",
+        ),
+        ProjectManager::Maturin => match &project_info.pyo3_python_manager {
+            Some(Pyo3PythonManager::Uv) => (
+                "uv",
+                "https://docs.astral.sh/uv/getting-started/installation/",
+                "uv run pre-commit install",
+            ),
+            Some(Pyo3PythonManager::Setuptools) => (
+                "pip",
+                "https://pip.pypa.io/en/stable/installation/",
+                "pre-commit install",
+            ),
+            None => bail!("A PyO3 Python manager is required for maturin"),
+        },
+    };
+    let is_maturin = matches!(project_info.project_manager, ProjectManager::Maturin);
 
-{project_description}
-"#
-    )
-}
+    let mut contributing = format!(
+        r#"# Contributing
 
-fn save_readme_file(project_info: &ProjectInfo) -> Result<()> {
-    let file_path = project_info.base_dir().join("README.md");
-    let content = create_readme_file(
-        &project_info.project_name,
-        &project_info.project_description,
-    );
-    save_file_with_content(&file_path, &content)?;
+Contributions are welcome. This guide covers setting up a development environment and the checks
+that need to pass before a pull request is merged.
 
-    Ok(())
-}
+## Prerequisites
 
-pub fn generate_project(project_info: &ProjectInfo) -> Result<()> {
-    if create_directories(project_info).is_err() {
-        bail!("Error creating project directories");
-    }
+- [{manager_name}]({manager_url}) to manage the Python environment
+- [just](https://github.com/casey/just) to run the project commands
+"#
+    );
 
-    if save_gitigngore_file(project_info).is_err() {
-        bail!("Error creating .gitignore file");
+    if is_maturin {
+        contributing.push_str(
+            "- [Rust](https://rustup.rs) to build the extension module
+",
+        );
     }
 
-    if save_pre_commit_file(project_info).is_err() {
-        bail!("Error creating .gitignore file");
-    }
+    contributing.push_str(
+        r#"
+## Setup
 
-    if save_readme_file(project_info).is_err() {
-        bail!("Error creating README.md file");
-    }
+Install the project and the development dependencies:
+
+```sh
+just install
+```
+"#,
+    );
 
-    generate_license(project_info)?;
+    if is_maturin {
+        contributing.push_str(
+            r#"
+After changing Rust code rebuild the extension module:
 
-    if save_empty_src_file(project_info, "py.typed").is_err() {
-        bail!("Error creating py.typed file");
+```sh
+just develop
+```
+"#,
+        );
     }
 
-    generate_python_files(project_info)?;
+    contributing.push_str(&format!(
+        r#"
+Then install the pre-commit hooks so the checks run on each commit:
 
-    if save_pyproject_toml_file(project_info).is_err() {
-        bail!("Error creating pyproject.toml file");
-    }
+```sh
+{pre_commit_install}
+```
 
-    if save_justfile(project_info).is_err() {
-        bail!("Error creating justfile");
-    }
+## Linting
 
-    match &project_info.project_manager {
-        ProjectManager::Maturin => {
-            if let Some(pyo3_python_manager) = &project_info.pyo3_python_manager {
-                if pyo3_python_manager == &Pyo3PythonManager::Setuptools
-                    && save_dev_requirements(project_info).is_err()
-                {
-                    bail!("Error creating requirements-dev.txt file");
-                }
+Run all of the linters and the type checker:
 
-                if save_lib_file(project_info).is_err() {
-                    bail!("Error creating Rust lib.rs file");
-                }
+```sh
+just lint
+```
+"#
+    ));
 
-                if save_cargo_toml_file(project_info).is_err() {
-                    bail!("Error creating Rust lib.rs file");
-                }
-            } else {
-                bail!("A PyO3 Python Manager is required with Maturin");
-            }
-        }
-        ProjectManager::Setuptools => {
-            if save_dev_requirements(project_info).is_err() {
-                bail!("Error creating requirements-dev.txt file");
-            }
-        }
-        _ => (),
-    }
+    if project_info.include_bandit {
+        contributing.push_str(
+            r#"
+Check for common security issues with bandit:
 
-    if project_info.use_continuous_deployment && save_pypi_publish_file(project_info).is_err() {
-        bail!("Error creating PyPI publish file");
+```sh
+just security
+```
+"#,
+        );
     }
 
-    if project_info.include_docs && save_docs_publish_file(project_info).is_err() {
-        bail!("Error creating docs publish file");
-    }
+    contributing.push_str(
+        r#"
+## Testing
 
-    if project_info.use_multi_os_ci {
-        if save_ci_testing_multi_os_file(project_info).is_err() {
-            bail!("Error creating CI teesting file");
+Run the test suite:
+
+```sh
+just test
+```
+
+Write an HTML coverage report to `htmlcov/index.html`:
+
+```sh
+just coverage
+```
+"#,
+    );
+
+    Ok(contributing)
+}
+
+fn save_contributing_file(project_info: &ProjectInfo) -> Result<()> {
+    let file_path = project_info.base_dir().join("CONTRIBUTING.md");
+    let content = create_contributing_file(project_info)?;
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
+/// Only the latest minor release is listed as supported since that is where fixes are released.
+fn create_security_file(project_info: &ProjectInfo) -> String {
+    let supported_version = match project_info.version.rsplit_once('.') {
+        Some((major_minor, _)) => format!("{major_minor}.x"),
+        None => project_info.version.clone(),
+    };
+
+    format!(
+        r#"# Security Policy
+
+## Supported Versions
+
+Only the latest release receives security fixes.
+
+| Version | Supported          |
+| ------- | ------------------ |
+| {supported_version} | :white_check_mark: |
+
+## Reporting a Vulnerability
+
+Please do not report security vulnerabilities through public GitHub issues. Instead, send an email
+to {creator_email} with a description of the issue, the steps to reproduce it, and the affected
+versions. You should receive a response within a few days, and a fix will be released as soon as
+possible once the issue is confirmed.
+"#,
+        creator_email = project_info.creator_email,
+    )
+}
+
+fn save_security_file(project_info: &ProjectInfo) -> Result<()> {
+    let file_path = project_info.base_dir().join("SECURITY.md");
+    let content = create_security_file(project_info);
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
+fn create_changelog_file(use_release_drafter: bool) -> String {
+    let mut changelog = r#"# Changelog
+
+All notable changes to this project will be documented in this file.
+
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/), and this project
+adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).
+"#
+    .to_string();
+
+    if use_release_drafter {
+        changelog.push_str(
+            r#"
+GitHub release notes are drafted by release-drafter from merged pull requests. Copy the notes into
+this file when a release is published.
+"#,
+        );
+    }
+
+    changelog.push_str(
+        r#"
+## [Unreleased]
+
+### Added
+
+- Initial project structure
+"#,
+    );
+
+    changelog
+}
+
+fn save_changelog_file(project_info: &ProjectInfo) -> Result<()> {
+    let file_path = project_info.base_dir().join("CHANGELOG.md");
+    let content = create_changelog_file(project_info.use_release_drafter);
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
+fn cff_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// CFF wants a person's name split into given and family names. The last word is used as the
+/// family name, and a single word name is written as a name instead.
+fn create_cff_author(name: &str, email: &str) -> String {
+    let name_lines = match name.trim().rsplit_once(' ') {
+        Some((given_names, family_names)) => format!(
+            "  - family-names: {}\n    given-names: {}\n",
+            cff_string(family_names),
+            cff_string(given_names.trim())
+        ),
+        None => format!("  - name: {}\n", cff_string(name.trim())),
+    };
+
+    format!("{name_lines}    email: {}\n", cff_string(email))
+}
+
+fn create_citation_file(project_info: &ProjectInfo) -> String {
+    let mut citation = format!(
+        "cff-version: 1.2.0\nmessage: \"If you use this software, please cite it as below.\"\ntitle: {}\nversion: {}\n",
+        cff_string(&project_info.project_name),
+        cff_string(&project_info.version)
+    );
+
+    if !project_info.project_description.trim().is_empty() {
+        citation.push_str("abstract: ");
+        citation.push_str(&cff_string(&project_info.project_description));
+        citation.push('\n');
+    }
+
+    citation.push_str("authors:\n");
+    citation.push_str(&create_cff_author(
+        &project_info.creator,
+        &project_info.creator_email,
+    ));
+    for (name, email) in &project_info.additional_authors {
+        citation.push_str(&create_cff_author(name, email));
+    }
+
+    if project_info.license != LicenseType::NoLicense {
+        citation.push_str(&format!(
+            "license: {}\n",
+            license_str(&project_info.license)
+        ));
+    }
+
+    citation
+}
+
+fn save_citation_file(project_info: &ProjectInfo) -> Result<()> {
+    let file_path = project_info.base_dir().join("CITATION.cff");
+    let content = create_citation_file(project_info);
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
+fn create_readme_file(project_name: &str, project_description: &str) -> String {
+    format!(
+        r#"# {project_name}
+
+{project_description}
+"#
+    )
+}
+
+fn create_manifest_in(project_info: &ProjectInfo) -> String {
+    let module = project_info.source_dir.replace([' ', '-'], "_");
+    let mut manifest = String::new();
+
+    if project_info.license != LicenseType::NoLicense {
+        manifest.push_str("include LICENSE\n");
+    }
+
+    manifest.push_str(&format!("include README.md\ninclude {module}/py.typed\n"));
+
+    manifest
+}
+
+/// Setuptools doesn't include non-Python files in the sdist unless they are listed in MANIFEST.in.
+fn save_manifest_in(project_info: &ProjectInfo) -> Result<()> {
+    let file_path = project_info.base_dir().join("MANIFEST.in");
+    let content = create_manifest_in(project_info);
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
+fn save_readme_file(project_info: &ProjectInfo) -> Result<()> {
+    let file_path = project_info.base_dir().join("README.md");
+    let content = create_readme_file(
+        &project_info.project_name,
+        &project_info.project_description,
+    );
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
+/// How long each phase of `generate_project` took. Steps within a phase run in parallel so a
+/// phase's time is the wall clock time from its first step starting to its last step finishing.
+/// Phases overlap so their times don't add up to the total.
+#[derive(Debug, Default)]
+pub struct GenerationTimings {
+    /// The phase name with when it started and finished, relative to the start of generation.
+    phases: Vec<(&'static str, Duration, Duration)>,
+    total: Duration,
+}
+
+impl GenerationTimings {
+    /// Phases that have steps in more than one place are combined and kept in the order they
+    /// first ran.
+    fn add(&mut self, phase: &'static str, started: Duration, finished: Duration) {
+        match self.phases.iter_mut().find(|(name, _, _)| *name == phase) {
+            Some((_, phase_started, phase_finished)) => {
+                *phase_started = (*phase_started).min(started);
+                *phase_finished = (*phase_finished).max(finished);
+            }
+            None => self.phases.push((phase, started, finished)),
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    pub fn report(&self) -> String {
+        let mut report = format!("Generated in {:.2}s", self.total().as_secs_f64());
+
+        for (phase, started, finished) in &self.phases {
+            let duration = finished.saturating_sub(*started);
+            report.push_str(&format!("\n  {phase}: {:.2}s", duration.as_secs_f64()));
+        }
+
+        report
+    }
+}
+
+type StepRun<'a> = Box<dyn Fn(&ProjectInfo) -> Result<()> + Sync + 'a>;
+
+/// A file that only depends on the project directories existing and the resolved dependency
+/// versions, so it can be written in parallel with the other files.
+struct GenerationStep<'a> {
+    phase: &'static str,
+    error: Option<&'static str>,
+    run: StepRun<'a>,
+}
+
+impl<'a> GenerationStep<'a> {
+    fn new(
+        phase: &'static str,
+        error: &'static str,
+        run: impl Fn(&ProjectInfo) -> Result<()> + Sync + 'a,
+    ) -> Self {
+        Self {
+            phase,
+            error: Some(error),
+            run: Box::new(run),
+        }
+    }
+
+    /// The step's own error is returned instead of a generic message.
+    fn with_own_error(
+        phase: &'static str,
+        run: impl Fn(&ProjectInfo) -> Result<()> + Sync + 'a,
+    ) -> Self {
+        Self {
+            phase,
+            error: None,
+            run: Box::new(run),
+        }
+    }
+}
+
+fn generation_steps<'a>(
+    project_info: &ProjectInfo,
+    versions: &'a DependencyVersions,
+) -> Result<Vec<GenerationStep<'a>>> {
+    let mut steps = vec![
+        GenerationStep::new(
+            "project files",
+            "Error creating .gitignore file",
+            save_gitigngore_file,
+        ),
+        GenerationStep::new(
+            "project files",
+            "Error creating .pre-commit-config.yaml file",
+            save_pre_commit_file,
+        ),
+        GenerationStep::new(
+            "project files",
+            "Error creating README.md file",
+            save_readme_file,
+        ),
+        GenerationStep::with_own_error("project files", generate_license),
+        GenerationStep::new("project files", "Error creating py.typed file", |p| {
+            save_empty_src_file(p, "py.typed")
+        }),
+        GenerationStep::with_own_error("python files", generate_python_files),
+        GenerationStep::with_own_error("pyproject.toml", |p| {
+            match save_pyproject_toml_file(p, versions) {
+                Ok(_) => Ok(()),
+                Err(e) => bail!("Error creating pyproject.toml file: {e}"),
+            }
+        }),
+        GenerationStep::new("project files", "Error creating justfile", save_justfile),
+    ];
+
+    if project_info.include_contributing {
+        steps.push(GenerationStep::new(
+            "project files",
+            "Error creating CONTRIBUTING.md file",
+            save_contributing_file,
+        ));
+    }
+
+    if project_info.include_security_policy {
+        steps.push(GenerationStep::new(
+            "project files",
+            "Error creating SECURITY.md file",
+            save_security_file,
+        ));
+    }
+
+    if project_info.include_changelog {
+        steps.push(GenerationStep::new(
+            "project files",
+            "Error creating CHANGELOG.md file",
+            save_changelog_file,
+        ));
+    }
+
+    if project_info.include_citation {
+        steps.push(GenerationStep::new(
+            "project files",
+            "Error creating CITATION.cff file",
+            save_citation_file,
+        ));
+    }
+
+    let uses_requirements_file = match &project_info.project_manager {
+        ProjectManager::Maturin => match &project_info.pyo3_python_manager {
+            Some(pyo3_python_manager) => pyo3_python_manager == &Pyo3PythonManager::Setuptools,
+            None => bail!("A PyO3 Python Manager is required with Maturin"),
+        },
+        ProjectManager::Setuptools => true,
+        _ => false,
+    };
+
+    if uses_requirements_file {
+        steps.push(GenerationStep::new(
+            "project files",
+            "Error creating requirements.txt file",
+            |p| save_runtime_requirements(p, versions),
+        ));
+        steps.push(GenerationStep::new(
+            "project files",
+            "Error creating requirements-dev.txt file",
+            |p| save_dev_requirements(p, versions),
+        ));
+        steps.push(GenerationStep::new(
+            "project files",
+            "Error creating MANIFEST.in file",
+            save_manifest_in,
+        ));
+    }
+
+    if let ProjectManager::Maturin = &project_info.project_manager {
+        steps.push(GenerationStep::new(
+            "project files",
+            "Error creating Rust lib.rs file",
+            save_lib_file,
+        ));
+        steps.push(GenerationStep::new(
+            "project files",
+            "Error creating Cargo.toml file",
+            save_cargo_toml_file,
+        ));
+
+        if project_info.include_rust_toolchain {
+            steps.push(GenerationStep::new(
+                "project files",
+                "Error creating rust-toolchain.toml file",
+                save_rust_toolchain_file,
+            ));
+        }
+    }
+
+    if project_info.project_manager == ProjectManager::Pixi && project_info.include_conda_env {
+        steps.push(GenerationStep::new(
+            "project files",
+            "Error creating environment.yml file",
+            |p| save_conda_environment_file(p, versions),
+        ));
+    }
+
+    if project_info.use_continuous_deployment {
+        steps.push(GenerationStep::new(
+            "CI",
+            "Error creating PyPI publish file",
+            save_pypi_publish_file,
+        ));
+    }
+
+    if project_info.use_continuous_deployment && project_info.include_testpypi_publish {
+        steps.push(GenerationStep::new(
+            "CI",
+            "Error creating TestPyPI publish file",
+            save_testpypi_publish_file,
+        ));
+    }
+
+    if project_info.include_docs {
+        steps.push(GenerationStep::new(
+            "CI",
+            "Error creating docs publish file",
+            save_docs_publish_file,
+        ));
+    }
+
+    if project_info.use_multi_os_ci {
+        steps.push(GenerationStep::new(
+            "CI",
+            "Error creating CI testing file",
+            save_ci_testing_multi_os_file,
+        ));
+    } else {
+        steps.push(GenerationStep::new(
+            "CI",
+            "Error creating CI testing file",
+            save_ci_testing_linux_only_file,
+        ));
+    }
+
+    if project_info.include_docs {
+        steps.push(GenerationStep::new(
+            "docs",
+            "Error creating mkdocs.yml file",
+            save_mkdocs_yaml,
+        ));
+        steps.push(GenerationStep::new(
+            "docs",
+            "Error creating CNAME file for docs",
+            save_docs_cname,
+        ));
+        steps.push(GenerationStep::new(
+            "docs",
+            "Error index.md file for docs",
+            save_docs_index_md,
+        ));
+        steps.push(GenerationStep::new(
+            "docs",
+            "Error saving docs css file",
+            save_docs_css,
+        ));
+    }
+
+    if project_info.use_dependabot {
+        steps.push(GenerationStep::new(
+            "CI",
+            "Error creating dependabot file",
+            save_dependabot_file,
+        ));
+    }
+
+    if project_info.use_release_drafter {
+        steps.push(GenerationStep::new(
+            "CI",
+            "Error creating release drafter file",
+            save_release_drafter_file,
+        ));
+    }
+
+    if project_info.include_codeowners {
+        steps.push(GenerationStep::new(
+            "CI",
+            "Error creating CODEOWNERS file",
+            save_codeowners_file,
+        ));
+    }
+
+    if project_info.include_github_templates {
+        steps.push(GenerationStep::new(
+            "CI",
+            "Error creating GitHub issue and pull request templates",
+            save_github_templates,
+        ));
+    }
+
+    if project_info.cloud_provider.is_some() {
+        steps.push(GenerationStep::new(
+            "CI",
+            "Error creating deploy file",
+            save_deploy_file,
+        ));
+    }
+
+    if should_publish_docker(project_info) {
+        steps.push(GenerationStep::new(
+            "CI",
+            "Error creating Docker publish file",
+            save_docker_publish_file,
+        ));
+    }
+
+    if project_info.include_codeql {
+        steps.push(GenerationStep::new(
+            "CI",
+            "Error creating CodeQL file",
+            save_codeql_file,
+        ));
+    }
+
+    Ok(steps)
+}
+
+/// The directories are created first, then every file is written in parallel. If more than one
+/// file fails the error for the first one in step order is returned.
+pub fn generate_project(project_info: &ProjectInfo) -> Result<GenerationTimings> {
+    let start = Instant::now();
+    let mut timings = GenerationTimings::default();
+
+    if create_directories(project_info).is_err() {
+        bail!("Error creating project directories");
+    }
+
+    timings.add("directories", Duration::ZERO, start.elapsed());
+
+    let versions_started = start.elapsed();
+    let versions = DependencyVersions::resolve(project_info)?;
+    timings.add("dependency versions", versions_started, start.elapsed());
+
+    let steps = generation_steps(project_info, &versions)?;
+    let results: Vec<(Duration, Duration, Result<()>)> = steps
+        .par_iter()
+        .map(|step| {
+            let started = start.elapsed();
+            let result = (step.run)(project_info);
+            (started, start.elapsed(), result)
+        })
+        .collect();
+
+    for (step, (started, finished, result)) in steps.iter().zip(results) {
+        timings.add(step.phase, started, finished);
+
+        if let Err(e) = result {
+            match step.error {
+                Some(error) => bail!(error),
+                None => return Err(e),
+            }
+        }
+    }
+
+    timings.total = start.elapsed();
+
+    Ok(timings)
+}
+
+/// The Docker publish workflow is only useful for applications that already have a Dockerfile.
+fn should_publish_docker(project_info: &ProjectInfo) -> bool {
+    project_info.publish_docker
+        && project_info.is_application
+        && project_info.base_dir().join("Dockerfile").is_file()
+}
+
+/// Explains why `publish_docker` is set but the Docker publish workflow won't be generated.
+pub fn docker_publish_warning(project_info: &ProjectInfo) -> Option<String> {
+    if !project_info.publish_docker || should_publish_docker(project_info) {
+        return None;
+    }
+
+    if project_info.is_application {
+        Some("Skipped the Docker publish workflow because the project has no Dockerfile. Add a Dockerfile then run `python-project update` to generate it".to_string())
+    } else {
+        Some(
+            "Skipped the Docker publish workflow because it is only generated for applications"
+                .to_string(),
+        )
+    }
+}
+
+/// Regenerates the GitHub workflow and pre-commit files for an existing project without touching
+/// any source files.
+pub fn update_project(project_info: &ProjectInfo) -> Result<()> {
+    if save_pre_commit_file(project_info).is_err() {
+        bail!("Error creating .pre-commit-config.yaml file");
+    }
+
+    if project_info.use_continuous_deployment && save_pypi_publish_file(project_info).is_err() {
+        bail!("Error creating PyPI publish file");
+    }
+
+    if project_info.use_continuous_deployment
+        && project_info.include_testpypi_publish
+        && save_testpypi_publish_file(project_info).is_err()
+    {
+        bail!("Error creating TestPyPI publish file");
+    }
+
+    if project_info.include_docs && save_docs_publish_file(project_info).is_err() {
+        bail!("Error creating docs publish file");
+    }
+
+    if project_info.use_multi_os_ci {
+        if save_ci_testing_multi_os_file(project_info).is_err() {
+            bail!("Error creating CI testing file");
         }
     } else if save_ci_testing_linux_only_file(project_info).is_err() {
-        bail!("Error creating CI teesting file");
+        bail!("Error creating CI testing file");
+    }
+
+    if project_info.use_release_drafter && save_release_drafter_file(project_info).is_err() {
+        bail!("Error creating release drafter file");
+    }
+
+    if should_publish_docker(project_info) && save_docker_publish_file(project_info).is_err() {
+        bail!("Error creating Docker publish file");
+    }
+
+    if project_info.include_codeql && save_codeql_file(project_info).is_err() {
+        bail!("Error creating CodeQL file");
+    }
+
+    if project_info.cloud_provider.is_some() && save_deploy_file(project_info).is_err() {
+        bail!("Error creating deploy file");
+    }
+
+    Ok(())
+}
+
+/// Keys of the project table that belong to the project rather than the generator. They are kept
+/// from the existing file when pyproject.toml is regenerated.
+const PRESERVED_PROJECT_KEYS: [&str; 4] = ["version", "authors", "license", "dependencies"];
+
+/// Tables and arrays users add their own entries to. Entries the generator doesn't create are kept
+/// from the existing file, the generated entries are updated.
+const MERGED_PYPROJECT_PATHS: [&[&str]; 4] = [
+    &["dependency-groups", "dev"],
+    &["project", "optional-dependencies", "dev"],
+    &["tool", "poetry", "group", "dev", "dependencies"],
+    &["tool", "pytest", "ini_options"],
+];
+
+/// The package name from a requirement specifier such as `mypy[faster-cache]>=1.14.1`.
+fn requirement_name(specifier: &str) -> String {
+    specifier
+        .split(['[', '<', '>', '=', '!', '~', ';', ' '])
+        .next()
+        .unwrap_or(specifier)
+        .trim()
+        .to_lowercase()
+}
+
+fn merge_user_entries(existing: &DocumentMut, generated: &mut DocumentMut) {
+    for path in MERGED_PYPROJECT_PATHS {
+        let Some(existing_item) = path
+            .iter()
+            .try_fold(existing.as_item(), |item, key| item.get(key))
+        else {
+            continue;
+        };
+        let Some(generated_item) = path
+            .iter()
+            .try_fold(generated.as_item_mut(), |item, key| item.get_mut(key))
+        else {
+            continue;
+        };
+
+        match (generated_item, existing_item) {
+            (
+                Item::Value(toml_edit::Value::Array(generated_array)),
+                Item::Value(toml_edit::Value::Array(existing_array)),
+            ) => {
+                // New entries are laid out like the generated ones, one per line in multi-line arrays.
+                let decor = generated_array.get(0).map(|value| value.decor().clone());
+                let generated_names: Vec<String> = generated_array
+                    .iter()
+                    .filter_map(|value| value.as_str())
+                    .map(requirement_name)
+                    .collect();
+                for value in existing_array.iter() {
+                    if value
+                        .as_str()
+                        .is_some_and(|s| !generated_names.contains(&requirement_name(s)))
+                    {
+                        let mut value = value.clone();
+                        if let Some(decor) = &decor {
+                            *value.decor_mut() = decor.clone();
+                        }
+                        generated_array.push_formatted(value);
+                    }
+                }
+            }
+            (Item::Table(generated_table), Item::Table(existing_table)) => {
+                for (key, item) in existing_table.iter() {
+                    if !generated_table.contains_key(key) {
+                        generated_table.insert(key, item.clone());
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+fn project_table_mut<'a>(
+    document: &'a mut DocumentMut,
+    project_manager: &ProjectManager,
+) -> Option<&'a mut Table> {
+    match project_manager {
+        ProjectManager::Poetry => document
+            .get_mut("tool")
+            .and_then(|t| t.get_mut("poetry"))
+            .and_then(|p| p.as_table_mut()),
+        _ => document.get_mut("project").and_then(|p| p.as_table_mut()),
+    }
+}
+
+/// Copies the preserved project keys from the existing pyproject.toml into the regenerated one and
+/// keeps the dev dependencies and pytest options the user added. A version is only copied when the
+/// regenerated file has a static version.
+fn preserve_project_keys(
+    existing: &str,
+    generated: &str,
+    project_manager: &ProjectManager,
+) -> Result<String> {
+    let Ok(mut existing) = existing.parse::<DocumentMut>() else {
+        bail!("Error parsing the existing pyproject.toml");
+    };
+    let mut generated = generated.parse::<DocumentMut>()?;
+    merge_user_entries(&existing, &mut generated);
+
+    let (Some(existing_table), Some(generated_table)) = (
+        project_table_mut(&mut existing, project_manager),
+        project_table_mut(&mut generated, project_manager),
+    ) else {
+        return Ok(generated.to_string());
+    };
+
+    for key in PRESERVED_PROJECT_KEYS {
+        if key == "version" && !generated_table.contains_key(key) {
+            continue;
+        }
+
+        match (generated_table.get_mut(key), existing_table.get(key)) {
+            // Sub-tables such as Poetry's dependencies keep their place in the generated file.
+            (Some(Item::Table(generated_sub_table)), Some(Item::Table(existing_sub_table))) => {
+                generated_sub_table.clear();
+                for (sub_key, item) in existing_sub_table.iter() {
+                    generated_sub_table.insert(sub_key, item.clone());
+                }
+            }
+            (_, Some(item)) => {
+                generated_table.insert(key, item.clone());
+            }
+            (_, None) => {
+                generated_table.remove(key);
+            }
+        }
+    }
+
+    Ok(generated.to_string())
+}
+
+/// Regenerates only the pyproject.toml file for an existing project. The project's version,
+/// authors, license, and dependencies are kept along with any dev dependencies and pytest options
+/// the user added, everything else is regenerated.
+pub fn update_pyproject(project_info: &ProjectInfo) -> Result<()> {
+    let file_path = project_info.base_dir().join("pyproject.toml");
+    let generated = match DependencyVersions::resolve(project_info)
+        .and_then(|versions| create_pyproject_toml(project_info, &versions))
+    {
+        Ok(g) => g,
+        Err(e) => bail!("Error creating pyproject.toml file: {e}"),
+    };
+    let content = match read_to_string(&file_path) {
+        Ok(existing) => {
+            preserve_project_keys(&existing, &generated, &project_info.project_manager)?
+        }
+        Err(_) => generated,
+    };
+
+    if let Err(e) = validate_toml(&content) {
+        bail!("Error creating pyproject.toml file: {e}");
+    }
+
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::package_version::default_version;
+    use crate::project_info::{
+        CloudProvider, DocsInfo, ProjectInfo, PublishAuth, Pyo3PythonManager,
+    };
+    use insta::assert_yaml_snapshot;
+    use std::collections::HashMap;
+    use tmp_path::tmp_path;
+
+    fn dependency_versions(project_info: &ProjectInfo) -> DependencyVersions {
+        DependencyVersions::resolve(project_info).unwrap()
+    }
+
+    #[tmp_path]
+    fn project_info_dummy() -> ProjectInfo {
+        ProjectInfo {
+            project_name: "My project".to_string(),
+            project_slug: "my-project".to_string(),
+            source_dir: "my_project".to_string(),
+            project_description: "This is a test".to_string(),
+            creator: "Arthur Dent".to_string(),
+            creator_email: "authur@heartofgold.com".to_string(),
+            license: LicenseType::Mit,
+            copyright_year: Some("2023".to_string()),
+            version: "0.1.0".to_string(),
+            python_version: "3.11".to_string(),
+            min_python_version: "3.9".to_string(),
+            project_manager: ProjectManager::Poetry,
+            pyo3_python_manager: Some(Pyo3PythonManager::Uv),
+            is_application: true,
+            is_async_project: false,
+            github_actions_python_test_versions: vec![
+                "3.9".to_string(),
+                "3.10".to_string(),
+                "3.11".to_string(),
+                "3.12".to_string(),
+            ],
+            max_line_length: 100,
+            ruff_line_length: None,
+            use_dependabot: true,
+            dependabot_schedule: None,
+            dependabot_day: None,
+            use_continuous_deployment: true,
+            use_release_drafter: true,
+            use_multi_os_ci: true,
+            include_docs: false,
+            docs_info: None,
+            download_latest_packages: false,
+            min_coverage: None,
+            extra_ruff_rules: Vec::new(),
+            include_bandit: false,
+            extra_pre_commit_hooks: Vec::new(),
+            use_precommit_ci: false,
+            include_settings: false,
+            include_logging: false,
+            mypy_files: None,
+            ruff_target_version: None,
+            offline: false,
+            cloud_provider: None,
+            cloud_deploy_role: None,
+            publish_docker: false,
+            supply_chain_attestation: false,
+            pypi_index_url: None,
+            publish_auth: PublishAuth::TrustedPublishing,
+            include_testpypi_publish: false,
+            include_rust_toolchain: false,
+            rust_edition: "2021".to_string(),
+            pyo3_version: None,
+            use_abi3: false,
+            build_musllinux: false,
+            maturin_targets: None,
+            package_version_overrides: HashMap::new(),
+            ci_job_timeout: 30,
+            dependency_bound_style: None,
+            type_checker: TypeChecker::Mypy,
+            include_conda_env: false,
+            copyright_holder: None,
+            additional_authors: Vec::new(),
+            include_codeowners: false,
+            github_username: None,
+            include_github_templates: false,
+            include_contributing: false,
+            include_codeql: false,
+            include_security_policy: false,
+            include_changelog: false,
+            include_citation: false,
+            include_benchmarks: false,
+            ci_coverage_comment: false,
+            uv_frozen: true,
+            project_root_dir: Some(tmp_path),
+        }
+    }
+
+    fn docs_info_dummy() -> DocsInfo {
+        DocsInfo {
+            site_name: "Test Repo".to_string(),
+            site_description: "Dummy data for testing".to_string(),
+            site_url: Some("https://mytest.com".to_string()),
+            locale: "en".to_string(),
+            repo_name: "sanders41/python-project-generator".to_string(),
+            repo_url: "https://github.com/sanders41/python-project-generator".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_save_gitigngore_file() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Poetry;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join(".gitignore");
+        save_gitigngore_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_gitigngore_pyo3_file() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join(".gitignore");
+        save_gitigngore_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_create_gitignore_maturin_section() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        let gitignore = create_gitigngore_file(&project_info);
+
+        assert!(gitignore.contains("# Rust\n/target\n"));
+        assert!(!gitignore.contains(".pixi"));
+    }
+
+    #[test]
+    fn test_create_gitignore_base_no_manager_sections() {
+        for project_manager in [
+            ProjectManager::Poetry,
+            ProjectManager::Setuptools,
+            ProjectManager::Uv,
+        ] {
+            let mut project_info = project_info_dummy();
+            project_info.project_manager = project_manager;
+            let gitignore = create_gitigngore_file(&project_info);
+
+            assert!(!gitignore.contains("/target"));
+            assert!(!gitignore.contains(".pixi"));
+        }
+    }
+
+    #[test]
+    fn test_create_gitignore_pixi_section() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Pixi;
+        let gitignore = create_gitigngore_file(&project_info);
+
+        assert!(gitignore.contains("# pixi environments\n.pixi\n"));
+        assert!(!gitignore.contains("/target"));
+    }
+
+    #[test]
+    fn test_create_gitignore_uv_lib_ignores_lock_file() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.is_application = false;
+        let gitignore = create_gitigngore_file(&project_info);
+
+        assert!(gitignore.contains("# Lock file\nuv.lock\n"));
+    }
+
+    #[test]
+    fn test_create_gitignore_uv_application_keeps_lock_file() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.is_application = true;
+        let gitignore = create_gitigngore_file(&project_info);
+
+        assert!(!gitignore.contains("uv.lock"));
+    }
+
+    #[test]
+    fn test_generate_project_uv_lock_file_matches_ci() {
+        for is_application in [false, true] {
+            let mut project_info = project_info_dummy();
+            project_info.project_manager = ProjectManager::Uv;
+            project_info.is_application = is_application;
+            project_info.offline = true;
+            let base = project_info.base_dir();
+            generate_project(&project_info).unwrap();
+            let gitignore = std::fs::read_to_string(base.join(".gitignore")).unwrap();
+            let testing =
+                std::fs::read_to_string(base.join(".github/workflows/testing.yml")).unwrap();
+            let justfile = std::fs::read_to_string(base.join("justfile")).unwrap();
+            let lock_file_ignored = gitignore.contains("\nuv.lock\n");
+
+            assert_eq!(lock_file_ignored, !is_application);
+            assert_eq!(testing.contains("uv sync --frozen"), !lock_file_ignored);
+            assert_eq!(justfile.contains("uv sync --frozen"), !lock_file_ignored);
+            std::fs::remove_dir_all(base).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_create_gitignore_setuptools_lib_no_lock_file() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Setuptools;
+        project_info.is_application = false;
+        let gitignore = create_gitigngore_file(&project_info);
+
+        assert!(!gitignore.contains("# Lock file"));
+    }
+
+    #[test]
+    fn test_save_pre_commit_file() {
+        let project_info = project_info_dummy();
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join(".pre-commit-config.yaml");
+        save_pre_commit_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        insta::with_settings!({filters => vec![
+            (r": v\d+\.\d+\.\d+", ": v1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
+    }
+
+    #[test]
+    fn test_save_pre_commit_file_precommit_ci() {
+        let mut project_info = project_info_dummy();
+        project_info.use_precommit_ci = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join(".pre-commit-config.yaml");
+        save_pre_commit_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        insta::with_settings!({filters => vec![
+            (r": v\d+\.\d+\.\d+", ": v1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
+    }
+
+    #[test]
+    fn test_create_pre_commit_file_no_precommit_ci() {
+        let project_info = project_info_dummy();
+        let content = create_pre_commit_file(&project_info);
+
+        assert!(!content.contains("\nci:\n"));
+    }
+
+    #[test]
+    fn test_save_pre_commit_file_bandit() {
+        let mut project_info = project_info_dummy();
+        project_info.include_bandit = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join(".pre-commit-config.yaml");
+        save_pre_commit_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        insta::with_settings!({filters => vec![
+            (r": v\d+\.\d+\.\d+", ": v1.0.0"),
+            (r"rev: \d+\.\d+\.\d+", "rev: 1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
+    }
+
+    #[test]
+    fn test_pre_commit_python_version() {
+        assert_eq!(pre_commit_python_version("3.10"), "python3.10".to_string());
+    }
+
+    #[test]
+    fn test_pre_commit_python_version_drops_patch() {
+        assert_eq!(
+            pre_commit_python_version("3.11.4"),
+            "python3.11".to_string()
+        );
+    }
+
+    #[test]
+    fn test_pre_commit_python_version_caret() {
+        assert_eq!(pre_commit_python_version("^3.10"), "python3.10".to_string());
+    }
+
+    #[test]
+    fn test_pre_commit_python_version_tilde() {
+        assert_eq!(pre_commit_python_version("~3.10"), "python3.10".to_string());
+    }
+
+    #[test]
+    fn test_pre_commit_python_version_greater_or_equal() {
+        assert_eq!(
+            pre_commit_python_version(">=3.10"),
+            "python3.10".to_string()
+        );
+    }
+
+    #[test]
+    fn test_pre_commit_python_version_equal() {
+        assert_eq!(
+            pre_commit_python_version("==3.10.2"),
+            "python3.10".to_string()
+        );
+    }
+
+    #[test]
+    fn test_save_pre_commit_file_default_language_version() {
+        let mut project_info = project_info_dummy();
+        project_info.min_python_version = "3.10".to_string();
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join(".pre-commit-config.yaml");
+        save_pre_commit_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert!(content.starts_with("default_language_version:\n  python: python3.10\nrepos:"));
+
+        insta::with_settings!({filters => vec![
+            (r": v\d+\.\d+\.\d+", ": v1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
+    }
+
+    #[test]
+    fn test_create_pre_commit_file_codespell() {
+        let mut project_info = project_info_dummy();
+        project_info.extra_pre_commit_hooks = vec![ExtraPreCommitHook::Codespell];
+        let content = create_pre_commit_file(&project_info);
+
+        assert!(content.contains(
+            "\n  - repo: https://github.com/codespell-project/codespell\n    rev: v2.4.1\n    hooks:\n    - id: codespell"
+        ));
+        assert!(!content.contains("check-merge-conflict"));
+    }
+
+    #[test]
+    fn test_save_pre_commit_file_extra_hooks() {
+        let mut project_info = project_info_dummy();
+        project_info.extra_pre_commit_hooks = vec![
+            ExtraPreCommitHook::Codespell,
+            ExtraPreCommitHook::CheckMergeConflict,
+            ExtraPreCommitHook::CheckCaseConflict,
+        ];
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join(".pre-commit-config.yaml");
+        save_pre_commit_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        insta::with_settings!({filters => vec![
+            (r": v\d+\.\d+\.\d+", ": v1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
+    }
+
+    #[test]
+    fn test_create_pixi_pyproject_toml_bandit_task() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Pixi;
+        project_info.include_bandit = true;
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains("run-bandit = \"bandit -r my_project\"\n"));
+        assert!(content.contains("bandit"));
+    }
+
+    #[test]
+    fn test_save_poetry_pyproject_toml_file_mit_application() {
+        let mut project_info = project_info_dummy();
+        project_info.license = LicenseType::Mit;
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.is_application = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("pyproject.toml");
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        insta::with_settings!({filters => vec![
+            (r#""\d+\.\d+\.\d+"#, "\"1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
+    }
+
+    #[test]
+    fn test_save_poetry_pyproject_toml_file_apache_application() {
+        let mut project_info = project_info_dummy();
+        project_info.license = LicenseType::Apache2;
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.is_application = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("pyproject.toml");
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        insta::with_settings!({filters => vec![
+            (r#""\d+\.\d+\.\d+"#, "\"1.0.0"),
+            (r#"">=\d+\.\d+\.\d+"#, "\">=1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
     }
 
-    if project_info.include_docs {
-        if save_mkdocs_yaml(project_info).is_err() {
-            bail!("Error creating mkdocs.yml file");
-        }
+    #[test]
+    fn test_save_poetry_pyproject_toml_file_no_license_application() {
+        let mut project_info = project_info_dummy();
+        project_info.license = LicenseType::NoLicense;
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.is_application = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("pyproject.toml");
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
-        if save_docs_cname(project_info).is_err() {
-            bail!("Error creating CNAME file for docs");
-        }
+        assert!(expected_file.is_file());
 
-        if save_docs_index_md(project_info).is_err() {
-            bail!("Error index.md file for docs");
-        }
+        let content = std::fs::read_to_string(expected_file).unwrap();
 
-        if save_docs_css(project_info).is_err() {
-            bail!("Error saving docs css file");
-        }
+        insta::with_settings!({filters => vec![
+            (r#""\d+\.\d+\.\d+"#, "\"1.0.0"),
+            (r#"">=\d+\.\d+\.\d+"#, "\">=1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
     }
 
-    if project_info.use_dependabot && save_dependabot_file(project_info).is_err() {
-        bail!("Error creating dependabot file");
+    #[test]
+    fn test_create_poetry_pyproject_toml_mit_lib() {
+        let mut project_info = project_info_dummy();
+        project_info.license = LicenseType::Mit;
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.is_application = false;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("pyproject.toml");
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        insta::with_settings!({filters => vec![
+            (r#""\d+\.\d+\.\d+"#, "\"1.0.0"),
+            (r#"">=\d+\.\d+\.\d+"#, "\">=1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
     }
 
-    if project_info.use_release_drafter && save_release_drafter_file(project_info).is_err() {
-        bail!("Error creating release drafter file");
+    #[test]
+    fn test_update_project_docker_publish_requires_dockerfile() {
+        let mut project_info = project_info_dummy();
+        project_info.publish_docker = true;
+        let base = project_info.base_dir();
+        let docker_publish_file = base.join(".github/workflows/docker_publish.yml");
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        update_project(&project_info).unwrap();
+
+        assert!(!docker_publish_file.exists());
+
+        std::fs::write(base.join("Dockerfile"), "FROM python:3.13-slim\n").unwrap();
+        update_project(&project_info).unwrap();
+
+        assert!(docker_publish_file.is_file());
     }
 
-    Ok(())
-}
+    #[test]
+    fn test_update_project_deploy_file() {
+        let mut project_info = project_info_dummy();
+        project_info.cloud_provider = Some(CloudProvider::Gcp);
+        let base = project_info.base_dir();
+        create_dir_all(base.join(".github/workflows")).unwrap();
+        update_project(&project_info).unwrap();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::project_info::{DocsInfo, LicenseType, ProjectInfo, Pyo3PythonManager};
-    use insta::assert_yaml_snapshot;
-    use tmp_path::tmp_path;
+        assert!(base.join(".github/workflows/deploy.yml").is_file());
+    }
 
-    #[tmp_path]
-    fn project_info_dummy() -> ProjectInfo {
-        ProjectInfo {
-            project_name: "My project".to_string(),
-            project_slug: "my-project".to_string(),
-            source_dir: "my_project".to_string(),
-            project_description: "This is a test".to_string(),
-            creator: "Arthur Dent".to_string(),
-            creator_email: "authur@heartofgold.com".to_string(),
-            license: LicenseType::Mit,
-            copyright_year: Some("2023".to_string()),
-            version: "0.1.0".to_string(),
-            python_version: "3.11".to_string(),
-            min_python_version: "3.9".to_string(),
-            project_manager: ProjectManager::Poetry,
-            pyo3_python_manager: Some(Pyo3PythonManager::Uv),
-            is_application: true,
-            is_async_project: false,
-            github_actions_python_test_versions: vec![
-                "3.9".to_string(),
-                "3.10".to_string(),
-                "3.11".to_string(),
-                "3.12".to_string(),
-            ],
-            max_line_length: 100,
-            use_dependabot: true,
-            dependabot_schedule: None,
-            dependabot_day: None,
-            use_continuous_deployment: true,
-            use_release_drafter: true,
-            use_multi_os_ci: true,
-            include_docs: false,
-            docs_info: None,
-            download_latest_packages: false,
-            project_root_dir: Some(tmp_path),
+    #[test]
+    fn test_docker_publish_warning() {
+        let mut project_info = project_info_dummy();
+        project_info.is_application = true;
+        assert!(docker_publish_warning(&project_info).is_none());
+
+        project_info.publish_docker = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        assert!(docker_publish_warning(&project_info).is_some());
+
+        std::fs::write(base.join("Dockerfile"), "FROM python:3.13-slim\n").unwrap();
+        assert!(docker_publish_warning(&project_info).is_none());
+
+        project_info.is_application = false;
+        assert!(docker_publish_warning(&project_info).is_some());
+    }
+
+    #[test]
+    fn test_generate_project_offline() {
+        use crate::package_version::{HTTP_CLIENTS_CREATED, NETWORK_LOCK};
+        use std::sync::atomic::Ordering;
+
+        let _lock = NETWORK_LOCK.lock().unwrap();
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.download_latest_packages = true;
+        project_info.offline = true;
+        let clients_created = HTTP_CLIENTS_CREATED.load(Ordering::SeqCst);
+        generate_project(&project_info).unwrap();
+
+        assert_eq!(HTTP_CLIENTS_CREATED.load(Ordering::SeqCst), clients_created);
+    }
+
+    #[cfg(not(feature = "network"))]
+    #[test]
+    fn test_generate_project_without_network_uses_default_versions() {
+        use crate::package_version::{default_pre_commit_rev, default_version};
+
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.download_latest_packages = true;
+        project_info.offline = false;
+        let base = project_info.base_dir();
+        generate_project(&project_info).unwrap();
+
+        let pyproject = std::fs::read_to_string(base.join("pyproject.toml")).unwrap();
+        let pre_commit = std::fs::read_to_string(base.join(".pre-commit-config.yaml")).unwrap();
+
+        assert!(pyproject.contains(&format!(
+            "\"ruff=={}\"",
+            default_version(&PythonPackage::Ruff)
+        )));
+        assert!(pre_commit.contains(&format!(
+            "rev: {}",
+            default_pre_commit_rev(&PreCommitHook::Ruff)
+        )));
+    }
+
+    #[test]
+    fn test_generate_project_rust_toolchain_only_for_maturin() {
+        for project_manager in [
+            ProjectManager::Maturin,
+            ProjectManager::Poetry,
+            ProjectManager::Setuptools,
+            ProjectManager::Uv,
+            ProjectManager::Pixi,
+        ] {
+            let mut project_info = project_info_dummy();
+            let is_maturin = project_manager == ProjectManager::Maturin;
+            project_info.project_manager = project_manager;
+            project_info.pyo3_python_manager = Some(Pyo3PythonManager::Uv);
+            project_info.offline = true;
+            project_info.include_rust_toolchain = true;
+            generate_project(&project_info).unwrap();
+
+            assert_eq!(
+                project_info
+                    .base_dir()
+                    .join("rust-toolchain.toml")
+                    .is_file(),
+                is_maturin
+            );
         }
     }
 
-    fn docs_info_dummy() -> DocsInfo {
-        DocsInfo {
-            site_name: "Test Repo".to_string(),
-            site_description: "Dummy data for testing".to_string(),
-            site_url: "https://mytest.com".to_string(),
-            locale: "en".to_string(),
-            repo_name: "sanders41/python-project-generator".to_string(),
-            repo_url: "https://github.com/sanders41/python-project-generator".to_string(),
+    #[test]
+    fn test_generate_project_creates_all_files() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.pyo3_python_manager = Some(Pyo3PythonManager::Setuptools);
+        project_info.offline = true;
+        project_info.use_continuous_deployment = true;
+        project_info.use_dependabot = true;
+        project_info.use_release_drafter = true;
+        project_info.use_multi_os_ci = true;
+        project_info.include_docs = true;
+        project_info.docs_info = Some(docs_info_dummy());
+        project_info.include_contributing = true;
+        project_info.include_security_policy = true;
+        project_info.include_changelog = true;
+        project_info.include_citation = true;
+        project_info.include_benchmarks = true;
+        project_info.include_testpypi_publish = true;
+        project_info.include_rust_toolchain = true;
+        project_info.include_github_templates = true;
+        project_info.include_codeql = true;
+        let base = project_info.base_dir();
+        let timings = generate_project(&project_info).unwrap();
+
+        for file in [
+            ".gitignore",
+            ".pre-commit-config.yaml",
+            "README.md",
+            "LICENSE",
+            "my_project/py.typed",
+            "my_project/__init__.py",
+            "tests/__init__.py",
+            "pyproject.toml",
+            "justfile",
+            "CONTRIBUTING.md",
+            "SECURITY.md",
+            "CHANGELOG.md",
+            "CITATION.cff",
+            "benchmarks/test_benchmark.py",
+            "requirements.txt",
+            "requirements-dev.txt",
+            "MANIFEST.in",
+            "src/lib.rs",
+            "Cargo.toml",
+            "rust-toolchain.toml",
+            ".github/workflows/pypi_publish.yml",
+            ".github/workflows/testpypi_publish.yml",
+            ".github/workflows/docs_publish.yml",
+            ".github/workflows/testing.yml",
+            ".github/workflows/codeql.yml",
+            ".github/dependabot.yml",
+            ".github/release_drafter_template.yml",
+            ".github/pull_request_template.md",
+            "mkdocs.yml",
+            "docs/index.md",
+            "docs/css/custom.css",
+        ] {
+            assert!(base.join(file).is_file(), "{file} was not created");
         }
+
+        assert!(timings.report().contains("\n  dependency versions: "));
+        assert!(timings.report().contains("\n  docs: "));
     }
 
     #[test]
-    fn test_save_gitigngore_file() {
+    fn test_generation_timings_parallel_steps_use_phase_span() {
+        let mut timings = GenerationTimings::default();
+        timings.add("CI", Duration::from_millis(10), Duration::from_millis(40));
+        timings.add("docs", Duration::from_millis(10), Duration::from_millis(30));
+        timings.add("CI", Duration::from_millis(20), Duration::from_millis(50));
+
+        assert_eq!(
+            timings.report(),
+            "Generated in 0.00s\n  CI: 0.04s\n  docs: 0.02s"
+        );
+    }
+
+    #[test]
+    fn test_generate_project_module_name_override() {
         let mut project_info = project_info_dummy();
-        project_info.project_manager = ProjectManager::Poetry;
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.source_dir = "mypkg".to_string();
+        let base = project_info.base_dir();
+        generate_project(&project_info).unwrap();
+
+        assert!(base.join("mypkg/__init__.py").is_file());
+        assert!(!base.join("my_project").exists());
+
+        let content = std::fs::read_to_string(base.join("pyproject.toml")).unwrap();
+
+        assert!(content.contains("name = \"mypkg\""));
+        assert!(content.contains("path = \"mypkg/_version.py\""));
+        assert!(content.contains("files = [\"mypkg\", \"tests\"]"));
+    }
+
+    #[test]
+    fn test_save_pyproject_toml_file_mit_pyo3() {
+        let mut project_info = project_info_dummy();
+        project_info.license = LicenseType::Mit;
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.is_application = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
-        let expected_file = base.join(".gitignore");
-        save_gitigngore_file(&project_info).unwrap();
+        let expected_file = base.join("pyproject.toml");
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
-        assert_yaml_snapshot!(content);
+        insta::with_settings!({filters => vec![
+            (r"==\d+\.\d+\.\d+", "==1.0.0"),
+            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
     }
 
     #[test]
-    fn test_save_gitigngore_pyo3_file() {
+    fn test_save_pyproject_toml_file_apache_pyo3() {
+        let mut project_info = project_info_dummy();
+        project_info.license = LicenseType::Apache2;
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.is_application = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("pyproject.toml");
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        insta::with_settings!({filters => vec![
+            (r"==\d+\.\d+\.\d+", "==1.0.0"),
+            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
+    }
+
+    #[test]
+    fn test_save_pyproject_toml_file_no_license_pyo3() {
         let mut project_info = project_info_dummy();
+        project_info.license = LicenseType::NoLicense;
         project_info.project_manager = ProjectManager::Maturin;
+        project_info.is_application = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("pyproject.toml");
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        insta::with_settings!({filters => vec![
+            (r"==\d+\.\d+\.\d+", "==1.0.0"),
+            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
+    }
+
+    #[test]
+    fn test_save_setuptools_pyproject_toml_file_mit_application() {
+        let mut project_info = project_info_dummy();
+        project_info.license = LicenseType::Mit;
+        project_info.project_manager = ProjectManager::Setuptools;
+        project_info.is_application = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("pyproject.toml");
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        insta::with_settings!({filters => vec![
+            (r"==\d+\.\d+\.\d+", "==1.0.0"),
+            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
+    }
+
+    #[test]
+    fn test_save_setuptools_pyproject_toml_file_apache_application() {
+        let mut project_info = project_info_dummy();
+        project_info.license = LicenseType::Apache2;
+        project_info.project_manager = ProjectManager::Setuptools;
+        project_info.is_application = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("pyproject.toml");
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        insta::with_settings!({filters => vec![
+            (r"==\d+\.\d+\.\d+", "==1.0.0"),
+            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
+    }
+
+    #[test]
+    fn test_save_setuptools_pyproject_toml_file_no_license_application() {
+        let mut project_info = project_info_dummy();
+        project_info.license = LicenseType::NoLicense;
+        project_info.project_manager = ProjectManager::Setuptools;
+        project_info.is_application = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
-        let expected_file = base.join(".gitignore");
-        save_gitigngore_file(&project_info).unwrap();
+        let expected_file = base.join("pyproject.toml");
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
-        assert_yaml_snapshot!(content);
+        insta::with_settings!({filters => vec![
+            (r"==\d+\.\d+\.\d+", "==1.0.0"),
+            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
+        ]}, { assert_yaml_snapshot!(content)});
     }
 
     #[test]
-    fn test_save_pre_commit_file() {
-        let project_info = project_info_dummy();
+    fn test_create_setuptools_pyproject_toml_mit_lib() {
+        let mut project_info = project_info_dummy();
+        project_info.license = LicenseType::Mit;
+        project_info.project_manager = ProjectManager::Setuptools;
+        project_info.is_application = false;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
-        let expected_file = base.join(".pre-commit-config.yaml");
-        save_pre_commit_file(&project_info).unwrap();
+        let expected_file = base.join("pyproject.toml");
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
         insta::with_settings!({filters => vec![
-            (r": v\d+\.\d+\.\d+", ": v1.0.0"),
+            (r"==\d+\.\d+\.\d+", "==1.0.0"),
+            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
         ]}, { assert_yaml_snapshot!(content)});
     }
 
     #[test]
-    fn test_save_poetry_pyproject_toml_file_mit_application() {
+    fn test_save_uv_pyproject_toml_file_mit_application() {
         let mut project_info = project_info_dummy();
         project_info.license = LicenseType::Mit;
-        project_info.project_manager = ProjectManager::Poetry;
+        project_info.project_manager = ProjectManager::Uv;
         project_info.is_application = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
         let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
         insta::with_settings!({filters => vec![
-            (r#""\d+\.\d+\.\d+"#, "\"1.0.0"),
+            (r"==\d+\.\d+\.\d+", "==1.0.0"),
+            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
         ]}, { assert_yaml_snapshot!(content)});
     }
 
     #[test]
-    fn test_save_poetry_pyproject_toml_file_apache_application() {
+    fn test_save_uv_pyproject_toml_file_apache_application() {
         let mut project_info = project_info_dummy();
         project_info.license = LicenseType::Apache2;
-        project_info.project_manager = ProjectManager::Poetry;
+        project_info.project_manager = ProjectManager::Uv;
         project_info.is_application = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
         let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
         insta::with_settings!({filters => vec![
-            (r#""\d+\.\d+\.\d+"#, "\"1.0.0"),
-            (r#"">=\d+\.\d+\.\d+"#, "\">=1.0.0"),
+            (r"==\d+\.\d+\.\d+", "==1.0.0"),
+            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
         ]}, { assert_yaml_snapshot!(content)});
     }
 
     #[test]
-    fn test_save_poetry_pyproject_toml_file_no_license_application() {
+    fn test_save_uv_pyproject_toml_file_no_license_application() {
         let mut project_info = project_info_dummy();
         project_info.license = LicenseType::NoLicense;
-        project_info.project_manager = ProjectManager::Poetry;
+        project_info.project_manager = ProjectManager::Uv;
         project_info.is_application = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
         let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
         insta::with_settings!({filters => vec![
-            (r#""\d+\.\d+\.\d+"#, "\"1.0.0"),
-            (r#"">=\d+\.\d+\.\d+"#, "\">=1.0.0"),
+            (r"==\d+\.\d+\.\d+", "==1.0.0"),
+            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
         ]}, { assert_yaml_snapshot!(content)});
     }
 
     #[test]
-    fn test_create_poetry_pyproject_toml_mit_lib() {
+    fn test_create_uv_pyproject_toml_mit_lib() {
         let mut project_info = project_info_dummy();
         project_info.license = LicenseType::Mit;
-        project_info.project_manager = ProjectManager::Poetry;
+        project_info.project_manager = ProjectManager::Uv;
         project_info.is_application = false;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
         let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
         insta::with_settings!({filters => vec![
-            (r#""\d+\.\d+\.\d+"#, "\"1.0.0"),
-            (r#"">=\d+\.\d+\.\d+"#, "\">=1.0.0"),
+            (r"==\d+\.\d+\.\d+", "==1.0.0"),
+            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
         ]}, { assert_yaml_snapshot!(content)});
     }
 
     #[test]
-    fn test_save_pyproject_toml_file_mit_pyo3() {
+    fn test_save_pixi_pyproject_toml_file_mit_application() {
         let mut project_info = project_info_dummy();
         project_info.license = LicenseType::Mit;
-        project_info.project_manager = ProjectManager::Maturin;
+        project_info.project_manager = ProjectManager::Pixi;
         project_info.is_application = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
         let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
@@ -1468,15 +3577,15 @@ mod tests {
     }
 
     #[test]
-    fn test_save_pyproject_toml_file_apache_pyo3() {
+    fn test_save_pixi_pyproject_toml_file_apache_application() {
         let mut project_info = project_info_dummy();
         project_info.license = LicenseType::Apache2;
-        project_info.project_manager = ProjectManager::Maturin;
+        project_info.project_manager = ProjectManager::Pixi;
         project_info.is_application = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
         let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
@@ -1489,15 +3598,15 @@ mod tests {
     }
 
     #[test]
-    fn test_save_pyproject_toml_file_no_license_pyo3() {
+    fn test_save_pixi_pyproject_toml_file_no_license_application() {
         let mut project_info = project_info_dummy();
         project_info.license = LicenseType::NoLicense;
-        project_info.project_manager = ProjectManager::Maturin;
+        project_info.project_manager = ProjectManager::Pixi;
         project_info.is_application = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
         let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
@@ -1510,15 +3619,15 @@ mod tests {
     }
 
     #[test]
-    fn test_save_setuptools_pyproject_toml_file_mit_application() {
+    fn test_create_pixi_pyproject_toml_mit_lib() {
         let mut project_info = project_info_dummy();
         project_info.license = LicenseType::Mit;
-        project_info.project_manager = ProjectManager::Setuptools;
-        project_info.is_application = true;
+        project_info.project_manager = ProjectManager::Pixi;
+        project_info.is_application = false;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
         let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
@@ -1531,252 +3640,616 @@ mod tests {
     }
 
     #[test]
-    fn test_save_setuptools_pyproject_toml_file_apache_application() {
+    fn test_create_pyproject_toml_async_project() {
         let mut project_info = project_info_dummy();
-        project_info.license = LicenseType::Apache2;
-        project_info.project_manager = ProjectManager::Setuptools;
-        project_info.is_application = true;
+        project_info.is_async_project = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
         let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
         insta::with_settings!({filters => vec![
-            (r"==\d+\.\d+\.\d+", "==1.0.0"),
-            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
+            (r"\d+\.\d+\.\d+", "1.0.0"),
         ]}, { assert_yaml_snapshot!(content)});
     }
 
     #[test]
-    fn test_save_setuptools_pyproject_toml_file_no_license_application() {
+    fn test_create_pyproject_toml_no_min_coverage() {
+        let project_info = project_info_dummy();
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains(
+            r#"addopts = "--cov=my_project --cov-report term-missing --no-cov-on-fail""#
+        ));
+        assert!(!content.contains("fail_under"));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_min_coverage() {
         let mut project_info = project_info_dummy();
-        project_info.license = LicenseType::NoLicense;
-        project_info.project_manager = ProjectManager::Setuptools;
+        project_info.min_coverage = Some(90);
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains(
+            r#"addopts = "--cov=my_project --cov-report term-missing --no-cov-on-fail --cov-fail-under=90""#
+        ));
+        assert!(content.contains("fail_under = 90\n"));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_ruff_line_length() {
+        let mut project_info = project_info_dummy();
+        project_info.max_line_length = 100;
+        project_info.ruff_line_length = Some(88);
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains("[tool.ruff]\nline-length = 88\n"));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_coverage_comment() {
+        let mut project_info = project_info_dummy();
+        project_info.ci_coverage_comment = true;
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content
+            .contains("[tool.coverage.run]\nrelative_files = true\n\n[tool.coverage.report]\n"));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_uv_application_settings_dependency() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
         project_info.is_application = true;
+        project_info.include_settings = true;
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains("dependencies = [\n  \"pydantic-settings==2.7.1\",\n]\n"));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_poetry_lib_settings_dependency() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.is_application = false;
+        project_info.include_settings = true;
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains(
+            "[tool.poetry.dependencies]\npython = \"^3.9\"\npydantic-settings = \">=2.7.1\"\n\n"
+        ));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_no_settings_dependency() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains("dependencies = []\n"));
+        assert!(!content.contains("pydantic-settings"));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_default_mypy_files() {
+        let project_info = project_info_dummy();
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains("[tool.mypy]\nfiles = [\"my_project\", \"tests\"]\n"));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_package_version_override() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info
+            .package_version_overrides
+            .insert("mypy".to_string(), "1.10.0".to_string());
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains("\"mypy[faster-cache]==1.10.0\""));
+    }
+
+    #[test]
+    fn test_save_dev_requirements_package_version_override() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Setuptools;
+        project_info
+            .package_version_overrides
+            .insert("mypy".to_string(), "1.10.0".to_string());
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
-        let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        save_dev_requirements(&project_info, &dependency_versions(&project_info)).unwrap();
+        let content = std::fs::read_to_string(base.join("requirements-dev.txt")).unwrap();
+
+        assert!(content.contains("mypy[faster-cache]==1.10.0\n"));
+    }
+
+    #[test]
+    fn test_save_pyproject_toml_file_invalid_toml() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.project_description = r#"A "quoted" description"#.to_string();
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let result = save_pyproject_toml_file(&project_info, &dependency_versions(&project_info));
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains(r#"description = "A "quoted" description""#));
+        assert!(!base.join("pyproject.toml").exists());
+    }
+
+    #[test]
+    fn test_validate_toml() {
+        assert!(validate_toml("[project]\nname = \"my-project\"\n").is_ok());
+
+        let error = validate_toml("[project]\nname = my-project\n")
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.starts_with("Invalid TOML on line 2"));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_poetry_additional_authors() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.additional_authors = vec![(
+            "Ford Prefect".to_string(),
+            "ford@heartofgold.com".to_string(),
+        )];
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains(
+            "authors = [\"Arthur Dent <authur@heartofgold.com>\", \"Ford Prefect <ford@heartofgold.com>\"]\n"
+        ));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_uv_additional_authors() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.additional_authors = vec![(
+            "Ford Prefect".to_string(),
+            "ford@heartofgold.com".to_string(),
+        )];
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains(
+            r#"authors = [
+  { name = "Arthur Dent", email = "authur@heartofgold.com" },
+  { name = "Ford Prefect", email = "ford@heartofgold.com" }
+]
+"#
+        ));
+        toml::from_str::<toml::Value>(&content).unwrap();
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_maturin_additional_authors() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.additional_authors = vec![(
+            "Ford Prefect".to_string(),
+            "ford@heartofgold.com".to_string(),
+        )];
+
+        for pyo3_python_manager in [Pyo3PythonManager::Uv, Pyo3PythonManager::Setuptools] {
+            project_info.pyo3_python_manager = Some(pyo3_python_manager);
+            let content =
+                create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+            let pyproject = toml::from_str::<toml::Value>(&content).unwrap();
+            let authors = pyproject["project"]["authors"].as_array().unwrap();
+
+            assert_eq!(authors.len(), 2);
+            assert_eq!(authors[1]["name"].as_str(), Some("Ford Prefect"));
+            assert_eq!(authors[1]["email"].as_str(), Some("ford@heartofgold.com"));
+        }
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_pinned_version_default_packages() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.download_latest_packages = false;
+        project_info
+            .package_version_overrides
+            .insert("ruff".to_string(), "0.6.0".to_string());
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains("\"ruff==0.6.0\""));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_offline_pinned_version() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.download_latest_packages = true;
+        project_info.offline = true;
+        project_info
+            .package_version_overrides
+            .insert("ruff".to_string(), "0.6.0".to_string());
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains("\"ruff==0.6.0\""));
+        assert!(content.contains(&format!(
+            "\"pytest=={}\"",
+            default_version(&PythonPackage::Pytest)
+        )));
+    }
+
+    #[test]
+    fn test_save_security_file() {
+        let mut project_info = project_info_dummy();
+        project_info.include_security_policy = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("SECURITY.md");
+        save_security_file(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
-        insta::with_settings!({filters => vec![
-            (r"==\d+\.\d+\.\d+", "==1.0.0"),
-            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
-        ]}, { assert_yaml_snapshot!(content)});
+        assert!(content.contains(&project_info.creator_email));
+        assert!(content.contains("| 0.1.x | :white_check_mark: |"));
+        assert_yaml_snapshot!(content);
     }
 
     #[test]
-    fn test_create_setuptools_pyproject_toml_mit_lib() {
+    fn test_save_changelog_file() {
         let mut project_info = project_info_dummy();
-        project_info.license = LicenseType::Mit;
-        project_info.project_manager = ProjectManager::Setuptools;
-        project_info.is_application = false;
+        project_info.include_changelog = true;
+        project_info.use_release_drafter = false;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
-        let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        let expected_file = base.join("CHANGELOG.md");
+        save_changelog_file(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
-        insta::with_settings!({filters => vec![
-            (r"==\d+\.\d+\.\d+", "==1.0.0"),
-            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
-        ]}, { assert_yaml_snapshot!(content)});
+        assert!(content.contains("## [Unreleased]"));
+        assert!(!content.contains("release-drafter"));
+        assert_yaml_snapshot!(content);
     }
 
     #[test]
-    fn test_save_uv_pyproject_toml_file_mit_application() {
+    fn test_save_changelog_file_release_drafter() {
         let mut project_info = project_info_dummy();
-        project_info.license = LicenseType::Mit;
-        project_info.project_manager = ProjectManager::Uv;
-        project_info.is_application = true;
+        project_info.include_changelog = true;
+        project_info.use_release_drafter = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
-        let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        let expected_file = base.join("CHANGELOG.md");
+        save_changelog_file(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
-        insta::with_settings!({filters => vec![
-            (r"==\d+\.\d+\.\d+", "==1.0.0"),
-            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
-        ]}, { assert_yaml_snapshot!(content)});
+        assert!(content.contains("release-drafter"));
+        assert_yaml_snapshot!(content);
     }
 
     #[test]
-    fn test_save_uv_pyproject_toml_file_apache_application() {
+    fn test_save_citation_file() {
+        let mut project_info = project_info_dummy();
+        project_info.include_citation = true;
+        project_info.additional_authors =
+            vec![("Ford".to_string(), "ford@heartofgold.com".to_string())];
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("CITATION.cff");
+        save_citation_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert!(content.contains("title: \"My project\"\n"));
+        assert!(content.contains("version: \"0.1.0\"\n"));
+        assert!(content.contains("  - family-names: \"Dent\"\n    given-names: \"Arthur\"\n"));
+        assert!(content.contains("  - name: \"Ford\"\n"));
+        assert!(content.contains("license: MIT\n"));
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_create_citation_file_no_license() {
+        let mut project_info = project_info_dummy();
+        project_info.license = LicenseType::NoLicense;
+        let content = create_citation_file(&project_info);
+
+        assert!(!content.contains("license:"));
+    }
+
+    #[test]
+    fn test_save_contributing_file_poetry() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.include_contributing = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("CONTRIBUTING.md");
+        save_contributing_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert!(content.contains("poetry run pre-commit install"));
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_contributing_file_uv() {
         let mut project_info = project_info_dummy();
-        project_info.license = LicenseType::Apache2;
         project_info.project_manager = ProjectManager::Uv;
-        project_info.is_application = true;
+        project_info.include_contributing = true;
+        project_info.include_bandit = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
-        let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        let expected_file = base.join("CONTRIBUTING.md");
+        save_contributing_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert!(content.contains("uv run pre-commit install"));
+        assert!(content.contains("just security"));
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_contributing_file_setuptools() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Setuptools;
+        project_info.include_contributing = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("CONTRIBUTING.md");
+        save_contributing_file(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
-        insta::with_settings!({filters => vec![
-            (r"==\d+\.\d+\.\d+", "==1.0.0"),
-            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
-        ]}, { assert_yaml_snapshot!(content)});
+        assert_yaml_snapshot!(content);
     }
 
     #[test]
-    fn test_save_uv_pyproject_toml_file_no_license_application() {
+    fn test_save_contributing_file_pixi() {
         let mut project_info = project_info_dummy();
-        project_info.license = LicenseType::NoLicense;
-        project_info.project_manager = ProjectManager::Uv;
-        project_info.is_application = true;
+        project_info.project_manager = ProjectManager::Pixi;
+        project_info.include_contributing = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
-        let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        let expected_file = base.join("CONTRIBUTING.md");
+        save_contributing_file(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
-        insta::with_settings!({filters => vec![
-            (r"==\d+\.\d+\.\d+", "==1.0.0"),
-            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
-        ]}, { assert_yaml_snapshot!(content)});
+        assert!(content.contains("pixi run -e dev pre-commit install"));
+        assert_yaml_snapshot!(content);
     }
 
     #[test]
-    fn test_create_uv_pyproject_toml_mit_lib() {
+    fn test_save_contributing_file_pyo3_uv() {
         let mut project_info = project_info_dummy();
-        project_info.license = LicenseType::Mit;
-        project_info.project_manager = ProjectManager::Uv;
-        project_info.is_application = false;
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.pyo3_python_manager = Some(Pyo3PythonManager::Uv);
+        project_info.include_contributing = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
-        let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        let expected_file = base.join("CONTRIBUTING.md");
+        save_contributing_file(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
-        insta::with_settings!({filters => vec![
-            (r"==\d+\.\d+\.\d+", "==1.0.0"),
-            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
-        ]}, { assert_yaml_snapshot!(content)});
+        assert!(content.contains("just develop"));
+        assert!(content.contains("https://rustup.rs"));
+        assert_yaml_snapshot!(content);
     }
 
     #[test]
-    fn test_save_pixi_pyproject_toml_file_mit_application() {
+    fn test_save_contributing_file_pyo3_setuptools() {
         let mut project_info = project_info_dummy();
-        project_info.license = LicenseType::Mit;
-        project_info.project_manager = ProjectManager::Pixi;
-        project_info.is_application = true;
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.pyo3_python_manager = Some(Pyo3PythonManager::Setuptools);
+        project_info.include_contributing = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
-        let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        let expected_file = base.join("CONTRIBUTING.md");
+        save_contributing_file(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
-        insta::with_settings!({filters => vec![
-            (r"==\d+\.\d+\.\d+", "==1.0.0"),
-            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
-        ]}, { assert_yaml_snapshot!(content)});
+        assert_yaml_snapshot!(content);
     }
 
     #[test]
-    fn test_save_pixi_pyproject_toml_file_apache_application() {
+    fn test_save_manifest_in() {
         let mut project_info = project_info_dummy();
-        project_info.license = LicenseType::Apache2;
-        project_info.project_manager = ProjectManager::Pixi;
-        project_info.is_application = true;
+        project_info.project_manager = ProjectManager::Setuptools;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
-        let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        let expected_file = base.join("MANIFEST.in");
+        save_manifest_in(&project_info).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
-        insta::with_settings!({filters => vec![
-            (r"==\d+\.\d+\.\d+", "==1.0.0"),
-            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
-        ]}, { assert_yaml_snapshot!(content)});
+        assert_eq!(
+            content,
+            "include LICENSE\ninclude README.md\ninclude my_project/py.typed\n"
+        );
     }
 
     #[test]
-    fn test_save_pixi_pyproject_toml_file_no_license_application() {
+    fn test_create_manifest_in_no_license() {
         let mut project_info = project_info_dummy();
         project_info.license = LicenseType::NoLicense;
+
+        assert!(!create_manifest_in(&project_info).contains("LICENSE"));
+    }
+
+    #[test]
+    fn test_save_conda_environment_file() {
+        let mut project_info = project_info_dummy();
         project_info.project_manager = ProjectManager::Pixi;
-        project_info.is_application = true;
+        project_info.include_conda_env = true;
+        project_info.include_docs = true;
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
-        let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        let expected_file = base.join("environment.yml");
+        save_conda_environment_file(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
         let content = std::fs::read_to_string(expected_file).unwrap();
 
+        assert!(content.contains("  - python>=3.9\n"));
+        assert!(content.contains("  - pytest=="));
+        assert!(content.contains("      - mypy[faster-cache]=="));
+        assert!(content.contains("      - mkdocstrings[python]=="));
+
         insta::with_settings!({filters => vec![
             (r"==\d+\.\d+\.\d+", "==1.0.0"),
-            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
-        ]}, { assert_yaml_snapshot!(content)});
+        ]}, { insta::assert_yaml_snapshot!(content)});
     }
 
     #[test]
-    fn test_create_pixi_pyproject_toml_mit_lib() {
+    fn test_create_pyproject_toml_uv_dependency_bound_styles() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+
+        for (style, operator, add_bounds) in [
+            (DependencyBoundStyle::Exact, "==", "exact"),
+            (DependencyBoundStyle::Minimum, ">=", "lower"),
+            (DependencyBoundStyle::Compatible, "~=", "minor"),
+        ] {
+            project_info.dependency_bound_style = Some(style);
+            let content =
+                create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+            assert!(content.contains(&format!("\"mypy[faster-cache]{operator}")));
+            assert!(content.contains(&format!("\"pytest{operator}")));
+            assert!(content.contains(&format!("[tool.uv]\nadd-bounds = \"{add_bounds}\"\n")));
+        }
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_pixi_dependency_bound_style() {
         let mut project_info = project_info_dummy();
-        project_info.license = LicenseType::Mit;
         project_info.project_manager = ProjectManager::Pixi;
-        project_info.is_application = false;
-        let base = project_info.base_dir();
-        create_dir_all(&base).unwrap();
-        let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        project_info.dependency_bound_style = Some(DependencyBoundStyle::Minimum);
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
 
-        assert!(expected_file.is_file());
+        assert!(content.contains("\"ruff>="));
+        assert!(content.contains("\"pytest>="));
+        assert!(!content.contains("add-bounds"));
+    }
 
-        let content = std::fs::read_to_string(expected_file).unwrap();
+    #[test]
+    fn test_create_pyproject_toml_uv_no_dependency_bound_style() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
 
-        insta::with_settings!({filters => vec![
-            (r"==\d+\.\d+\.\d+", "==1.0.0"),
-            (r">=\d+\.\d+\.\d+", ">=1.0.0"),
-        ]}, { assert_yaml_snapshot!(content)});
+        assert!(content.contains("\"pytest=="));
+        assert!(!content.contains("[tool.uv]"));
     }
 
     #[test]
-    fn test_create_pyproject_toml_async_project() {
+    fn test_pyupgrade_version() {
+        assert_eq!(pyupgrade_version("3.10"), "310");
+        assert_eq!(pyupgrade_version("^3.10"), "310");
+        assert_eq!(pyupgrade_version("3.10.2"), "310");
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_patch_min_python_version() {
         let mut project_info = project_info_dummy();
-        project_info.is_async_project = true;
-        let base = project_info.base_dir();
-        create_dir_all(&base).unwrap();
-        let expected_file = base.join("pyproject.toml");
-        save_pyproject_toml_file(&project_info).unwrap();
+        project_info.project_manager = ProjectManager::Uv;
 
-        assert!(expected_file.is_file());
+        for version in ["3.10", "^3.10", "3.10.2"] {
+            project_info.min_python_version = version.to_string();
+            let content =
+                create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
 
-        let content = std::fs::read_to_string(expected_file).unwrap();
+            assert!(content.contains("target-version = \"py310\""));
+        }
+    }
 
-        insta::with_settings!({filters => vec![
-            (r"\d+\.\d+\.\d+", "1.0.0"),
-        ]}, { assert_yaml_snapshot!(content)});
+    #[test]
+    fn test_create_pyproject_toml_ruff_target_version() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.min_python_version = "3.9".to_string();
+        project_info.ruff_target_version = Some("3.12".to_string());
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains("target-version = \"py312\""));
+        assert!(content.contains("requires-python = \">=3.9\""));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_custom_mypy_files() {
+        let mut project_info = project_info_dummy();
+        project_info.mypy_files = Some(vec![
+            "my_project".to_string(),
+            "tests".to_string(),
+            "scripts".to_string(),
+        ]);
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains("[tool.mypy]\nfiles = [\"my_project\", \"tests\", \"scripts\"]\n"));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_extra_ruff_rules() {
+        let mut project_info = project_info_dummy();
+        project_info.extra_ruff_rules = vec!["SIM".to_string(), "PTH".to_string()];
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert_eq!(content.matches(r#""SIM","#).count(), 1);
+        assert_eq!(content.matches(r#""PTH","#).count(), 1);
+        assert!(content.contains("\"RUF023\",  # Unforted __slots__\n  \"SIM\",\n  \"PTH\",\n]"));
     }
 
     #[test]
@@ -1787,7 +4260,7 @@ mod tests {
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
         let expected_file = base.join("requirements-dev.txt");
-        save_dev_requirements(&project_info).unwrap();
+        save_dev_requirements(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
@@ -1807,7 +4280,7 @@ mod tests {
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
         let expected_file = base.join("requirements-dev.txt");
-        save_dev_requirements(&project_info).unwrap();
+        save_dev_requirements(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
@@ -1819,6 +4292,48 @@ mod tests {
         ]}, { assert_yaml_snapshot!(content)});
     }
 
+    #[test]
+    fn test_save_setuptools_dev_requirements_references_runtime_requirements() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Setuptools;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        save_runtime_requirements(&project_info, &dependency_versions(&project_info)).unwrap();
+        save_dev_requirements(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(base.join("requirements.txt").is_file());
+
+        let content = std::fs::read_to_string(base.join("requirements-dev.txt")).unwrap();
+
+        assert!(content.starts_with("-r requirements.txt\n"));
+    }
+
+    #[test]
+    fn test_save_runtime_requirements_include_settings() {
+        for (project_manager, pyo3_python_manager) in [
+            (ProjectManager::Setuptools, None),
+            (ProjectManager::Maturin, Some(Pyo3PythonManager::Setuptools)),
+        ] {
+            let mut project_info = project_info_dummy();
+            project_info.project_manager = project_manager;
+            project_info.pyo3_python_manager = pyo3_python_manager;
+            project_info.include_settings = true;
+            project_info.is_application = true;
+            let base = project_info.base_dir();
+            create_dir_all(&base).unwrap();
+            save_runtime_requirements(&project_info, &dependency_versions(&project_info)).unwrap();
+            let content = std::fs::read_to_string(base.join("requirements.txt")).unwrap();
+
+            assert_eq!(
+                content,
+                format!(
+                    "pydantic-settings=={}\n",
+                    default_version(&PythonPackage::PydanticSettings)
+                )
+            );
+        }
+    }
+
     #[test]
     fn test_save_setuptools_dev_requirements_application_file() {
         let mut project_info = project_info_dummy();
@@ -1828,7 +4343,7 @@ mod tests {
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
         let expected_file = base.join("requirements-dev.txt");
-        save_dev_requirements(&project_info).unwrap();
+        save_dev_requirements(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
@@ -1849,7 +4364,7 @@ mod tests {
         let base = project_info.base_dir();
         create_dir_all(&base).unwrap();
         let expected_file = base.join("requirements-dev.txt");
-        save_dev_requirements(&project_info).unwrap();
+        save_dev_requirements(&project_info, &dependency_versions(&project_info)).unwrap();
 
         assert!(expected_file.is_file());
 
@@ -1877,6 +4392,34 @@ mod tests {
         assert_yaml_snapshot!(content);
     }
 
+    #[test]
+    fn test_build_mkdocs_yaml_github_pages_site_url() {
+        let mut project_info = project_info_dummy();
+        project_info.include_docs = true;
+        let mut docs_info = docs_info_dummy();
+        docs_info.site_url = None;
+        project_info.docs_info = Some(docs_info);
+        let content = build_mkdocs_yaml(&project_info).unwrap();
+
+        assert!(
+            content.contains("site_url: https://sanders41.github.io/python-project-generator/\n")
+        );
+    }
+
+    #[test]
+    fn test_save_cname_file_no_custom_domain() {
+        let mut project_info = project_info_dummy();
+        project_info.include_docs = true;
+        let mut docs_info = docs_info_dummy();
+        docs_info.site_url = None;
+        project_info.docs_info = Some(docs_info);
+        let base = project_info.base_dir().join("docs");
+        create_dir_all(&base).unwrap();
+        save_docs_cname(&project_info).unwrap();
+
+        assert!(!base.join("CNAME").exists());
+    }
+
     #[test]
     fn test_save_cname_file() {
         let mut project_info = project_info_dummy();
@@ -1957,6 +4500,22 @@ mod tests {
         assert_yaml_snapshot!(content);
     }
 
+    #[test]
+    fn test_save_justfile_uv() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("justfile");
+        save_justfile(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
     #[test]
     fn test_save_justfile_maturin() {
         let mut project_info = project_info_dummy();
@@ -1974,6 +4533,141 @@ mod tests {
         assert_yaml_snapshot!(content);
     }
 
+    #[test]
+    fn test_save_justfile_poetry_bandit() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.include_bandit = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("justfile");
+        save_justfile(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_justfile_maturin_bandit() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.is_application = false;
+        project_info.include_bandit = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("justfile");
+        save_justfile(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_justfile_poetry_ty() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Poetry;
+        project_info.type_checker = TypeChecker::Ty;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("justfile");
+        save_justfile(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_justfile_pixi_ty() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Pixi;
+        project_info.type_checker = TypeChecker::Ty;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("justfile");
+        save_justfile(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_justfile_uv_benchmarks() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.include_benchmarks = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        save_justfile(&project_info).unwrap();
+        let content = std::fs::read_to_string(base.join("justfile")).unwrap();
+
+        assert!(
+            content.ends_with("\n@bench:\n  uv run pytest benchmarks --benchmark-only --no-cov\n")
+        );
+    }
+
+    #[test]
+    fn test_pixi_benchmarks() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Pixi;
+        project_info.include_benchmarks = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        save_justfile(&project_info).unwrap();
+        save_pyproject_toml_file(&project_info, &dependency_versions(&project_info)).unwrap();
+        let justfile = std::fs::read_to_string(base.join("justfile")).unwrap();
+        let pyproject = std::fs::read_to_string(base.join("pyproject.toml")).unwrap();
+
+        assert!(justfile.contains("\n@bench:\n  pixi run run-bench\n"));
+        assert!(pyproject.contains("run-bench = \"pytest benchmarks --benchmark-only --no-cov\"\n"));
+        assert!(pyproject.contains("pytest-benchmark"));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_maturin_abi3() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.pyo3_python_manager = Some(Pyo3PythonManager::Uv);
+        project_info.use_abi3 = true;
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(content.contains("features = [\"pyo3/extension-module\", \"pyo3/abi3-py39\"]\n"));
+    }
+
+    #[test]
+    fn test_create_pyproject_toml_ty() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.type_checker = TypeChecker::Ty;
+        let content =
+            create_pyproject_toml(&project_info, &dependency_versions(&project_info)).unwrap();
+
+        assert!(!content.contains("[tool.mypy]"));
+        assert!(!content.contains("\"mypy"));
+        assert!(content.contains("\"ty=="));
+    }
+
+    #[test]
+    fn test_create_pre_commit_file_ty() {
+        let mut project_info = project_info_dummy();
+        project_info.type_checker = TypeChecker::Ty;
+        let content = create_pre_commit_file(&project_info);
+
+        assert!(!content.contains("mypy"));
+    }
+
     #[test]
     fn test_save_readme_file() {
         let project_info = project_info_dummy();
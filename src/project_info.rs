@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     fmt,
     io::Write,
     path::{Path, PathBuf},
@@ -6,10 +7,39 @@ use std::{
 
 use anyhow::{bail, Result};
 use clap::ValueEnum;
+use colored::*;
+use inquire::MultiSelect;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
 use crate::config::Config;
+use crate::utils::is_python_version_or_greater;
+
+/// The number of minutes a publish job can run before it is cancelled.
+pub const DEFAULT_CI_JOB_TIMEOUT: u16 = 30;
+
+pub const DEFAULT_RUST_EDITION: &str = "2021";
+
+const RUST_EDITIONS: [&str; 3] = ["2018", "2021", "2024"];
+
+/// The targets wheels are built for on each platform when publishing a Maturin project.
+pub const MATURIN_LINUX_TARGETS: [&str; 6] =
+    ["x86_64", "x86", "aarch64", "armv7", "s390x", "ppc64le"];
+pub const MATURIN_WINDOWS_TARGETS: [&str; 2] = ["x64", "x86"];
+pub const MATURIN_MACOS_TARGETS: [&str; 2] = ["x86_64", "aarch64"];
+pub const MATURIN_MUSLLINUX_TARGETS: [&str; 4] = ["x86_64", "x86", "aarch64", "armv7"];
+
+/// The Windows target triples that can be built, with the matching setup-python architecture
+/// which is also accepted as a Maturin target.
+pub const MATURIN_WINDOWS_TRIPLES: [(&str, &str); 2] = [
+    ("x86_64-pc-windows-msvc", "x64"),
+    ("i686-pc-windows-msvc", "x86"),
+];
+
+/// Ruff lint rules that are always selected in the generated pyproject.toml.
+pub const DEFAULT_RUFF_RULES: [&str; 10] = [
+    "E", "B", "W", "F", "UP", "I001", "T201", "T203", "RUF022", "RUF023",
+];
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, ValueEnum, PartialEq, Eq)]
 pub enum DependabotSchedule {
@@ -29,6 +59,71 @@ impl fmt::Display for DependabotSchedule {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, ValueEnum, PartialEq, Eq)]
+pub enum CloudProvider {
+    Aws,
+    Gcp,
+}
+
+impl fmt::Display for CloudProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Aws => write!(f, "AWS"),
+            Self::Gcp => write!(f, "GCP"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ValueEnum, PartialEq, Eq)]
+pub enum DependencyBoundStyle {
+    Exact,
+    Minimum,
+    Compatible,
+}
+
+impl fmt::Display for DependencyBoundStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Exact => write!(f, "Exact"),
+            Self::Minimum => write!(f, "Minimum"),
+            Self::Compatible => write!(f, "Compatible"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ValueEnum, PartialEq, Eq)]
+pub enum PublishAuth {
+    #[default]
+    TrustedPublishing,
+    ApiToken,
+}
+
+impl fmt::Display for PublishAuth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::TrustedPublishing => write!(f, "TrustedPublishing"),
+            Self::ApiToken => write!(f, "ApiToken"),
+        }
+    }
+}
+
+/// ty is still pre-release so mypy stays the default.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ValueEnum, PartialEq, Eq)]
+pub enum TypeChecker {
+    #[default]
+    Mypy,
+    Ty,
+}
+
+impl fmt::Display for TypeChecker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Mypy => write!(f, "Mypy"),
+            Self::Ty => write!(f, "Ty"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize, ValueEnum, PartialEq, Eq)]
 pub enum Day {
     #[default]
@@ -55,6 +150,23 @@ impl fmt::Display for Day {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, ValueEnum, PartialEq, Eq)]
+pub enum ExtraPreCommitHook {
+    CheckCaseConflict,
+    CheckMergeConflict,
+    Codespell,
+}
+
+impl fmt::Display for ExtraPreCommitHook {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::CheckCaseConflict => write!(f, "check-case-conflict"),
+            Self::CheckMergeConflict => write!(f, "check-merge-conflict"),
+            Self::Codespell => write!(f, "codespell"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize, ValueEnum, PartialEq, Eq)]
 pub enum LicenseType {
     #[default]
@@ -111,6 +223,28 @@ impl fmt::Display for ProjectManager {
     }
 }
 
+impl ProjectManager {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Maturin => "Python packages with a Rust extension module built with PyO3",
+            Self::Poetry => "Dependency management and packaging with a poetry.lock file",
+            Self::Setuptools => "Plain pip and requirements files without a lock file",
+            Self::Uv => "Fast dependency management and packaging with a uv.lock file",
+            Self::Pixi => "Conda and PyPI dependencies managed together with a pixi.lock file",
+        }
+    }
+
+    /// The build backend set in the generated pyproject.toml.
+    pub fn build_backend(&self) -> &'static str {
+        match self {
+            Self::Maturin => "maturin",
+            Self::Poetry => "poetry-core",
+            Self::Setuptools => "setuptools",
+            Self::Uv | Self::Pixi => "hatchling",
+        }
+    }
+}
+
 struct Prompt {
     prompt_text: String,
     default: Option<String>,
@@ -120,10 +254,9 @@ impl Prompt {
     fn show_prompt(&self) -> Result<String> {
         let mut input = String::new();
 
-        if let Some(d) = &self.default {
-            print!("{} ({d}): ", self.prompt_text);
-        } else {
-            print!("{}: ", self.prompt_text);
+        match &self.default {
+            Some(d) if !d.is_empty() => print!("{} ({d}): ", self.prompt_text),
+            _ => print!("{}: ", self.prompt_text),
         }
 
         std::io::stdout().flush().unwrap();
@@ -147,12 +280,43 @@ impl Prompt {
 pub struct DocsInfo {
     pub site_name: String,
     pub site_description: String,
-    pub site_url: String,
+    /// A custom domain for the docs, if not set the docs are served from GitHub Pages.
+    pub site_url: Option<String>,
     pub locale: String,
     pub repo_name: String,
     pub repo_url: String,
 }
 
+impl DocsInfo {
+    /// Without a custom domain GitHub Pages serves the docs from a subpath,
+    /// `https://<owner>.github.io/<repo>/`, and mkdocs needs the subpath in its `site_url`.
+    pub fn resolved_site_url(&self) -> Result<String> {
+        if let Some(site_url) = &self.site_url {
+            return Ok(site_url.clone());
+        }
+
+        let repo = self
+            .repo_url
+            .trim_end_matches('/')
+            .trim_end_matches(".git")
+            .strip_prefix("https://github.com/")
+            .unwrap_or(&self.repo_name);
+
+        match repo.split_once('/') {
+            Some((owner, name)) if !owner.is_empty() && !name.is_empty() && !name.contains('/') => {
+                Ok(format!(
+                    "https://{}.github.io/{name}/",
+                    owner.to_lowercase()
+                ))
+            }
+            _ => bail!(
+                "Unable to determine the GitHub Pages url from {}",
+                self.repo_url
+            ),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct ProjectInfo {
     pub project_name: String,
@@ -161,6 +325,8 @@ pub struct ProjectInfo {
     pub project_description: String,
     pub creator: String,
     pub creator_email: String,
+    pub additional_authors: Vec<(String, String)>,
+    pub copyright_holder: Option<String>,
     pub license: LicenseType,
     pub copyright_year: Option<String>,
     pub version: String,
@@ -172,25 +338,82 @@ pub struct ProjectInfo {
     pub is_application: bool,
     pub github_actions_python_test_versions: Vec<String>,
     pub max_line_length: u8,
+    pub ruff_line_length: Option<u8>,
+    pub min_coverage: Option<u8>,
+    pub extra_ruff_rules: Vec<String>,
+    pub include_bandit: bool,
+    pub include_conda_env: bool,
+    pub include_codeowners: bool,
+    pub github_username: Option<String>,
+    pub include_github_templates: bool,
+    pub include_contributing: bool,
+    pub include_codeql: bool,
+    pub include_security_policy: bool,
+    pub include_changelog: bool,
+    pub include_citation: bool,
+    pub include_benchmarks: bool,
+    pub extra_pre_commit_hooks: Vec<ExtraPreCommitHook>,
+    pub use_precommit_ci: bool,
+    pub include_settings: bool,
+    pub include_logging: bool,
+    pub mypy_files: Option<Vec<String>>,
+    pub ruff_target_version: Option<String>,
+    pub package_version_overrides: HashMap<String, String>,
+    pub dependency_bound_style: Option<DependencyBoundStyle>,
+    pub type_checker: TypeChecker,
     pub use_dependabot: bool,
     pub dependabot_schedule: Option<DependabotSchedule>,
     pub dependabot_day: Option<Day>,
     pub use_continuous_deployment: bool,
     pub use_release_drafter: bool,
+    pub cloud_provider: Option<CloudProvider>,
+    pub cloud_deploy_role: Option<String>,
+    pub publish_docker: bool,
+    pub supply_chain_attestation: bool,
+    pub pypi_index_url: Option<String>,
+    pub publish_auth: PublishAuth,
+    pub include_testpypi_publish: bool,
+    pub include_rust_toolchain: bool,
+    pub rust_edition: String,
+    pub pyo3_version: Option<String>,
+    pub use_abi3: bool,
+    pub build_musllinux: bool,
+    pub maturin_targets: Option<Vec<String>>,
+    pub ci_job_timeout: u16,
+    pub ci_coverage_comment: bool,
+    pub uv_frozen: bool,
     pub use_multi_os_ci: bool,
     pub include_docs: bool,
     pub docs_info: Option<DocsInfo>,
     pub download_latest_packages: bool,
+    pub offline: bool,
     pub project_root_dir: Option<PathBuf>,
 }
 
 impl ProjectInfo {
+    /// Offline mode takes precedence over `download_latest_packages` so no network requests are
+    /// made. Builds without the `network` feature always use the default versions.
+    pub fn should_download_latest_packages(&self) -> bool {
+        cfg!(feature = "network") && self.download_latest_packages && !self.offline
+    }
+
     pub fn base_dir(&self) -> PathBuf {
         match &self.project_root_dir {
             Some(root) => PathBuf::from(&format!("{}/{}", root.display(), self.project_slug)),
             None => PathBuf::from(&self.project_slug),
         }
     }
+
+    /// Applications commit their lock file for reproducible installs, libraries ignore it.
+    pub fn commits_lock_file(&self) -> bool {
+        self.is_application
+    }
+
+    /// `uv sync --frozen` fails without a committed `uv.lock` so it is only used when the lock
+    /// file is committed.
+    pub fn uv_sync_frozen(&self) -> bool {
+        self.uv_frozen && self.commits_lock_file()
+    }
 }
 
 /// `selected_default` is the value passed from the saved `default` values. default is used if
@@ -402,6 +625,50 @@ fn pyo3_python_manager_prompt(default: Option<Pyo3PythonManager>) -> Result<Pyo3
     }
 }
 
+/// A deliberately permissive check, it only rejects values that can't be an email address so
+/// unusual but valid addresses are still accepted.
+pub fn is_valid_email(email: &str) -> bool {
+    if email.chars().any(char::is_whitespace) {
+        return false;
+    }
+
+    match email.rsplit_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.is_empty()
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !domain.contains("..")
+        }
+        None => false,
+    }
+}
+
+/// Parses an author in the `Name <email>` format used by the `--author` flag.
+pub fn parse_author(author: &str) -> Result<(String, String), String> {
+    let Some((name, email)) = author
+        .trim()
+        .strip_suffix('>')
+        .and_then(|a| a.rsplit_once('<'))
+    else {
+        return Err(format!(
+            r#"{author} is not a valid author, use the format "Name <email>""#
+        ));
+    };
+    let name = name.trim();
+    let email = email.trim();
+
+    if name.is_empty() {
+        return Err(format!("{author} is missing the author name"));
+    }
+
+    if !is_valid_email(email) {
+        return Err(format!("{email} is not a valid email address"));
+    }
+
+    Ok((name.to_string(), email.to_string()))
+}
+
 pub fn is_valid_python_version(version: &str) -> bool {
     let split_version: Vec<&str> = version.split('.').collect();
     let split_length = split_version.len();
@@ -424,6 +691,405 @@ pub fn is_valid_python_version(version: &str) -> bool {
     true
 }
 
+pub fn is_valid_rust_edition(edition: &str) -> bool {
+    RUST_EDITIONS.contains(&edition)
+}
+
+/// A Maturin target is either one of the default architectures or a full target triple for
+/// Linux, Windows, or macOS, such as `aarch64-unknown-linux-musl`.
+pub fn is_valid_maturin_target(target: &str) -> bool {
+    // The Windows interpreter is set up with the target as its architecture so only triples
+    // setup-python has an architecture for can be built.
+    if target.contains("-windows") {
+        return MATURIN_WINDOWS_TRIPLES
+            .iter()
+            .any(|(triple, _)| *triple == target);
+    }
+
+    MATURIN_LINUX_TARGETS.contains(&target)
+        || MATURIN_WINDOWS_TARGETS.contains(&target)
+        || MATURIN_MACOS_TARGETS.contains(&target)
+        || ["-linux", "-apple-"].iter().any(|os| target.contains(os))
+}
+
+/// Crate versions are used as Cargo version requirements so `0.23` and `0.23.4` are both valid.
+pub fn is_valid_crate_version(version: &str) -> bool {
+    let split_version: Vec<&str> = version.split('.').collect();
+
+    (1..=3).contains(&split_version.len()) && split_version.iter().all(|s| s.parse::<u32>().is_ok())
+}
+
+/// Test versions can also be a free-threaded build, such as `3.14t`, which are available from
+/// Python 3.13. These aren't valid for the project's Python version since the `t` suffix isn't
+/// allowed in `requires-python`.
+pub fn is_valid_python_test_version(version: &str) -> bool {
+    let Some(base_version) = version.strip_suffix('t') else {
+        return is_valid_python_version(version);
+    };
+
+    if !is_valid_python_version(base_version) {
+        return false;
+    }
+
+    let minor = base_version
+        .split('.')
+        .nth(1)
+        .and_then(|m| m.parse::<i32>().ok());
+
+    minor.is_some_and(|m| m >= 13)
+}
+
+const PYTHON_KEYWORDS: [&str; 35] = [
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Top level standard library modules. A package with one of these names shadows the standard
+/// library module when it is imported. This is `sys.stdlib_module_names` without the private
+/// modules, plus the `test` package which that list leaves out and the modules added in newer
+/// Python versions.
+const STDLIB_MODULES: &[&str] = &[
+    "abc",
+    "aifc",
+    "annotationlib",
+    "antigravity",
+    "argparse",
+    "array",
+    "ast",
+    "asynchat",
+    "asyncio",
+    "asyncore",
+    "atexit",
+    "audioop",
+    "base64",
+    "bdb",
+    "binascii",
+    "bisect",
+    "builtins",
+    "bz2",
+    "cProfile",
+    "calendar",
+    "cgi",
+    "cgitb",
+    "chunk",
+    "cmath",
+    "cmd",
+    "code",
+    "codecs",
+    "codeop",
+    "collections",
+    "colorsys",
+    "compileall",
+    "compression",
+    "concurrent",
+    "configparser",
+    "contextlib",
+    "contextvars",
+    "copy",
+    "copyreg",
+    "crypt",
+    "csv",
+    "ctypes",
+    "curses",
+    "dataclasses",
+    "datetime",
+    "dbm",
+    "decimal",
+    "difflib",
+    "dis",
+    "distutils",
+    "doctest",
+    "email",
+    "encodings",
+    "ensurepip",
+    "enum",
+    "errno",
+    "faulthandler",
+    "fcntl",
+    "filecmp",
+    "fileinput",
+    "fnmatch",
+    "fractions",
+    "ftplib",
+    "functools",
+    "gc",
+    "genericpath",
+    "getopt",
+    "getpass",
+    "gettext",
+    "glob",
+    "graphlib",
+    "grp",
+    "gzip",
+    "hashlib",
+    "heapq",
+    "hmac",
+    "html",
+    "http",
+    "idlelib",
+    "imaplib",
+    "imghdr",
+    "imp",
+    "importlib",
+    "inspect",
+    "io",
+    "ipaddress",
+    "itertools",
+    "json",
+    "keyword",
+    "lib2to3",
+    "linecache",
+    "locale",
+    "logging",
+    "lzma",
+    "mailbox",
+    "mailcap",
+    "marshal",
+    "math",
+    "mimetypes",
+    "mmap",
+    "modulefinder",
+    "msilib",
+    "msvcrt",
+    "multiprocessing",
+    "netrc",
+    "nis",
+    "nntplib",
+    "nt",
+    "ntpath",
+    "nturl2path",
+    "numbers",
+    "opcode",
+    "operator",
+    "optparse",
+    "os",
+    "ossaudiodev",
+    "pathlib",
+    "pdb",
+    "pickle",
+    "pickletools",
+    "pipes",
+    "pkgutil",
+    "platform",
+    "plistlib",
+    "poplib",
+    "posix",
+    "posixpath",
+    "pprint",
+    "profile",
+    "pstats",
+    "pty",
+    "pwd",
+    "py_compile",
+    "pyclbr",
+    "pydoc",
+    "pydoc_data",
+    "pyexpat",
+    "queue",
+    "quopri",
+    "random",
+    "re",
+    "readline",
+    "reprlib",
+    "resource",
+    "rlcompleter",
+    "runpy",
+    "sched",
+    "secrets",
+    "select",
+    "selectors",
+    "shelve",
+    "shlex",
+    "shutil",
+    "signal",
+    "site",
+    "smtpd",
+    "smtplib",
+    "sndhdr",
+    "socket",
+    "socketserver",
+    "spwd",
+    "sqlite3",
+    "sre_compile",
+    "sre_constants",
+    "sre_parse",
+    "ssl",
+    "stat",
+    "statistics",
+    "string",
+    "stringprep",
+    "struct",
+    "subprocess",
+    "sunau",
+    "symtable",
+    "sys",
+    "sysconfig",
+    "syslog",
+    "tabnanny",
+    "tarfile",
+    "telnetlib",
+    "tempfile",
+    "termios",
+    "test",
+    "textwrap",
+    "this",
+    "threading",
+    "time",
+    "timeit",
+    "tkinter",
+    "token",
+    "tokenize",
+    "tomllib",
+    "trace",
+    "traceback",
+    "tracemalloc",
+    "tty",
+    "turtle",
+    "turtledemo",
+    "types",
+    "typing",
+    "unicodedata",
+    "unittest",
+    "urllib",
+    "uu",
+    "uuid",
+    "venv",
+    "warnings",
+    "wave",
+    "weakref",
+    "webbrowser",
+    "winreg",
+    "winsound",
+    "wsgiref",
+    "xdrlib",
+    "xml",
+    "xmlrpc",
+    "zipapp",
+    "zipfile",
+    "zipimport",
+    "zlib",
+    "zoneinfo",
+];
+
+/// A ruff rule selector is a prefix of uppercase letters optionally followed by digits, e.g.
+/// `SIM`, `PTH`, or `RUF100`.
+pub fn is_valid_ruff_rule(rule: &str) -> bool {
+    let digits_start = rule
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(rule.len());
+    let (letters, digits) = rule.split_at(digits_start);
+
+    !letters.is_empty()
+        && letters.chars().all(|c| c.is_ascii_uppercase())
+        && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// A module name has to be importable so it is limited to ASCII letters, numbers, and
+/// underscores, and can't start with a number.
+pub fn is_valid_python_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Rejects module names that can't be imported because they are keywords, or that would shadow a
+/// standard library module.
+fn validate_module_name(module_name: &str) -> Result<()> {
+    if PYTHON_KEYWORDS.contains(&module_name) {
+        bail!(format!(
+            "{module_name} is a Python keyword and can't be used as a module name. Try {module_name}_pkg instead"
+        ));
+    }
+
+    if STDLIB_MODULES.contains(&module_name) {
+        bail!(format!(
+            "{module_name} shadows the {module_name} standard library module. Try {module_name}_pkg instead"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Builds a valid Python identifier from a project name. Accented Latin letters are transliterated,
+/// any other character that isn't allowed in an identifier becomes a separator, and a name that
+/// doesn't start with a letter is prefixed so it can still be imported.
+fn module_name_from_project_name(project_name: &str) -> String {
+    let mut module_name = String::new();
+
+    for c in project_name.to_lowercase().chars() {
+        let c = transliterate_char(c);
+        if c.is_ascii_alphanumeric() {
+            module_name.push(c);
+        } else if !module_name.is_empty() && !module_name.ends_with('_') {
+            module_name.push('_');
+        }
+    }
+
+    let module_name = module_name.trim_end_matches('_');
+
+    if module_name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        module_name.to_string()
+    } else if module_name.is_empty() {
+        "project".to_string()
+    } else {
+        format!("project_{module_name}")
+    }
+}
+
+fn transliterate_char(c: char) -> char {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+        'ç' => 'c',
+        'è' | 'é' | 'ê' | 'ë' => 'e',
+        'ì' | 'í' | 'î' | 'ï' => 'i',
+        'ñ' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => 'o',
+        'ù' | 'ú' | 'û' | 'ü' => 'u',
+        'ý' | 'ÿ' => 'y',
+        _ => c,
+    }
+}
+
+/// The project slug is used as the package name so it has to be a valid PyPI name (PEP 503 and
+/// PEP 508). Only lowercase letters are allowed so the slug is already in its normalized form.
+fn validate_project_slug(slug: &str) -> Result<()> {
+    let is_valid = slug.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && slug.ends_with(|c: char| c.is_ascii_alphanumeric())
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || ['-', '_', '.'].contains(&c));
+
+    if is_valid {
+        return Ok(());
+    }
+
+    let message = format!(
+        "{slug} is not a valid project slug. Slugs can only contain lowercase letters, numbers, '-', '_', and '.', and must start and end with a letter or number"
+    );
+    let normalized = normalize_project_slug(slug);
+
+    if normalized.is_empty() {
+        bail!(message);
+    }
+
+    bail!(format!("{message}. Try {normalized} instead"));
+}
+
+fn normalize_project_slug(slug: &str) -> String {
+    let mut normalized = String::new();
+
+    for c in slug.to_lowercase().chars() {
+        if c.is_ascii_alphanumeric() {
+            normalized.push(c);
+        } else if !normalized.is_empty() && !normalized.ends_with('-') {
+            normalized.push('-');
+        }
+    }
+
+    normalized.trim_end_matches('-').to_string()
+}
+
 fn copyright_year_prompt(license: &LicenseType, default: Option<String>) -> Result<String> {
     let prompt_text = "Copyright Year".to_string();
     let prompt = Prompt {
@@ -437,25 +1103,56 @@ fn copyright_year_prompt(license: &LicenseType, default: Option<String>) -> Resu
             "A copyright year is required for {} license",
             license
         ));
-    } else {
-        match input.parse::<i32>() {
-            Ok(y) => {
-                if !(1000..=9999).contains(&y) {
-                    bail!(format!("{y} is not a valid year"));
-                }
-            }
-            _ => {
-                bail!(format!("{input} is not a valid year"));
-            }
-        };
+    } else if !is_valid_copyright_year(&input) {
+        bail!(format!(
+            "{input} is not a valid copyright year, use a year like 2024, a range like 2021-2024, or 2021-present"
+        ));
     }
 
     Ok(input)
 }
 
-pub fn get_project_info(use_defaults: bool) -> Result<ProjectInfo> {
-    let config = Config::default().load_config();
-    let project_name = string_prompt("Project Name".to_string(), None)?;
+fn parse_year(year: &str) -> Option<u16> {
+    if year.len() != 4 || !year.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    year.parse::<u16>().ok().filter(|y| *y >= 1000)
+}
+
+/// Accepts a single year, `2024`, a range, `2021-2024`, or an open range, `2021-present`.
+pub fn is_valid_copyright_year(year: &str) -> bool {
+    match year.split_once('-') {
+        None => parse_year(year).is_some(),
+        Some((start, "present")) => parse_year(start).is_some(),
+        Some((start, end)) => match (parse_year(start), parse_year(end)) {
+            (Some(start), Some(end)) => start < end,
+            _ => false,
+        },
+    }
+}
+
+pub fn get_project_info(
+    config: Config,
+    use_defaults: bool,
+    module_name: Option<String>,
+) -> Result<ProjectInfo> {
+    build_project_info(config, None, None, module_name, use_defaults)
+}
+
+/// Builds the project info from the config, prompting for anything that isn't provided. The
+/// project name and description are only prompted for when they are `None`.
+pub fn build_project_info(
+    config: Config,
+    project_name: Option<String>,
+    project_description: Option<String>,
+    module_name: Option<String>,
+    use_defaults: bool,
+) -> Result<ProjectInfo> {
+    let project_name = match project_name {
+        Some(name) => name,
+        None => string_prompt("Project Name".to_string(), None)?,
+    };
     let project_slug_default = project_name.replace(' ', "-").to_lowercase();
     let project_slug = default_or_prompt_string(
         "Project Slug".to_string(),
@@ -463,23 +1160,42 @@ pub fn get_project_info(use_defaults: bool) -> Result<ProjectInfo> {
         use_defaults,
     )?;
 
+    validate_project_slug(&project_slug)?;
+
     if Path::new(&project_slug).exists() {
         bail!(format!("The {project_slug} directory already exists"));
     }
 
-    let source_dir_default = project_name.replace([' ', '-'], "_").to_lowercase();
-    let source_dir = default_or_prompt_string(
-        "Source Directory".to_string(),
-        Some(source_dir_default),
-        use_defaults,
-    )?;
-    let project_description = string_prompt("Project Description".to_string(), None)?;
+    let source_dir_default = module_name_from_project_name(&project_name);
+    let source_dir = match module_name {
+        Some(name) => {
+            if !is_valid_python_identifier(&name) {
+                bail!(format!("{name} is not a valid Python module name"));
+            }
+
+            name
+        }
+        None => default_or_prompt_string(
+            "Source Directory".to_string(),
+            Some(source_dir_default),
+            use_defaults,
+        )?,
+    };
+    validate_module_name(&source_dir.replace([' ', '-'], "_"))?;
+
+    let project_description = match project_description {
+        Some(description) => description,
+        None => string_prompt("Project Description".to_string(), None)?,
+    };
     let creator = default_or_prompt_string("Creator".to_string(), config.creator, use_defaults)?;
     let creator_email = default_or_prompt_string(
         "Creator Email".to_string(),
         config.creator_email,
         use_defaults,
     )?;
+    if !is_valid_email(&creator_email) {
+        bail!(format!("{creator_email} is not a valid email address"));
+    }
     let license = if use_defaults {
         config.license.unwrap_or_default()
     } else {
@@ -499,6 +1215,19 @@ pub fn get_project_info(use_defaults: bool) -> Result<ProjectInfo> {
     } else {
         None
     };
+    let copyright_holder = if let LicenseType::Mit = license {
+        if use_defaults {
+            config.copyright_holder
+        } else {
+            let holder = string_prompt(
+                "Copyright Holder".to_string(),
+                Some(config.copyright_holder.unwrap_or(creator.clone())),
+            )?;
+            (holder != creator).then_some(holder)
+        }
+    } else {
+        None
+    };
 
     let default_version = "0.1.0".to_string();
     let version =
@@ -559,6 +1288,12 @@ pub fn get_project_info(use_defaults: bool) -> Result<ProjectInfo> {
         github_actions_python_test_versions_prompt(github_actions_python_test_version_default)?
     };
 
+    if let Some(warning) =
+        python_test_versions_warning(&min_python_version, &github_actions_python_test_versions)?
+    {
+        eprintln!("{}", warning.yellow());
+    }
+
     let project_manager = if use_defaults {
         config.project_manager.unwrap_or_default()
     } else {
@@ -602,14 +1337,23 @@ pub fn get_project_info(use_defaults: bool) -> Result<ProjectInfo> {
         max_line_length_prompt(config.max_line_length)?
     };
 
-    let use_dependabot = if use_defaults {
-        config.use_dependabot.unwrap_or(true)
+    let optional_features_default = OptionalFeatures {
+        use_dependabot: config.use_dependabot.unwrap_or(true),
+        use_continuous_deployment: config.use_continuous_deployment.unwrap_or(true),
+        use_release_drafter: config.use_release_drafter.unwrap_or(true),
+        use_multi_os_ci: config.use_multi_os_ci.unwrap_or(true),
+        include_docs: config.include_docs.unwrap_or(false),
+    };
+    let OptionalFeatures {
+        use_dependabot,
+        use_continuous_deployment,
+        use_release_drafter,
+        use_multi_os_ci,
+        include_docs,
+    } = if use_defaults {
+        optional_features_default
     } else {
-        boolean_prompt(
-            "Use Dependabot\n  1 - Yes\n  2 - No\n  Choose from [1, 2]".to_string(),
-            config.use_dependabot,
-            true,
-        )?
+        optional_features_prompt(&optional_features_default)?
     };
 
     let dependabot_schedule = if use_dependabot {
@@ -629,35 +1373,15 @@ pub fn get_project_info(use_defaults: bool) -> Result<ProjectInfo> {
     } else {
         None
     };
-    let use_continuous_deployment = default_or_prompt_bool(
-        "Use Continuous Deployment\n  1 - Yes\n  2 - No\n  Choose from [1, 2]".to_string(),
-        config.use_continuous_deployment,
-        true,
-        use_defaults,
-    )?;
-    let use_release_drafter = default_or_prompt_bool(
-        "Use Release Drafter\n  1 - Yes\n  2 - No\n  Choose from [1, 2]".to_string(),
-        config.use_release_drafter,
-        true,
-        use_defaults,
-    )?;
-    let use_multi_os_ci = default_or_prompt_bool(
-        "Use Multi OS CI\n  1 - Yes\n  2 - No\n  Choose from [1, 2]".to_string(),
-        config.use_multi_os_ci,
-        true,
-        use_defaults,
-    )?;
-    let include_docs = default_or_prompt_bool(
-        "Include Docs\n  1 - Yes\n  2 - No\n  Choose from [1, 2]".to_string(),
-        config.include_docs,
-        false,
-        use_defaults,
-    )?;
 
     let docs_info = if include_docs {
         let site_name = string_prompt("Docs Site Name".to_string(), None)?;
         let site_description = string_prompt("Docs Site Description".to_string(), None)?;
-        let site_url = string_prompt("Docs Site Url".to_string(), None)?;
+        let site_url = string_prompt(
+            "Docs Site Url, leave blank to use GitHub Pages".to_string(),
+            Some(String::new()),
+        )?;
+        let site_url = (!site_url.is_empty()).then_some(site_url);
         let locale = string_prompt("Docs Locale".to_string(), Some("en".to_string()))?;
         let repo_name = string_prompt("Docs Repo Name".to_string(), None)?;
         let repo_url = string_prompt("Docs Repo Url".to_string(), None)?;
@@ -681,6 +1405,8 @@ pub fn get_project_info(use_defaults: bool) -> Result<ProjectInfo> {
         project_description,
         creator,
         creator_email,
+        additional_authors: Vec::new(),
+        copyright_holder,
         license,
         copyright_year,
         version,
@@ -692,15 +1418,57 @@ pub fn get_project_info(use_defaults: bool) -> Result<ProjectInfo> {
         is_async_project,
         github_actions_python_test_versions,
         max_line_length,
+        ruff_line_length: config.ruff_line_length,
+        min_coverage: config.min_coverage,
+        extra_ruff_rules: config.extra_ruff_rules.unwrap_or_default(),
+        include_bandit: config.include_bandit.unwrap_or(false),
+        include_conda_env: config.include_conda_env.unwrap_or(false),
+        include_codeowners: config.include_codeowners.unwrap_or(false),
+        github_username: config.github_username,
+        include_github_templates: config.include_github_templates.unwrap_or(false),
+        include_contributing: config.include_contributing.unwrap_or(false),
+        include_codeql: config.include_codeql.unwrap_or(false),
+        include_security_policy: config.include_security_policy.unwrap_or(false),
+        include_changelog: config.include_changelog.unwrap_or(false),
+        include_citation: config.include_citation.unwrap_or(false),
+        include_benchmarks: config.include_benchmarks.unwrap_or(false),
+        extra_pre_commit_hooks: config.extra_pre_commit_hooks.unwrap_or_default(),
+        use_precommit_ci: config.use_precommit_ci.unwrap_or(false),
+        include_settings: false,
+        include_logging: false,
+        mypy_files: config.mypy_files,
+        ruff_target_version: config.ruff_target_version,
+        package_version_overrides: config.package_version_overrides.unwrap_or_default(),
+        dependency_bound_style: config.dependency_bound_style,
+        type_checker: config.type_checker.unwrap_or_default(),
         use_dependabot,
         dependabot_schedule,
         dependabot_day,
         use_continuous_deployment,
         use_release_drafter,
+        cloud_provider: config.cloud_provider,
+        cloud_deploy_role: config.cloud_deploy_role,
+        publish_docker: config.publish_docker.unwrap_or(false),
+        supply_chain_attestation: config.supply_chain_attestation.unwrap_or(false),
+        pypi_index_url: config.pypi_index_url,
+        publish_auth: config.publish_auth.unwrap_or_default(),
+        include_testpypi_publish: config.include_testpypi_publish.unwrap_or(false),
+        include_rust_toolchain: config.include_rust_toolchain.unwrap_or(false),
+        rust_edition: config
+            .rust_edition
+            .unwrap_or(DEFAULT_RUST_EDITION.to_string()),
+        pyo3_version: config.pyo3_version,
+        use_abi3: config.use_abi3.unwrap_or(false),
+        build_musllinux: config.build_musllinux.unwrap_or(false),
+        maturin_targets: config.maturin_targets,
+        ci_job_timeout: config.ci_job_timeout.unwrap_or(DEFAULT_CI_JOB_TIMEOUT),
+        ci_coverage_comment: config.ci_coverage_comment.unwrap_or(false),
+        uv_frozen: config.uv_frozen.unwrap_or(true),
         use_multi_os_ci,
         include_docs,
         docs_info,
         download_latest_packages: false,
+        offline: false,
         project_root_dir: None,
     })
 }
@@ -717,7 +1485,7 @@ fn github_actions_python_test_versions_prompt(default: Vec<String>) -> Result<Ve
     let version_check = input.replace(' ', "");
 
     for version in version_check.split(',') {
-        if !is_valid_python_version(version) {
+        if !is_valid_python_test_version(version) {
             bail!(format!("{} is not a valid Python Version", version));
         }
 
@@ -727,6 +1495,87 @@ fn github_actions_python_test_versions_prompt(default: Vec<String>) -> Result<Ve
     Ok(versions)
 }
 
+/// CI would try to test versions below the project's `requires-python`, so warn about any test
+/// versions lower than the minimum version.
+fn python_test_versions_warning(
+    min_python_version: &str,
+    github_actions_python_test_versions: &[String],
+) -> Result<Option<String>> {
+    let mut below_min: Vec<&str> = Vec::new();
+
+    for version in github_actions_python_test_versions {
+        if !is_python_version_or_greater(version, min_python_version)? {
+            below_min.push(version);
+        }
+    }
+
+    if below_min.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(format!(
+            "The CI test versions {} are lower than the min Python version {min_python_version}",
+            below_min.join(", ")
+        )))
+    }
+}
+
+/// The optional features toggled together in one multiselect prompt.
+#[derive(Debug, PartialEq, Eq)]
+struct OptionalFeatures {
+    use_dependabot: bool,
+    use_continuous_deployment: bool,
+    use_release_drafter: bool,
+    use_multi_os_ci: bool,
+    include_docs: bool,
+}
+
+const DEPENDABOT_LABEL: &str = "Dependabot";
+const CONTINUOUS_DEPLOYMENT_LABEL: &str = "Continuous Deployment";
+const RELEASE_DRAFTER_LABEL: &str = "Release Drafter";
+const MULTI_OS_CI_LABEL: &str = "Multi OS CI";
+const DOCS_LABEL: &str = "Docs";
+const OPTIONAL_FEATURE_LABELS: [&str; 5] = [
+    DEPENDABOT_LABEL,
+    CONTINUOUS_DEPLOYMENT_LABEL,
+    RELEASE_DRAFTER_LABEL,
+    MULTI_OS_CI_LABEL,
+    DOCS_LABEL,
+];
+
+fn optional_features_from_labels(selected: &[&str]) -> OptionalFeatures {
+    OptionalFeatures {
+        use_dependabot: selected.contains(&DEPENDABOT_LABEL),
+        use_continuous_deployment: selected.contains(&CONTINUOUS_DEPLOYMENT_LABEL),
+        use_release_drafter: selected.contains(&RELEASE_DRAFTER_LABEL),
+        use_multi_os_ci: selected.contains(&MULTI_OS_CI_LABEL),
+        include_docs: selected.contains(&DOCS_LABEL),
+    }
+}
+
+/// The indexes into `OPTIONAL_FEATURE_LABELS` that start out selected.
+fn optional_features_selected_indexes(features: &OptionalFeatures) -> Vec<usize> {
+    [
+        features.use_dependabot,
+        features.use_continuous_deployment,
+        features.use_release_drafter,
+        features.use_multi_os_ci,
+        features.include_docs,
+    ]
+    .iter()
+    .enumerate()
+    .filter_map(|(i, selected)| selected.then_some(i))
+    .collect()
+}
+
+fn optional_features_prompt(default: &OptionalFeatures) -> Result<OptionalFeatures> {
+    let default_indexes = optional_features_selected_indexes(default);
+    let selected = MultiSelect::new("Optional Features", OPTIONAL_FEATURE_LABELS.to_vec())
+        .with_default(&default_indexes)
+        .prompt()?;
+
+    Ok(optional_features_from_labels(&selected))
+}
+
 fn license_prompt(default: Option<LicenseType>) -> Result<LicenseType> {
     let default_license: Option<String> = match default {
         Some(d) => match d {
@@ -808,6 +1657,124 @@ fn python_version_prompt(default: String) -> Result<String> {
 mod tests {
     use super::*;
 
+    fn docs_info_dummy(site_url: Option<String>) -> DocsInfo {
+        DocsInfo {
+            site_name: "Test Repo".to_string(),
+            site_description: "Dummy data for testing".to_string(),
+            site_url,
+            locale: "en".to_string(),
+            repo_name: "sanders41/python-project-generator".to_string(),
+            repo_url: "https://github.com/sanders41/python-project-generator".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_resolved_site_url_custom_domain() {
+        let docs_info = docs_info_dummy(Some("https://mytest.com".to_string()));
+
+        assert_eq!(docs_info.resolved_site_url().unwrap(), "https://mytest.com");
+    }
+
+    #[test]
+    fn test_resolved_site_url_github_pages() {
+        let docs_info = docs_info_dummy(None);
+
+        assert_eq!(
+            docs_info.resolved_site_url().unwrap(),
+            "https://sanders41.github.io/python-project-generator/"
+        );
+    }
+
+    #[test]
+    fn test_resolved_site_url_github_pages_from_repo_name() {
+        let mut docs_info = docs_info_dummy(None);
+        docs_info.repo_url = "https://gitlab.com/sanders41/python-project-generator".to_string();
+
+        assert_eq!(
+            docs_info.resolved_site_url().unwrap(),
+            "https://sanders41.github.io/python-project-generator/"
+        );
+    }
+
+    #[test]
+    fn test_resolved_site_url_invalid_repo() {
+        let mut docs_info = docs_info_dummy(None);
+        docs_info.repo_url = "https://gitlab.com/python-project-generator".to_string();
+        docs_info.repo_name = "python-project-generator".to_string();
+
+        assert!(docs_info.resolved_site_url().is_err());
+    }
+
+    #[test]
+    fn test_parse_author() {
+        assert_eq!(
+            parse_author("Ford Prefect <ford@heartofgold.com>").unwrap(),
+            (
+                "Ford Prefect".to_string(),
+                "ford@heartofgold.com".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_author_invalid() {
+        assert!(parse_author("Ford Prefect").is_err());
+        assert!(parse_author("<ford@heartofgold.com>").is_err());
+        assert!(parse_author("Ford Prefect <ford>").is_err());
+    }
+
+    #[test]
+    fn test_valid_emails() {
+        for email in [
+            "authur@heartofgold.com",
+            "first.last+tag@example.co.uk",
+            "\"quoted@local\"@example.com",
+            "user@localhost",
+        ] {
+            assert!(is_valid_email(email), "{email}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_emails() {
+        for email in [
+            "",
+            "authur",
+            "@heartofgold.com",
+            "authur@",
+            "authur@.com",
+            "authur@heartofgold.",
+            "authur@heart..gold",
+            "authur dent@heartofgold.com",
+        ] {
+            assert!(!is_valid_email(email), "{email}");
+        }
+    }
+
+    #[test]
+    fn test_valid_copyright_year_single() {
+        assert!(is_valid_copyright_year("2024"));
+    }
+
+    #[test]
+    fn test_valid_copyright_year_range() {
+        assert!(is_valid_copyright_year("2021-2025"));
+    }
+
+    #[test]
+    fn test_valid_copyright_year_present() {
+        assert!(is_valid_copyright_year("2021-present"));
+    }
+
+    #[test]
+    fn test_invalid_copyright_year() {
+        assert!(!is_valid_copyright_year("abc"));
+        assert!(!is_valid_copyright_year("999"));
+        assert!(!is_valid_copyright_year("2025-2021"));
+        assert!(!is_valid_copyright_year("2021-"));
+        assert!(!is_valid_copyright_year("2021-2022-2023"));
+    }
+
     #[test]
     fn test_valid_two_digit_python_version() {
         assert!(is_valid_python_version("3.9"));
@@ -818,6 +1785,101 @@ mod tests {
         assert!(is_valid_python_version("3.11.0"));
     }
 
+    #[test]
+    fn test_optional_features_from_labels() {
+        let result = optional_features_from_labels(&[DEPENDABOT_LABEL, DOCS_LABEL]);
+
+        assert_eq!(
+            result,
+            OptionalFeatures {
+                use_dependabot: true,
+                use_continuous_deployment: false,
+                use_release_drafter: false,
+                use_multi_os_ci: false,
+                include_docs: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_optional_features_from_labels_none_selected() {
+        let result = optional_features_from_labels(&[]);
+
+        assert_eq!(
+            result,
+            OptionalFeatures {
+                use_dependabot: false,
+                use_continuous_deployment: false,
+                use_release_drafter: false,
+                use_multi_os_ci: false,
+                include_docs: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_optional_features_round_trip() {
+        let features = OptionalFeatures {
+            use_dependabot: true,
+            use_continuous_deployment: false,
+            use_release_drafter: true,
+            use_multi_os_ci: false,
+            include_docs: true,
+        };
+        let selected: Vec<&str> = optional_features_selected_indexes(&features)
+            .into_iter()
+            .map(|i| OPTIONAL_FEATURE_LABELS[i])
+            .collect();
+
+        assert_eq!(optional_features_from_labels(&selected), features);
+    }
+
+    #[test]
+    fn test_python_test_versions_warning_consistent() {
+        let versions = vec!["3.12".to_string(), "3.13".to_string(), "3.13t".to_string()];
+
+        assert!(python_test_versions_warning("3.12", &versions)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_python_test_versions_warning_inconsistent() {
+        let versions = vec!["3.10".to_string(), "3.11".to_string(), "3.12".to_string()];
+        let warning = python_test_versions_warning("3.12", &versions)
+            .unwrap()
+            .unwrap();
+
+        assert!(warning.contains("3.10, 3.11 are lower than the min Python version 3.12"));
+    }
+
+    #[test]
+    fn test_valid_free_threaded_python_test_version() {
+        assert!(is_valid_python_test_version("3.13t"));
+        assert!(is_valid_python_test_version("3.14t"));
+    }
+
+    #[test]
+    fn test_valid_python_test_version_without_suffix() {
+        assert!(is_valid_python_test_version("3.12"));
+    }
+
+    #[test]
+    fn test_invalid_free_threaded_python_test_version_before_3_13() {
+        assert!(!is_valid_python_test_version("3.12t"));
+    }
+
+    #[test]
+    fn test_invalid_free_threaded_python_test_version() {
+        assert!(!is_valid_python_test_version("3.14tt"));
+        assert!(!is_valid_python_test_version("t"));
+    }
+
+    #[test]
+    fn test_free_threaded_python_version_not_valid_project_version() {
+        assert!(!is_valid_python_version("3.14t"));
+    }
+
     #[test]
     fn test_invalid_python_version_major_less_than_three() {
         assert!(!is_valid_python_version("2.7"));
@@ -847,4 +1909,187 @@ mod tests {
     fn test_invalid_python_version_non_numeric_patch() {
         assert!(!is_valid_python_version("3.9.a"));
     }
+
+    #[test]
+    fn test_is_valid_rust_edition() {
+        assert!(is_valid_rust_edition("2021"));
+        assert!(is_valid_rust_edition("2024"));
+        assert!(!is_valid_rust_edition("2020"));
+    }
+
+    #[test]
+    fn test_project_manager_description() {
+        for project_manager in ProjectManager::value_variants() {
+            assert!(!project_manager.description().is_empty());
+            assert!(!project_manager.build_backend().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_is_valid_maturin_target() {
+        assert!(is_valid_maturin_target("x64"));
+        assert!(is_valid_maturin_target("aarch64-unknown-linux-musl"));
+        assert!(is_valid_maturin_target("aarch64-apple-darwin"));
+        assert!(!is_valid_maturin_target("sparc"));
+        assert!(is_valid_maturin_target("x86_64-pc-windows-msvc"));
+        assert!(!is_valid_maturin_target("aarch64-pc-windows-msvc"));
+        assert!(!is_valid_maturin_target("x86_64-pc-windows-gnu"));
+    }
+
+    #[test]
+    fn test_is_valid_crate_version() {
+        assert!(is_valid_crate_version("0.23"));
+        assert!(is_valid_crate_version("0.23.4"));
+        assert!(!is_valid_crate_version("0.23.4.1"));
+        assert!(!is_valid_crate_version("0.x"));
+    }
+
+    #[test]
+    fn test_module_name_from_project_name() {
+        assert_eq!(module_name_from_project_name("My Project"), "my_project");
+        assert_eq!(module_name_from_project_name("my-project"), "my_project");
+    }
+
+    #[test]
+    fn test_module_name_from_project_name_accented() {
+        assert_eq!(module_name_from_project_name("Café App"), "cafe_app");
+    }
+
+    #[test]
+    fn test_module_name_from_project_name_leading_number() {
+        assert_eq!(
+            module_name_from_project_name("123 numbers"),
+            "project_123_numbers"
+        );
+    }
+
+    #[test]
+    fn test_module_name_from_project_name_punctuation() {
+        assert_eq!(module_name_from_project_name("my-project!"), "my_project");
+    }
+
+    #[test]
+    fn test_module_name_from_project_name_emoji_only() {
+        assert_eq!(module_name_from_project_name("🚀"), "project");
+    }
+
+    #[test]
+    fn test_module_name_from_project_name_is_valid_identifier() {
+        for name in ["Café App", "123 numbers", "my-project!", "🚀 launch"] {
+            assert!(is_valid_python_identifier(&module_name_from_project_name(
+                name
+            )));
+        }
+    }
+
+    #[test]
+    fn test_validate_module_name() {
+        assert!(validate_module_name("my_project").is_ok());
+    }
+
+    #[test]
+    fn test_validate_module_name_keyword() {
+        let result = validate_module_name("class");
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("is a Python keyword"));
+    }
+
+    #[test]
+    fn test_validate_module_name_stdlib_shadow() {
+        let result = validate_module_name("json");
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Try json_pkg"));
+    }
+
+    #[test]
+    fn test_validate_module_name_stdlib_test_package() {
+        assert!(validate_module_name("test").is_err());
+    }
+
+    #[test]
+    fn test_valid_python_identifier() {
+        for name in ["mypkg", "my_pkg", "_private", "pkg2"] {
+            assert!(is_valid_python_identifier(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_python_identifier() {
+        for name in ["", "2pkg", "my-pkg", "my pkg", "café"] {
+            assert!(!is_valid_python_identifier(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_valid_project_slug() {
+        for slug in ["my-project", "my_project", "my.project", "project2", "a"] {
+            assert!(validate_project_slug(slug).is_ok(), "{slug}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_project_slug_uppercase() {
+        let result = validate_project_slug("My-Project");
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Try my-project"));
+    }
+
+    #[test]
+    fn test_invalid_project_slug_leading_underscore() {
+        let result = validate_project_slug("_my_project");
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Try my-project"));
+    }
+
+    #[test]
+    fn test_invalid_project_slug_disallowed_characters() {
+        for slug in ["my project", "my@project", "my-project-", "", "café"] {
+            assert!(validate_project_slug(slug).is_err(), "{slug}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_project_slug_no_suggestion() {
+        let result = validate_project_slug("___");
+
+        assert!(result.is_err());
+        assert!(!result.unwrap_err().to_string().contains("Try"));
+    }
+
+    #[test]
+    fn test_valid_ruff_rule_prefix() {
+        assert!(is_valid_ruff_rule("SIM"));
+    }
+
+    #[test]
+    fn test_valid_ruff_rule_with_digits() {
+        assert!(is_valid_ruff_rule("RUF100"));
+    }
+
+    #[test]
+    fn test_invalid_ruff_rule_empty() {
+        assert!(!is_valid_ruff_rule(""));
+    }
+
+    #[test]
+    fn test_invalid_ruff_rule_digits_only() {
+        assert!(!is_valid_ruff_rule("100"));
+    }
+
+    #[test]
+    fn test_invalid_ruff_rule_letters_after_digits() {
+        assert!(!is_valid_ruff_rule("E5A"));
+    }
+
+    #[test]
+    fn test_invalid_ruff_rule_lowercase() {
+        assert!(!is_valid_ruff_rule("sim"));
+    }
 }
@@ -136,6 +136,43 @@ fn save_pyo3_test_file(project_info: &ProjectInfo) -> Result<()> {
     Ok(())
 }
 
+fn create_benchmark_file(module: &str, project_manager: &ProjectManager) -> String {
+    if let ProjectManager::Maturin = project_manager {
+        format!(
+            r#"from {module} import sum_as_string
+
+
+def test_sum_as_string(benchmark):
+    assert benchmark(sum_as_string, 2, 4) == "6"
+"#
+        )
+    } else {
+        r#"def fibonacci(n: int) -> int:
+    # TODO: This is placeholder code, remove and replace with benchmarks of your code.
+    a, b = 0, 1
+    for _ in range(n):
+        a, b = b, a + b
+
+    return a
+
+
+def test_fibonacci(benchmark):
+    assert benchmark(fibonacci, 20) == 6765
+"#
+        .to_string()
+    }
+}
+
+fn save_benchmark_file(project_info: &ProjectInfo) -> Result<()> {
+    let module = project_info.source_dir.replace([' ', '-'], "_");
+    let file_path = project_info.base_dir().join("benchmarks/test_benchmark.py");
+    let content = create_benchmark_file(&module, &project_info.project_manager);
+
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
 fn create_project_init_file(module: &str, project_manager: &ProjectManager) -> String {
     match project_manager {
         ProjectManager::Maturin => {
@@ -228,6 +265,72 @@ pub fn save_pyi_file(project_info: &ProjectInfo) -> Result<()> {
     Ok(())
 }
 
+fn create_settings_file() -> String {
+    r#"from __future__ import annotations
+
+from pydantic_settings import BaseSettings, SettingsConfigDict
+
+
+class Settings(BaseSettings):
+    model_config = SettingsConfigDict(env_file=".env", env_file_encoding="utf-8", extra="ignore")
+
+    # TODO: This is a placeholder setting, remove and replace with your settings.
+    debug: bool = False
+
+
+settings = Settings()
+"#
+    .to_string()
+}
+
+fn save_settings_file(project_info: &ProjectInfo) -> Result<()> {
+    let module = project_info.source_dir.replace([' ', '-'], "_");
+    let file_path = project_info
+        .base_dir()
+        .join(format!("{}/settings.py", &module));
+    let content = create_settings_file();
+
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
+fn save_env_example_file(project_info: &ProjectInfo) -> Result<()> {
+    let file_path = project_info.base_dir().join(".env.example");
+    save_file_with_content(&file_path, "DEBUG=false\n")?;
+
+    Ok(())
+}
+
+fn create_logging_config_file() -> String {
+    r#"from __future__ import annotations
+
+import logging
+import os
+
+LOG_FORMAT = "%(asctime)s - %(name)s - %(levelname)s - %(message)s"
+
+
+def configure_logging() -> None:
+    """Configure the root logger, the level is set with the LOG_LEVEL environment variable."""
+    level = os.getenv("LOG_LEVEL", "INFO").upper()
+    logging.basicConfig(level=level, format=LOG_FORMAT)
+"#
+    .to_string()
+}
+
+fn save_logging_config_file(project_info: &ProjectInfo) -> Result<()> {
+    let module = project_info.source_dir.replace([' ', '-'], "_");
+    let file_path = project_info
+        .base_dir()
+        .join(format!("{}/logging_config.py", &module));
+    let content = create_logging_config_file();
+
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
 fn create_version_file(version: &str) -> String {
     format!("VERSION = \"{version}\"\n")
 }
@@ -338,12 +441,26 @@ pub fn generate_python_files(project_info: &ProjectInfo) -> Result<()> {
         if save_main_test_file(project_info).is_err() {
             bail!("Error creating main test file");
         }
+
+        if project_info.include_logging && save_logging_config_file(project_info).is_err() {
+            bail!("Error creating logging config file");
+        }
     }
 
     if save_version_file(project_info).is_err() {
         bail!("Error creating version file");
     }
 
+    if project_info.include_settings {
+        if save_settings_file(project_info).is_err() {
+            bail!("Error creating settings file");
+        }
+
+        if save_env_example_file(project_info).is_err() {
+            bail!("Error creating .env.example file");
+        }
+    }
+
     if save_version_test_file(project_info).is_err() {
         bail!("Error creating version test file")
     }
@@ -358,14 +475,21 @@ pub fn generate_python_files(project_info: &ProjectInfo) -> Result<()> {
         }
     }
 
+    if project_info.include_benchmarks && save_benchmark_file(project_info).is_err() {
+        bail!("Error creating benchmark file");
+    }
+
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::project_info::{LicenseType, ProjectInfo, ProjectManager, Pyo3PythonManager};
+    use crate::project_info::{
+        LicenseType, ProjectInfo, ProjectManager, PublishAuth, Pyo3PythonManager, TypeChecker,
+    };
     use insta::assert_yaml_snapshot;
+    use std::collections::HashMap;
     use std::fs::create_dir_all;
     use tmp_path::tmp_path;
 
@@ -394,6 +518,7 @@ mod tests {
                 "3.12".to_string(),
             ],
             max_line_length: 100,
+            ruff_line_length: None,
             use_dependabot: true,
             dependabot_schedule: None,
             dependabot_day: None,
@@ -403,6 +528,47 @@ mod tests {
             include_docs: false,
             docs_info: None,
             download_latest_packages: false,
+            min_coverage: None,
+            extra_ruff_rules: Vec::new(),
+            include_bandit: false,
+            extra_pre_commit_hooks: Vec::new(),
+            use_precommit_ci: false,
+            include_settings: false,
+            include_logging: false,
+            mypy_files: None,
+            ruff_target_version: None,
+            offline: false,
+            cloud_provider: None,
+            cloud_deploy_role: None,
+            publish_docker: false,
+            supply_chain_attestation: false,
+            pypi_index_url: None,
+            publish_auth: PublishAuth::TrustedPublishing,
+            include_testpypi_publish: false,
+            include_rust_toolchain: false,
+            rust_edition: "2021".to_string(),
+            pyo3_version: None,
+            use_abi3: false,
+            build_musllinux: false,
+            maturin_targets: None,
+            package_version_overrides: HashMap::new(),
+            ci_job_timeout: 30,
+            dependency_bound_style: None,
+            type_checker: TypeChecker::Mypy,
+            include_conda_env: false,
+            copyright_holder: None,
+            additional_authors: Vec::new(),
+            include_codeowners: false,
+            github_username: None,
+            include_github_templates: false,
+            include_contributing: false,
+            include_codeql: false,
+            include_security_policy: false,
+            include_changelog: false,
+            include_citation: false,
+            include_benchmarks: false,
+            ci_coverage_comment: false,
+            uv_frozen: true,
             project_root_dir: Some(tmp_path),
         }
     }
@@ -541,6 +707,40 @@ mod tests {
         assert_yaml_snapshot!(content);
     }
 
+    #[test]
+    fn test_save_benchmark_file() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Uv;
+        project_info.include_benchmarks = true;
+        let base = project_info.base_dir();
+        create_dir_all(base.join("benchmarks")).unwrap();
+        let expected_file = base.join("benchmarks/test_benchmark.py");
+        save_benchmark_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_benchmark_file_pyo3() {
+        let mut project_info = project_info_dummy();
+        project_info.project_manager = ProjectManager::Maturin;
+        project_info.include_benchmarks = true;
+        let base = project_info.base_dir();
+        create_dir_all(base.join("benchmarks")).unwrap();
+        let expected_file = base.join("benchmarks/test_benchmark.py");
+        save_benchmark_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
     #[test]
     fn test_save_pyo3_test_file() {
         let mut project_info = project_info_dummy();
@@ -579,6 +779,62 @@ mod tests {
         assert_yaml_snapshot!(content);
     }
 
+    #[test]
+    fn test_save_settings_file() {
+        let project_info = project_info_dummy();
+        let base = project_info.base_dir();
+        create_dir_all(base.join(&project_info.source_dir)).unwrap();
+        let expected_file = base.join(format!("{}/settings.py", &project_info.source_dir));
+        save_settings_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_save_env_example_file() {
+        let project_info = project_info_dummy();
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join(".env.example");
+        save_env_example_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+    }
+
+    #[test]
+    fn test_save_logging_config_file() {
+        let project_info = project_info_dummy();
+        let base = project_info.base_dir();
+        create_dir_all(base.join(&project_info.source_dir)).unwrap();
+        let expected_file = base.join(format!("{}/logging_config.py", &project_info.source_dir));
+        save_logging_config_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
+    #[test]
+    fn test_generate_python_files_lib_no_logging_config() {
+        let mut project_info = project_info_dummy();
+        project_info.is_application = false;
+        project_info.include_logging = true;
+        let base = project_info.base_dir();
+        create_dir_all(base.join(&project_info.source_dir)).unwrap();
+        create_dir_all(base.join("tests")).unwrap();
+        generate_python_files(&project_info).unwrap();
+
+        assert!(!base
+            .join(format!("{}/logging_config.py", &project_info.source_dir))
+            .exists());
+    }
+
     #[test]
     fn test_save_version_file() {
         let project_info = project_info_dummy();
@@ -4,20 +4,42 @@ use rayon::prelude::*;
 
 use crate::file_manager::save_file_with_content;
 use crate::licenses::license_str;
-use crate::package_version::{LatestVersion, RustPackageVersion};
+use crate::package_version::{LatestVersion, RustPackageVersion, VersionCache, RETRY_ATTEMPTS};
 use crate::project_info::{LicenseType, ProjectInfo};
 
-fn build_latest_dependencies(download_latest_packages: bool) -> String {
+/// The PyO3 feature that builds abi3 wheels for `min_python_version` and newer, e.g. `abi3-py310`.
+pub fn abi3_feature(min_python_version: &str) -> String {
+    let major_minor = min_python_version
+        .split('.')
+        .take(2)
+        .collect::<Vec<&str>>()
+        .concat();
+
+    format!("abi3-py{major_minor}")
+}
+
+/// A saved PyO3 version is used as is instead of the latest version.
+fn build_latest_dependencies(
+    download_latest_packages: bool,
+    pyo3_version: Option<&str>,
+    abi3_feature: Option<String>,
+) -> String {
     let mut version_string = String::new();
+    let mut features = vec!["extension-module".to_string()];
+    features.extend(abi3_feature);
     let mut packages = vec![RustPackageVersion {
         name: "pyo3".to_string(),
-        version: "0.23.4".to_string(),
-        features: Some(vec!["extension-module".to_string()]),
+        version: pyo3_version.unwrap_or("0.23.4").to_string(),
+        features: Some(features),
     }];
 
-    if download_latest_packages {
+    if download_latest_packages && pyo3_version.is_none() {
+        let cache = VersionCache::from_config().ok();
         packages.par_iter_mut().for_each(|package| {
-            if package.get_latest_version().is_err() {
+            if package
+                .get_latest_version_with_retry(RETRY_ATTEMPTS, cache.as_ref())
+                .is_err()
+            {
                 let error_message = format!(
                     "Error retrieving latest crate version for {}. Using default.",
                     package.name
@@ -57,9 +79,9 @@ fn create_cargo_toml_file(
     project_description: &str,
     source_dir: &str,
     license_type: &LicenseType,
-    download_latest_packages: bool,
+    rust_edition: &str,
+    versions: &str,
 ) -> String {
-    let versions = build_latest_dependencies(download_latest_packages);
     let license = license_str(license_type);
     let name = source_dir.replace([' ', '-'], "_");
 
@@ -68,7 +90,7 @@ fn create_cargo_toml_file(
 name = "{project_slug}"
 version = "0.1.0"
 description = "{project_description}"
-edition = "2021"
+edition = "{rust_edition}"
 license = "{license}"
 readme = "README.md"
 
@@ -84,12 +106,20 @@ crate-type = ["cdylib"]
 
 pub fn save_cargo_toml_file(project_info: &ProjectInfo) -> Result<()> {
     let file_path = project_info.base_dir().join("Cargo.toml");
+    let versions = build_latest_dependencies(
+        project_info.should_download_latest_packages(),
+        project_info.pyo3_version.as_deref(),
+        project_info
+            .use_abi3
+            .then(|| abi3_feature(&project_info.min_python_version)),
+    );
     let content = create_cargo_toml_file(
         &project_info.project_slug,
         &project_info.project_description,
         &project_info.source_dir,
         &project_info.license,
-        project_info.download_latest_packages,
+        &project_info.rust_edition,
+        &versions,
     );
 
     save_file_with_content(&file_path, &content)?;
@@ -97,6 +127,24 @@ pub fn save_cargo_toml_file(project_info: &ProjectInfo) -> Result<()> {
     Ok(())
 }
 
+/// Pins the stable toolchain with rustfmt and clippy so local builds use the same tools as CI.
+fn create_rust_toolchain_file() -> String {
+    r#"[toolchain]
+channel = "stable"
+components = ["rustfmt", "clippy"]
+"#
+    .to_string()
+}
+
+pub fn save_rust_toolchain_file(project_info: &ProjectInfo) -> Result<()> {
+    let file_path = project_info.base_dir().join("rust-toolchain.toml");
+    let content = create_rust_toolchain_file();
+
+    save_file_with_content(&file_path, &content)?;
+
+    Ok(())
+}
+
 fn create_lib_file(source_dir: &str) -> String {
     let module = source_dir.replace([' ', '-'], "_");
     format!(
@@ -128,8 +176,11 @@ pub fn save_lib_file(project_info: &ProjectInfo) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::project_info::{LicenseType, ProjectInfo, ProjectManager, Pyo3PythonManager};
+    use crate::project_info::{
+        LicenseType, ProjectInfo, ProjectManager, PublishAuth, Pyo3PythonManager, TypeChecker,
+    };
     use insta::assert_yaml_snapshot;
+    use std::collections::HashMap;
     use std::fs::create_dir_all;
     use tmp_path::tmp_path;
 
@@ -158,6 +209,7 @@ mod tests {
                 "3.12".to_string(),
             ],
             max_line_length: 100,
+            ruff_line_length: None,
             use_dependabot: true,
             dependabot_schedule: None,
             dependabot_day: None,
@@ -167,6 +219,47 @@ mod tests {
             include_docs: false,
             docs_info: None,
             download_latest_packages: false,
+            min_coverage: None,
+            extra_ruff_rules: Vec::new(),
+            include_bandit: false,
+            extra_pre_commit_hooks: Vec::new(),
+            use_precommit_ci: false,
+            include_settings: false,
+            include_logging: false,
+            mypy_files: None,
+            ruff_target_version: None,
+            offline: false,
+            cloud_provider: None,
+            cloud_deploy_role: None,
+            publish_docker: false,
+            supply_chain_attestation: false,
+            pypi_index_url: None,
+            publish_auth: PublishAuth::TrustedPublishing,
+            include_testpypi_publish: false,
+            include_rust_toolchain: false,
+            rust_edition: "2021".to_string(),
+            pyo3_version: None,
+            use_abi3: false,
+            build_musllinux: false,
+            maturin_targets: None,
+            package_version_overrides: HashMap::new(),
+            ci_job_timeout: 30,
+            dependency_bound_style: None,
+            type_checker: TypeChecker::Mypy,
+            include_conda_env: false,
+            copyright_holder: None,
+            additional_authors: Vec::new(),
+            include_codeowners: false,
+            github_username: None,
+            include_github_templates: false,
+            include_contributing: false,
+            include_codeql: false,
+            include_security_policy: false,
+            include_changelog: false,
+            include_citation: false,
+            include_benchmarks: false,
+            ci_coverage_comment: false,
+            uv_frozen: true,
             project_root_dir: Some(tmp_path),
         }
     }
@@ -188,6 +281,56 @@ mod tests {
         ]}, { assert_yaml_snapshot!(content)});
     }
 
+    #[test]
+    fn test_save_cargo_toml_file_rust_edition_and_pyo3_version() {
+        let mut project_info = project_info_dummy();
+        project_info.rust_edition = "2024".to_string();
+        project_info.pyo3_version = Some("0.22.6".to_string());
+        project_info.download_latest_packages = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        save_cargo_toml_file(&project_info).unwrap();
+        let content = std::fs::read_to_string(base.join("Cargo.toml")).unwrap();
+
+        assert!(content.contains("edition = \"2024\"\n"));
+        assert!(content
+            .contains("pyo3 = { version = \"0.22.6\", features = [\"extension-module\"] }\n"));
+    }
+
+    #[test]
+    fn test_save_cargo_toml_file_abi3() {
+        let mut project_info = project_info_dummy();
+        project_info.min_python_version = "3.10".to_string();
+        project_info.use_abi3 = true;
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        save_cargo_toml_file(&project_info).unwrap();
+        let content = std::fs::read_to_string(base.join("Cargo.toml")).unwrap();
+
+        assert!(content.contains("features = [\"extension-module\", \"abi3-py310\"] }\n"));
+    }
+
+    #[test]
+    fn test_abi3_feature() {
+        assert_eq!(abi3_feature("3.9"), "abi3-py39");
+        assert_eq!(abi3_feature("3.12.1"), "abi3-py312");
+    }
+
+    #[test]
+    fn test_save_rust_toolchain_file() {
+        let project_info = project_info_dummy();
+        let base = project_info.base_dir();
+        create_dir_all(&base).unwrap();
+        let expected_file = base.join("rust-toolchain.toml");
+        save_rust_toolchain_file(&project_info).unwrap();
+
+        assert!(expected_file.is_file());
+
+        let content = std::fs::read_to_string(expected_file).unwrap();
+
+        assert_yaml_snapshot!(content);
+    }
+
     #[test]
     fn test_save_lib_file() {
         let project_info = project_info_dummy();
@@ -14,6 +14,29 @@ pub fn is_python_312_or_greater(version: &str) -> Result<bool> {
     }
 }
 
+/// Without `--frozen` uv can install from a project that doesn't have a committed `uv.lock`.
+pub fn uv_sync_command(uv_frozen: bool) -> &'static str {
+    if uv_frozen {
+        "uv sync --frozen"
+    } else {
+        "uv sync"
+    }
+}
+
+/// Compares the major and minor versions, ignoring the patch version and a free-threaded `t`
+/// suffix.
+pub fn is_python_version_or_greater(version: &str, minimum: &str) -> Result<bool> {
+    fn major_minor(version: &str) -> Result<(i32, i32)> {
+        let mut split_version = version.trim_end_matches('t').split('.');
+        let major = split_version.next().unwrap_or_default().parse::<i32>()?;
+        let minor = split_version.next().unwrap_or("0").parse::<i32>()?;
+
+        Ok((major, minor))
+    }
+
+    Ok(major_minor(version)? >= major_minor(minimum)?)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -35,4 +58,17 @@ mod tests {
         let result = is_python_312_or_greater("3.11").unwrap();
         assert!(!result);
     }
+
+    #[test]
+    fn test_python_version_or_greater() {
+        assert!(is_python_version_or_greater("3.12", "3.12").unwrap());
+        assert!(is_python_version_or_greater("3.13.1", "3.12").unwrap());
+        assert!(is_python_version_or_greater("3.14t", "3.13").unwrap());
+        assert!(!is_python_version_or_greater("3.10", "3.12").unwrap());
+    }
+
+    #[test]
+    fn test_python_version_or_greater_invalid() {
+        assert!(is_python_version_or_greater("three", "3.12").is_err());
+    }
 }